@@ -9,6 +9,97 @@ fn readme(c: &mut Criterion) {
     });
 }
 
+fn code(c: &mut Criterion) {
+    let mut doc = String::from("```\n");
+    while doc.len() < 100 * 1024 {
+        doc.push_str("let value = some_function(argument, 42) + another_value;\n");
+    }
+    doc.push_str("```\n");
+
+    c.bench_with_input(BenchmarkId::new("code", "100 kb"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
+fn emphasis(c: &mut Criterion) {
+    let mut doc = String::new();
+    while doc.len() < 100 * 1024 {
+        doc.push_str("Some *emphasis*, **strong**, _mixed **and** nested_, and a*b*c in*side.\n");
+    }
+
+    c.bench_with_input(BenchmarkId::new("emphasis", "100 kb"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
+fn links(c: &mut Criterion) {
+    let mut doc = String::new();
+    let mut index = 0;
+    while doc.len() < 100 * 1024 {
+        doc.push_str(&format!(
+            "A [resource](https://example.com/{} \"title\"), a [reference][{}], and <https://example.com>.\n\n[{}]: https://example.org/{}\n\n",
+            index, index, index, index
+        ));
+        index += 1;
+    }
+
+    c.bench_with_input(BenchmarkId::new("links", "100 kb"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
+fn table(c: &mut Criterion) {
+    let mut doc = String::from("| a | b | c |\n| :- | :-: | -: |\n");
+    while doc.len() < 100 * 1024 {
+        doc.push_str("| some *text* | `code` | 42 |\n");
+    }
+
+    c.bench_with_input(BenchmarkId::new("table", "100 kb"), &doc, |b, s| {
+        b.iter(|| markdown::to_html_with_options(s, &markdown::Options::gfm()).unwrap());
+    });
+}
+
+fn html(c: &mut Criterion) {
+    let mut doc = String::new();
+    while doc.len() < 100 * 1024 {
+        doc.push_str("# Heading\n\n> A *quote* w/ a [link](https://example.com).\n\n* a `list`\n* **item**\n\n```js\ncode()\n```\n\n");
+    }
+
+    c.bench_with_input(BenchmarkId::new("html", "100 kb"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+}
+
+fn mdast(c: &mut Criterion) {
+    let mut doc = String::new();
+    while doc.len() < 100 * 1024 {
+        doc.push_str("# Heading\n\n> A *quote* w/ a [link](https://example.com).\n\n* a `list`\n* **item**\n\n```js\ncode()\n```\n\n");
+    }
+
+    c.bench_with_input(BenchmarkId::new("mdast", "100 kb"), &doc, |b, s| {
+        b.iter(|| markdown::to_mdast(s, &markdown::ParseOptions::default()).unwrap());
+    });
+}
+
+fn long_line(c: &mut Criterion) {
+    let mut group = c.benchmark_group("long line");
+    group.sample_size(10);
+
+    for (name, doc) in [
+        ("*", "*".repeat(1024 * 1024)),
+        ("a*", "a*".repeat(512 * 1024)),
+        ("a* ", "a* ".repeat(340 * 1024)),
+        ("[", "[".repeat(1024 * 1024)),
+        ("\\n", "\n".repeat(1024 * 1024)),
+    ] {
+        group.bench_with_input(BenchmarkId::new(name, "1 mb"), &doc, |b, s| {
+            b.iter(|| markdown::to_html(s));
+        });
+    }
+
+    group.finish();
+}
+
 // fn one_and_a_half_mb(c: &mut Criterion) {
 //     let doc = fs::read_to_string("../a-dump-of-markdown/markdown.md").unwrap();
 //     let mut group = c.benchmark_group("giant");
@@ -20,5 +111,5 @@ fn readme(c: &mut Criterion) {
 // }
 // , one_and_a_half_mb
 
-criterion_group!(benches, readme);
+criterion_group!(benches, readme, code, emphasis, links, table, html, mdast, long_line);
 criterion_main!(benches);
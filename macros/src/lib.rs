@@ -0,0 +1,188 @@
+//! Compile markdown to HTML at compile time, with `markdown-rs`.
+//!
+//! This crate exposes [`micromark!`][]: a function-like macro that takes a
+//! string literal of markdown and expands to a string literal of HTML.
+//! It is useful for static content, such as help text or about pages, which
+//! then does not have to be parsed when the program runs.
+//!
+//! ## Limitations
+//!
+//! *   only `CommonMark` is supported, with the same safe defaults as
+//!     `markdown::to_html`: no GFM, MDX, or other extensions, and no
+//!     dangerous HTML or protocols
+//! *   only string literals (`"…"` and `r#"…"#`) can be passed; macros such
+//!     as `include_str!` or `concat!`, and constants, are not expanded
+//!     first, because function-like macros see tokens, not values
+#![deny(clippy::pedantic)]
+#![allow(clippy::missing_panics_doc)]
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/wooorm/markdown-rs/8924580/media/logo-monochromatic.svg?sanitize=true"
+)]
+
+extern crate proc_macro;
+
+use proc_macro::{Literal, TokenStream, TokenTree};
+
+/// Turn a string literal of markdown into a string literal of HTML, at
+/// compile time.
+///
+/// Compiles markdown to HTML according to `CommonMark`, like
+/// `markdown::to_html` does when the program runs.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown_macros::micromark;
+///
+/// const HTML: &str = micromark!("# Hello, *world*!");
+///
+/// assert_eq!(HTML, "<h1>Hello, <em>world</em>!</h1>");
+/// ```
+///
+/// ## Errors
+///
+/// Passing anything other than one string literal results in an error at
+/// compile time:
+///
+/// ```compile_fail
+/// use markdown_macros::micromark;
+///
+/// let html = micromark!(1);
+/// ```
+#[proc_macro]
+pub fn micromark(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+
+    let value = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => string_value(&literal.to_string()),
+        _ => None,
+    };
+
+    match value {
+        Some(value) => TokenTree::Literal(Literal::string(&markdown::to_html(&value))).into(),
+        None => "compile_error!(\"expected one string literal of markdown\")"
+            .parse()
+            .unwrap(),
+    }
+}
+
+/// Get the value of a string literal, from how it looks in source code.
+///
+/// Supports normal strings (`"a"`) and raw strings (`r"a"`, `r#"a"#`).
+/// Returns `None` for other literals (such as numbers or byte strings).
+fn string_value(source: &str) -> Option<String> {
+    if let Some(rest) = source.strip_prefix('r') {
+        let hashes = rest.len() - rest.trim_start_matches('#').len();
+        let rest = &rest[hashes..];
+        let end = rest.len().checked_sub(hashes + 1)?;
+
+        if rest.starts_with('"') && end > 0 && rest[end..].starts_with('"') {
+            return Some(rest[1..end].into());
+        }
+    } else if source.len() > 1 && source.starts_with('"') && source.ends_with('"') {
+        return unescape(&source[1..source.len() - 1]);
+    }
+
+    None
+}
+
+/// Turn the escapes in a normal string literal into the characters they
+/// represent.
+fn unescape(value: &str) -> Option<String> {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            result.push(char);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            't' => result.push('\t'),
+            '0' => result.push('\0'),
+            '\\' => result.push('\\'),
+            '\'' => result.push('\''),
+            '"' => result.push('"'),
+            'x' => {
+                let digits = [chars.next()?, chars.next()?];
+                let code = u32::from_str_radix(&digits.iter().collect::<String>(), 16).ok()?;
+                result.push(char::from_u32(code)?);
+            }
+            'u' => {
+                if chars.next()? != '{' {
+                    return None;
+                }
+
+                let mut digits = String::new();
+
+                loop {
+                    match chars.next()? {
+                        '}' => break,
+                        '_' => {}
+                        digit => digits.push(digit),
+                    }
+                }
+
+                let code = u32::from_str_radix(&digits, 16).ok()?;
+                result.push(char::from_u32(code)?);
+            }
+            // Line continuation: skip the line ending and leading whitespace.
+            '\n' => {
+                while matches!(chars.peek(), Some(char) if char.is_whitespace()) {
+                    chars.next();
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_values() {
+        assert_eq!(
+            string_value("\"a\""),
+            Some("a".into()),
+            "should support normal strings"
+        );
+        assert_eq!(
+            string_value("\"a\\n\\t\\\\\\\"\\x41\\u{1F600}\""),
+            Some("a\n\t\\\"A\u{1F600}".into()),
+            "should support escapes"
+        );
+        assert_eq!(
+            string_value("\"a\\\n    b\""),
+            Some("ab".into()),
+            "should support line continuations"
+        );
+        assert_eq!(
+            string_value("r\"a\\n\""),
+            Some("a\\n".into()),
+            "should support raw strings"
+        );
+        assert_eq!(
+            string_value("r##\"a\"#b\"##"),
+            Some("a\"#b".into()),
+            "should support raw strings w/ hashes"
+        );
+        assert_eq!(string_value("1"), None, "should not support numbers");
+        assert_eq!(
+            string_value("b\"a\""),
+            None,
+            "should not support byte strings"
+        );
+        assert_eq!(
+            string_value("r#\""),
+            None,
+            "should not crash on broken raw strings"
+        );
+    }
+}
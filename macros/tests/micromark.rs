@@ -0,0 +1,38 @@
+use markdown_macros::micromark;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn micromark() {
+    const HTML: &str = micromark!("# a");
+
+    assert_eq!(HTML, "<h1>a</h1>", "should support usage in a `const`");
+
+    assert_eq!(
+        micromark!("*a*\n\n- b\n- c"),
+        markdown::to_html("*a*\n\n- b\n- c"),
+        "should match `to_html`"
+    );
+
+    assert_eq!(
+        micromark!(r#"[a](b "c") <br> \*d\*"#),
+        "<p><a href=\"b\" title=\"c\">a</a> &lt;br&gt; *d*</p>",
+        "should support raw strings, and be safe by default"
+    );
+
+    assert_eq!(
+        micromark!(
+            "a\u{2014}b \
+                    c"
+        ),
+        "<p>a\u{2014}b c</p>",
+        "should support escapes and line continuations"
+    );
+
+    assert_eq!(micromark!(""), "", "should support empty markdown");
+
+    assert_eq!(
+        micromark!("~a~ www.b.com"),
+        "<p>~a~ www.b.com</p>",
+        "should not support GFM"
+    );
+}
@@ -0,0 +1,24 @@
+//! Check that `markdown-rs` builds without `std`.
+//!
+//! Build this for a target that has no `std`, such as
+//! `thumbv7em-none-eabihf`: if `markdown-rs` (or one of its dependencies)
+//! uses `std`, that fails.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+/// Turn markdown into HTML.
+pub fn to_html(value: &str) -> String {
+    markdown::to_html(value)
+}
+
+/// Turn markdown into an AST.
+///
+/// ## Errors
+///
+/// There are no errors with default options, but MDX can fail.
+pub fn to_mdast(value: &str) -> Result<markdown::mdast::Node, String> {
+    markdown::to_mdast(value, &markdown::ParseOptions::gfm())
+}
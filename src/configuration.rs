@@ -2,7 +2,7 @@ use crate::util::{
     line_ending::LineEnding,
     mdx::{EsmParse as MdxEsmParse, ExpressionParse as MdxExpressionParse},
 };
-use alloc::{boxed::Box, fmt, string::String};
+use alloc::{boxed::Box, fmt, string::String, vec, vec::Vec};
 
 /// Control which constructs are enabled.
 ///
@@ -32,6 +32,20 @@ use alloc::{boxed::Box, fmt, string::String};
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Constructs {
+    /// Abbreviation.
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// > | a HTML b
+    ///       ^^^^
+    /// ```
+    ///
+    /// This construct only affects HTML output.
+    /// mdast has no nodes for it: when turning markdown into a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast], abbreviation definitions are
+    /// dropped and abbreviations are turned into text.
+    pub abbreviation: bool,
     /// Attention.
     ///
     /// ```markdown
@@ -99,6 +113,22 @@ pub struct Constructs {
     ///     ^^^^^^^^^^
     /// ```
     pub definition: bool,
+    /// Definition list.
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// > | : b
+    ///     ^^^
+    /// ```
+    pub definition_list: bool,
+    /// Emoji.
+    ///
+    /// ```markdown
+    /// > | a :smile: b
+    ///       ^^^^^^^
+    /// ```
+    pub emoji: bool,
     /// Frontmatter.
     ///
     /// ````markdown
@@ -320,6 +350,15 @@ pub struct Constructs {
     /// > Otherwise, expressions are parsed with a basic algorithm that only
     /// > cares about braces.
     pub mdx_jsx_text: bool,
+    /// Percent comment (flow and text).
+    ///
+    /// ```markdown
+    /// > | a %%b%% c
+    ///       ^^^^^
+    /// ```
+    ///
+    /// Comments, as used in Obsidian, are not compiled to anything.
+    pub percent_comment: bool,
     /// Thematic break.
     ///
     /// ```markdown
@@ -340,6 +379,7 @@ impl Default for Constructs {
     /// <https://spec.commonmark.org>.
     fn default() -> Self {
         Self {
+            abbreviation: false,
             attention: true,
             autolink: true,
             block_quote: true,
@@ -349,6 +389,8 @@ impl Default for Constructs {
             code_fenced: true,
             code_text: true,
             definition: true,
+            definition_list: false,
+            emoji: false,
             frontmatter: false,
             gfm_autolink_literal: false,
             gfm_label_start_footnote: false,
@@ -373,6 +415,7 @@ impl Default for Constructs {
             mdx_expression_text: false,
             mdx_jsx_flow: false,
             mdx_jsx_text: false,
+            percent_comment: false,
             thematic_break: true,
         }
     }
@@ -432,6 +475,93 @@ impl Constructs {
             ..Self::default()
         }
     }
+
+    /// Text.
+    ///
+    /// This turns on a safe subset of `CommonMark` for short snippets, such
+    /// as a one-line field: attention (emphasis, strong), autolinks,
+    /// character escapes and references, code (text), hard breaks, and
+    /// links.
+    /// Everything else is turned off: block constructs (such as headings,
+    /// lists, and block quotes), definitions, images, and HTML.
+    ///
+    /// Input is still wrapped in a paragraph, unless
+    /// [`inline`][ParseOptions::inline] is passed in [`ParseOptions`][] too.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# *a* <b>",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::text(),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p># <em>a</em> &lt;b&gt;</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn text() -> Self {
+        Self {
+            block_quote: false,
+            code_fenced: false,
+            code_indented: false,
+            heading_atx: false,
+            heading_setext: false,
+            list_item: false,
+            thematic_break: false,
+            ..Self::flow()
+        }
+    }
+
+    /// Flow.
+    ///
+    /// This turns on `CommonMark`, except for constructs that can be unsafe
+    /// or that can affect the rest of a page when embedding markdown:
+    /// [`text`][Constructs::text], plus block quotes, code (fenced,
+    /// indented), headings, lists, and thematic breaks.
+    /// Definitions, images, and HTML are turned off.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a\n\n<b>",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::flow(),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1>a</h1>\n<p>&lt;b&gt;</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn flow() -> Self {
+        Self {
+            definition: false,
+            html_flow: false,
+            html_text: false,
+            label_start_image: false,
+            ..Self::default()
+        }
+    }
 }
 
 /// Configuration that describes how to compile to HTML.
@@ -466,7 +596,7 @@ impl Constructs {
 /// # }
 /// ```
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct CompileOptions {
     /// Whether to allow (dangerous) HTML.
     ///
@@ -550,425 +680,2194 @@ pub struct CompileOptions {
     /// ```
     pub allow_dangerous_protocol: bool,
 
-    /// Default line ending to use when compiling to HTML, for line endings not
-    /// in `value`.
+    /// Whether to allow (dangerous) HTML (flow).
     ///
-    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
-    /// the markdown document over to the compiled HTML.
-    /// In some cases, such as `> a`, CommonMark requires that extra line
-    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
+    /// The default is `false`.
+    /// Pass `true` to get actual HTML elements for HTML (flow), such as a
+    /// `<div>` on its own line, while HTML (text) is still shown as text.
     ///
-    /// To create that line ending, the document is checked for the first line
-    /// ending that is used.
-    /// If there is no line ending, `default_line_ending` is used.
-    /// If that isn’t configured, `\n` is used.
+    /// This option does nothing if `allow_dangerous_html` is turned on, as
+    /// that option allows both HTML (flow) and HTML (text).
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` uses `\n` by default:
-    /// assert_eq!(
-    ///     to_html("> a"),
-    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
-    /// );
-    ///
-    /// // Define `default_line_ending` to configure the default:
+    /// // Turn `allow_html_flow` on to allow HTML (flow) but not HTML (text):
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "> a",
+    ///         "<div>\n\nHi, <i>venus</i>!",
     ///         &Options {
     ///             compile: CompileOptions {
-    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               allow_html_flow: true,
     ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    ///     "<div>\n<p>Hi, &lt;i&gt;venus&lt;/i&gt;!</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub default_line_ending: LineEnding,
+    pub allow_html_flow: bool,
 
-    /// Textual label to use for the footnotes section.
+    /// Whether to allow (dangerous) HTML (text).
     ///
-    /// The default value is `"Footnotes"`.
-    /// Change it when the markdown is not in English.
+    /// The default is `false`.
+    /// Pass `true` to get actual HTML elements for HTML (text), such as an
+    /// `<i>` in a paragraph, while HTML (flow) is still shown as text.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// This option does nothing if `allow_dangerous_html` is turned on, as
+    /// that option allows both HTML (flow) and HTML (text).
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"Footnotes"` is used by default:
-    /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
-    /// );
-    ///
-    /// // Pass `gfm_footnote_label` to use something else:
+    /// // Turn `allow_html_text` on to allow HTML (text) but not HTML (flow):
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "<div>\n\nHi, <i>venus</i>!",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               allow_html_text: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "&lt;div&gt;\n<p>Hi, <i>venus</i>!</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label: Option<String>,
+    pub allow_html_text: bool,
 
-    /// HTML tag name to use for the footnote label element.
-    ///
-    /// The default value is `"h2"`.
-    /// Change it to match your document structure.
-    ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// Elements that markdown can turn into.
+    ///
+    /// The default is `None`, which allows all elements.
+    /// Pass a list of tag names (such as `"em"` or `"h1"`) to only generate
+    /// those elements.
+    /// This applies to every element that markdown generates.
+    /// Headings, code (flow), and footnote labels that are not allowed turn
+    /// into paragraphs (if `p` is allowed), and images that are not allowed
+    /// turn into their alt text.
+    /// Void elements that are not allowed (`br`, `hr`, and the `input` of
+    /// task list items) are dropped, as are footnote backreferences if `a` is
+    /// not allowed.
+    /// Other elements that are not allowed turn into their content.
+    ///
+    /// This does not affect HTML in markdown: see `allow_dangerous_html` for
+    /// that.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"h2"` is used by default:
+    /// // `markdown-rs` generates all elements by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("# *Hi*!"),
+    ///     "<h1><em>Hi</em>!</h1>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// // Pass `allowed_elements` to limit them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "# *Hi*!",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_tag_name: Some("h1".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               allowed_elements: Some(vec!["p".into(), "em".into()]),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><em>Hi</em>!</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_tag_name: Option<String>,
+    pub allowed_elements: Option<Vec<String>>,
 
-    /// Attributes to use on the footnote label.
-    ///
-    /// The default value is `"class=\"sr-only\""`.
-    /// Change it to show the label and add other attributes.
+    /// Base URL to resolve relative URLs against.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass an empty string.
-    /// You can also add different attributes.
+    /// The default is `None`, which keeps URLs as they are.
+    /// Pass a URL (such as `https://example.com/blog/`) to resolve the URLs
+    /// of links and images against it.
+    /// URLs that start with `#`, `/`, or `//`, or that have a protocol (such
+    /// as `https:`), are not relative and are kept as they are.
     ///
-    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
-    /// > calls use it with `aria-describedby` to provide an accessible label.
+    /// The URL of a directory should end in `/`: otherwise, its final
+    /// segment is treated as a file and dropped, as in browsers.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"class=\"sr-only\""` is used by default:
+    /// // `markdown-rs` keeps relative URLs by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("![a](./b.png)"),
+    ///     "<p><img src=\"./b.png\" alt=\"a\" /></p>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// // Pass `base_url` to resolve them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "![a](./b.png)",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               base_url: Some("https://example.com/blog/".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><img src=\"https://example.com/blog/b.png\" alt=\"a\" /></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_attributes: Option<String>,
+    pub base_url: Option<String>,
 
-    /// Textual label to describe the backreference back to footnote calls.
-    ///
-    /// The default value is `"Back to content"`.
-    /// Change it when the markdown is not in English.
+    /// Whether to add a `cite` attribute to block quotes that start with a
+    /// source.
     ///
-    /// This label is used in the `aria-label` attribute on each backreference
-    /// (the `↩` links).
-    /// It affects users of assistive technology.
+    /// The default is `false`.
+    /// Pass `true` to turn a first line of `Source:` followed by an autolink,
+    /// such as `> Source: <https://example.com>`, into the `cite` attribute
+    /// of the `<blockquote>`.
+    /// The line itself is kept.
+    /// Block quotes that do not start with such a line are not affected.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"Back to content"` is used by default:
+    /// // `markdown-rs` does not add `cite` by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("> Source: <https://example.com>\n> Hi!"),
+    ///     "<blockquote>\n<p>Source: <a href=\"https://example.com\">https://example.com</a>\nHi!</p>\n</blockquote>"
     /// );
     ///
-    /// // Pass `gfm_footnote_back_label` to use something else:
+    /// // Turn `block_quote_cite` on to add it:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "> Source: <https://example.com>\n> Hi!",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_back_label: Some("Arrière".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               block_quote_cite: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<blockquote cite=\"https://example.com\">\n<p>Source: <a href=\"https://example.com\">https://example.com</a>\nHi!</p>\n</blockquote>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_back_label: Option<String>,
+    pub block_quote_cite: bool,
 
-    /// Prefix to use before the `id` attribute on footnotes to prevent them
-    /// from *clobbering*.
-    ///
-    /// The default is `"user-content-"`.
-    /// Pass `Some("".into())` for trusted markdown and when you are careful
-    /// with polyfilling.
-    /// You could pass a different prefix.
-    ///
-    /// DOM clobbering is this:
+    /// Whether to generate canonical HTML, which is useful for snapshot
+    /// tests and diffs.
     ///
-    /// ```html
-    /// <p id="x"></p>
-    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
-    /// ```
+    /// The default is `false`.
+    /// Pass `true` to sort the attributes of each tag by name, and to put
+    /// each block element on its own line (also when `tight_output` is on).
+    /// Sorting makes the output the same regardless of which options added
+    /// the attributes, and in which order.
+    /// Attributes in raw HTML are sorted too, as long as their values are
+    /// double quoted.
     ///
-    /// The above example shows that elements are made available by browsers,
-    /// by their ID, on the `window` object.
-    /// This is a security risk because you might be expecting some other
-    /// variable at that place.
-    /// It can also break polyfills.
-    /// Using a prefix solves these problems.
+    /// Canonical output is stable across versions of `markdown-rs` with the
+    /// same options, but it is not meant to be byte-identical to what
+    /// `github.com` or other markdown parsers produce.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `"user-content-"` is used by default:
+    /// // `markdown-rs` generates attributes in a fixed, but unsorted, order by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("![a](b \"c\")"),
+    ///     "<p><img src=\"b\" alt=\"a\" title=\"c\" /></p>"
     /// );
     ///
-    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// // Turn `canonical` on to sort them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "![a](b \"c\")",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_clobber_prefix: Some("".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               canonical: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><img alt=\"a\" src=\"b\" title=\"c\" /></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_clobber_prefix: Option<String>,
+    pub canonical: bool,
 
-    /// Whether to support the GFM tagfilter.
+    /// Whether to wrap each line of code (fenced, indented) in a `span`.
     ///
-    /// This option does nothing if `allow_dangerous_html` is not turned on.
-    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
-    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    /// The default is `false`.
+    /// Pass `true` to wrap each line in
+    /// `<span class="line" data-line="N">`, where `N` is the line number
+    /// (starting at `1`), which can be used to number lines with CSS.
     ///
-    /// The tagfilter is kinda weird and kinda useless.
-    /// The tag filter is a naïve attempt at XSS protection.
-    /// You should use a proper HTML sanitizing algorithm instead.
+    /// Line endings are kept outside of these elements.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// // `markdown-rs` does not wrap lines by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "<iframe>",
+    ///     to_html("```\na\nb\n```"),
+    ///     "<pre><code>a\nb\n</code></pre>"
+    /// );
+    ///
+    /// // Turn `code_line_numbers` on to wrap lines:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "```\na\nb\n```",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               allow_dangerous_html: true,
+    ///               code_line_numbers: true,
     ///               ..CompileOptions::default()
-    ///             }
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<iframe>"
+    ///     "<pre><code><span class=\"line\" data-line=\"1\">a</span>\n<span class=\"line\" data-line=\"2\">b</span>\n</code></pre>"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_line_numbers: bool,
+
+    /// Whether to expose the meta of code (fenced) as an attribute.
     ///
-    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// The info string of code (fenced) is split into two parts: the first
+    /// word, the language, which becomes the `language-*` class, and the
+    /// rest, the meta, such as `title="x"` in ` ```js title="x" `.
+    ///
+    /// The default is `false`, which drops the meta.
+    /// Pass `true` to add it, as is, as a `data-meta` attribute on `<code>`,
+    /// so that it can be handled later (such as by a syntax highlighter or
+    /// in the browser).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` drops the meta by default:
+    /// assert_eq!(
+    ///     to_html("```js title=\"a.js\"\nb\n```"),
+    ///     "<pre><code class=\"language-js\">b\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `code_meta_attribute: true` to keep it:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "<iframe>",
+    ///         "```js title=\"a.js\"\nb\n```",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               allow_dangerous_html: true,
-    ///               gfm_tagfilter: true,
+    ///               code_meta_attribute: true,
     ///               ..CompileOptions::default()
-    ///             }
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "&lt;iframe>"
+    ///     "<pre><code class=\"language-js\" data-meta=\"title=&quot;a.js&quot;\">b\n</code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub code_meta_attribute: bool,
+
+    /// Whether to collect stats on how much work parsing takes.
+    ///
+    /// The default is `false`, which counts nothing.
+    /// Pass `true` to get [`Stats`][crate::Stats] from
+    /// [`to_html_with_output()`][crate::to_html_with_output], such as the
+    /// number of events and the number of constructs attempted, which is
+    /// useful for profiling.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_output, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` does not collect stats by default:
+    /// assert_eq!(to_html_with_output("# a", &Options::default())?.stats, None);
+    ///
+    /// // Pass `collect_stats: true` to collect them:
+    /// let stats = to_html_with_output(
+    ///     "# a",
+    ///     &Options {
+    ///         compile: CompileOptions {
+    ///           collect_stats: true,
+    ///           ..CompileOptions::default()
+    ///         },
+    ///         ..Options::default()
+    ///     }
+    /// )?.stats.unwrap();
+    ///
+    /// assert_eq!(stats.bytes, 3);
+    /// assert_eq!(stats.events, 10);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub collect_stats: bool,
+
+    /// Class to add to code (text).
+    ///
+    /// The default is `None`, which adds no class to `<code>` elements of code
+    /// (text).
+    /// Pass a class (such as `language-rust`) to add it to all of them, which
+    /// is useful to highlight them.
+    /// This option does not affect code (fenced, indented) or math.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds no class to code (text) by default:
+    /// assert_eq!(
+    ///     to_html("`a`"),
+    ///     "<p><code>a</code></p>"
+    /// );
+    ///
+    /// // Pass `default_inline_code_class` to add one:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "`a`",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               default_inline_code_class: Some("language-rust".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><code class=\"language-rust\">a</code></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub default_inline_code_class: Option<String>,
+
+    /// Default line ending to use when compiling to HTML, for line endings not
+    /// in `value`.
+    ///
+    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
+    /// the markdown document over to the compiled HTML.
+    /// That includes line endings in text, such as after hard breaks
+    /// (`<br />`), which are never replaced by `default_line_ending`.
+    /// In some cases, such as `> a`, CommonMark requires that extra line
+    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
+    ///
+    /// To create that line ending, the document is checked for the first line
+    /// ending that is used.
+    /// If there is no line ending, `default_line_ending` is used.
+    /// If that isn’t configured, `\n` is used.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` uses `\n` by default:
+    /// assert_eq!(
+    ///     to_html("> a"),
+    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
+    /// );
+    ///
+    /// // Define `default_line_ending` to configure the default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "> a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub default_line_ending: LineEnding,
+
+    /// HTML tag name to use for emphasis.
+    ///
+    /// The default value is `"em"`.
+    /// Change it to use a different element, such as `"i"`.
+    /// Invalid tag names (such as `"i onclick=x"`) are an error.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"em"` is used by default:
+    /// assert_eq!(
+    ///     to_html("*a*"),
+    ///     "<p><em>a</em></p>"
+    /// );
+    ///
+    /// // Pass `emphasis_tag_name` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "*a*",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               emphasis_tag_name: Some("i".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><i>a</i></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub emphasis_tag_name: Option<String>,
+
+    /// Whether to percent-encode URLs of links, images, and autolinks.
+    ///
+    /// The default is `true`: characters that are not allowed in URLs, such
+    /// as spaces and non-ASCII characters, are percent-encoded (so `a b`
+    /// becomes `a%20b`).
+    /// Sequences that are already percent-encoded (such as `%20`) are kept
+    /// as they are, so they are never encoded twice.
+    ///
+    /// Pass `false` to keep URLs as they are in the markdown.
+    /// Characters that are unsafe in HTML (such as `"` and `&`) are still
+    /// encoded as character references, and
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// still applies.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` percent-encodes URLs by default:
+    /// assert_eq!(
+    ///     to_html("[a](<b c> \"d\")"),
+    ///     "<p><a href=\"b%20c\" title=\"d\">a</a></p>"
+    /// );
+    ///
+    /// // Turn `encode_urls` off to keep them as they are:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](<b c> \"d\")",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               encode_urls: false,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"b c\" title=\"d\">a</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub encode_urls: bool,
+
+    /// Value of the `rel` attribute to add to external links.
+    ///
+    /// Links are external if their URL has a host, such as
+    /// `https://example.com` or `//example.com`.
+    /// Relative links (such as `/a`) and fragments (such as `#a`) are not
+    /// external.
+    ///
+    /// The default is `None`, which adds no `rel` attribute.
+    /// Pass something like `Some("nofollow noopener".into())` for
+    /// user-generated content.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds no `rel` by default:
+    /// assert_eq!(
+    ///     to_html("[a](https://b) [c](#d)"),
+    ///     "<p><a href=\"https://b\">a</a> <a href=\"#d\">c</a></p>"
+    /// );
+    ///
+    /// // Pass `external_link_rel` to add one to external links:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://b) [c](#d)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_link_rel: Some("nofollow noopener".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://b\" rel=\"nofollow noopener\">a</a> <a href=\"#d\">c</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub external_link_rel: Option<String>,
+
+    /// Value of the `target` attribute to add to external links.
+    ///
+    /// Links are external if their URL has a host, see
+    /// [`external_link_rel`][CompileOptions::external_link_rel].
+    ///
+    /// The default is `None`, which adds no `target` attribute.
+    /// Pass `Some("_blank".into())` to open external links in a new tab.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds no `target` by default:
+    /// assert_eq!(
+    ///     to_html("<https://b>"),
+    ///     "<p><a href=\"https://b\">https://b</a></p>"
+    /// );
+    ///
+    /// // Pass `external_link_target` to add one to external links:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<https://b>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               external_link_target: Some("_blank".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://b\" target=\"_blank\">https://b</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub external_link_target: Option<String>,
+
+    /// Whether to always compile lists as tight.
+    ///
+    /// The default is `false`, which wraps the content of list items in
+    /// paragraphs (`<p>`) when a list is loose, that is, when there are blank
+    /// lines between its items or their content.
+    /// Pass `true` to never add those paragraphs, as if every list was tight.
+    ///
+    /// This only affects HTML: lists are still marked as spread in mdast.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` wraps items in loose lists in paragraphs by default:
+    /// assert_eq!(
+    ///     to_html("* a\n\n* b"),
+    ///     "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ul>"
+    /// );
+    ///
+    /// // Pass `force_tight_lists` to compile them as tight lists:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "* a\n\n* b",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               force_tight_lists: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li>a</li>\n<li>b</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub force_tight_lists: bool,
+
+    /// Textual label to use for the footnotes section.
+    ///
+    /// The default value is `"Footnotes"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"Footnotes"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label: Option<String>,
+
+    /// HTML tag name to use for the footnote label element.
+    ///
+    /// The default value is `"h2"`.
+    /// Change it to match your document structure.
+    /// Invalid tag names (such as `"h2 onclick=x"`) are an error.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"h2"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_tag_name: Some("h1".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_tag_name: Option<String>,
+
+    /// Attributes to use on the footnote label.
+    ///
+    /// The default value is `"class=\"sr-only\""`.
+    /// Change it to show the label and add other attributes.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass an empty string.
+    /// You can also add different attributes.
+    ///
+    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
+    /// > calls use it with `aria-describedby` to provide an accessible label.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"class=\"sr-only\""` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_attributes: Option<String>,
+
+    /// Textual label to describe the backreference back to footnote calls.
+    ///
+    /// The default value is `"Back to content"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is used in the `aria-label` attribute on each backreference
+    /// (the `↩` links).
+    /// It affects users of assistive technology.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"Back to content"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_back_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_back_label: Some("Arrière".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_back_label: Option<String>,
+
+    /// Prefix to use before the `id` attribute on footnotes to prevent them
+    /// from *clobbering*.
+    ///
+    /// The default is `"user-content-"`.
+    /// Pass `Some("".into())` for trusted markdown and when you are careful
+    /// with polyfilling.
+    /// You could pass a different prefix.
+    ///
+    /// DOM clobbering is this:
+    ///
+    /// ```html
+    /// <p id="x"></p>
+    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
+    /// ```
+    ///
+    /// The above example shows that elements are made available by browsers,
+    /// by their ID, on the `window` object.
+    /// This is a security risk because you might be expecting some other
+    /// variable at that place.
+    /// It can also break polyfills.
+    /// Using a prefix solves these problems.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"user-content-"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_clobber_prefix: Some("".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_clobber_prefix: Option<String>,
+
+    /// Template to use for GFM footnote calls (references).
+    ///
+    /// The default is `None`, which generates a superscript link, like how
+    /// `github.com` works:
+    /// `<sup><a href="#{id}" id="{ref_id}" data-footnote-ref=""
+    /// aria-describedby="footnote-label">{index}</a></sup>`.
+    /// Pass a string to generate something else, such as to match the styles
+    /// of a site.
+    ///
+    /// These placeholders are replaced:
+    ///
+    /// *   `{index}`
+    ///     — the number of the footnote, starting at `1`
+    /// *   `{id}`
+    ///     — the `id` of the footnote definition, such as
+    ///     `user-content-fn-a`
+    /// *   `{ref_id}`
+    ///     — the `id` of this call, such as `user-content-fnref-a` (or
+    ///     `user-content-fnref-a-2` when called again), which the back
+    ///     references in the footnote section link to
+    ///
+    /// The values are encoded, so they are safe to use in text and in double
+    /// quoted attributes.
+    /// The rest of the template is used as-is (it is not encoded), so only
+    /// use templates you trust.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // A superscript link is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_call_template` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_call_template: Some("<a class=\"note\" href=\"#{id}\" id=\"{ref_id}\">[{index}]</a>".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><a class=\"note\" href=\"#user-content-fn-a\" id=\"user-content-fnref-a\">[1]</a></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_call_template: Option<String>,
+
+    /// Whether to support the GFM tagfilter.
+    ///
+    /// This option does nothing if HTML is not allowed (with
+    /// `allow_dangerous_html`, `allow_html_flow`, or `allow_html_text`).
+    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
+    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    ///
+    /// The tagfilter is kinda weird and kinda useless.
+    /// The tag filter is a naïve attempt at XSS protection.
+    /// You should use a proper HTML sanitizing algorithm instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<iframe>"
+    /// );
+    ///
+    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               gfm_tagfilter: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "&lt;iframe>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## References
+    ///
+    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
+    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
+    pub gfm_tagfilter: bool,
+
+    /// Whether to turn soft line endings in text into hard breaks.
+    ///
+    /// The default is `false`, which keeps line endings in paragraphs (and
+    /// setext headings) as they are, like `CommonMark` does.
+    /// Pass `true` to emit a `<br />` before each of them, which is how
+    /// for example comments on GitHub treat line endings.
+    ///
+    /// Line endings in code, and line endings between blocks, are not
+    /// affected.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps soft line endings by default:
+    /// assert_eq!(
+    ///     to_html("a\nb"),
+    ///     "<p>a\nb</p>"
+    /// );
+    ///
+    /// // Pass `hard_break_all: true` to turn them into hard breaks:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               hard_break_all: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br />\nb</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub hard_break_all: bool,
+
+    /// Number to shift the rank of headings by.
+    ///
+    /// The default is `0`, which keeps headings as they are.
+    /// Pass a positive number to turn headings into less important ones, such
+    /// as `1` to turn `# a` into `<h2>a</h2>`, which is useful when embedding
+    /// content in an existing section, or a negative number to turn them into
+    /// more important ones.
+    /// The result is clamped to `1..=6`, so `###### a` with an offset of `1`
+    /// is still `<h6>a</h6>`.
+    ///
+    /// This applies to both headings (atx) and headings (setext).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps heading ranks by default:
+    /// assert_eq!(to_html("# a"), "<h1>a</h1>");
+    ///
+    /// // Pass `heading_offset` to shift them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_offset: 2,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h3>a</h3>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_offset: i8,
+
+    /// What to do with images that have an empty alt.
+    ///
+    /// An image with an empty alt, such as `![](a.png)`, is valid: it marks
+    /// the image as decorative.
+    /// Some accessibility checkers want such images to be explicitly
+    /// presentational, or left out entirely.
+    ///
+    /// The default is [`ImageEmptyAlt::Keep`][], which renders them as
+    /// normal images.
+    /// Images with a non-empty alt are not affected.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, ImageEmptyAlt, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps images with an empty alt by default:
+    /// assert_eq!(
+    ///     to_html("![](a.png)"),
+    ///     "<p><img src=\"a.png\" alt=\"\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_empty_alt: ImageEmptyAlt::Presentation` to add a role:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![](a.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_empty_alt: ImageEmptyAlt::Presentation,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><img src=\"a.png\" alt=\"\" role=\"presentation\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_empty_alt: ImageEmptyAlt::Drop` to leave them out:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a ![](b.png) c",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_empty_alt: ImageEmptyAlt::Drop,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a  c</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub image_empty_alt: ImageEmptyAlt,
+
+    /// Class to use on math (flow).
+    ///
+    /// This option does nothing if `math_flow` is not turned on in
+    /// `constructs` (in `parse`).
+    ///
+    /// The default is `None`, which uses `"language-math math-display"` on
+    /// `<code>`, or `"math math-display"` if
+    /// [`math_flow_tag_name`][Self::math_flow_tag_name] is set.
+    /// Pass a value to use a different class, such as one that a client-side
+    /// math renderer looks for.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // Pass `math_flow_class` to use a different class:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$$\na^2\n$$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_flow: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               math_flow_class: Some("katex-display".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"katex-display\">a^2\n</code></pre>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub math_flow_class: Option<String>,
+
+    /// HTML tag name to use for math (flow), instead of `<pre><code>`.
+    ///
+    /// This option does nothing if `math_flow` is not turned on in
+    /// `constructs` (in `parse`).
+    ///
+    /// The default is `None`, which wraps math (flow) in `<pre><code>`, like
+    /// code (fenced).
+    /// Pass a value, such as `"div"`, to wrap the raw (but escaped) LaTeX in
+    /// one element instead, which is what client-side renderers such as
+    /// `KaTeX` typically expect for display math.
+    /// The class defaults to `"math math-display"` then, see
+    /// [`math_flow_class`][Self::math_flow_class] to change it.
+    /// Invalid tag names (such as `"div onclick=x"`) are an error.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `<pre><code>` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$$\na < b\n$$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_flow: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"language-math math-display\">a &lt; b\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `math_flow_tag_name` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "$$\na < b\n$$",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 math_flow: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               math_flow_tag_name: Some("div".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///         }
+    ///     )?,
+    ///     "<div class=\"math math-display\">a &lt; b\n</div>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub math_flow_tag_name: Option<String>,
+
+    /// Maximum length of destinations (URLs) of links and images, in bytes.
+    ///
+    /// The default is `None`, which allows destinations of any length.
+    /// Pass a number to drop longer destinations, such as giant data URIs:
+    /// links with them are generated as their text, and images as their
+    /// alt.
+    /// The length is that of the destination as written (after character
+    /// escapes and character references), before `base_url` is applied and
+    /// before it is sanitized.
+    ///
+    /// This applies to resources (`[a](b)`) and references to definitions
+    /// (`[a]`), but not to autolinks (`<https://a>`).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` allows destinations of any length by default:
+    /// assert_eq!(
+    ///     to_html("[a](https://example.com) ![b](https://example.com/c.png)"),
+    ///     "<p><a href=\"https://example.com\">a</a> <img src=\"https://example.com/c.png\" alt=\"b\" /></p>"
+    /// );
+    ///
+    /// // Pass `max_url_length` to drop longer ones:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a](https://example.com) ![b](https://example.com/c.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               max_url_length: Some(20),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"https://example.com\">a</a> b</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub max_url_length: Option<usize>,
+
+    /// Whether to collapse runs of whitespace in text.
+    ///
+    /// The default is `false`, which keeps spaces and tabs in text as they
+    /// are in the markdown.
+    /// Pass `true` to collapse each run of spaces and tabs into one space,
+    /// which matches how browsers render text, and which can help when
+    /// diffing output.
+    ///
+    /// Runs are also collapsed when they are split by character references
+    /// (such as `a&#32; b`) or by content that is left out.
+    /// Whitespace in code (text) and code (flow) is not affected.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` keeps whitespace by default:
+    /// assert_eq!(
+    ///     to_html("a  \tb"),
+    ///     "<p>a  \tb</p>"
+    /// );
+    ///
+    /// // Pass `normalize_whitespace: true` to collapse it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a  \tb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               normalize_whitespace: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a b</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub normalize_whitespace: bool,
+
+    /// What to do with HTML that is not allowed.
+    ///
+    /// The default is `DisallowedHtml::Escape`, which shows HTML (flow) and
+    /// HTML (text) as text, when it is not allowed with
+    /// `allow_dangerous_html`, `allow_html_flow`, or `allow_html_text`.
+    /// Pass `DisallowedHtml::Remove` to leave it out, or
+    /// `DisallowedHtml::Keep` to pass it through untouched.
+    ///
+    /// HTML in image text ends up in the `alt` attribute, so it’s never kept
+    /// there.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, DisallowedHtml, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` escapes HTML by default:
+    /// assert_eq!(
+    ///     to_html("Hi, <i>venus</i>!"),
+    ///     "<p>Hi, &lt;i&gt;venus&lt;/i&gt;!</p>"
+    /// );
+    ///
+    /// // Pass `on_disallowed_html: DisallowedHtml::Remove` to leave it out:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "Hi, <i>venus</i>!",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               on_disallowed_html: DisallowedHtml::Remove,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>Hi, venus!</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub on_disallowed_html: DisallowedHtml,
+
+    /// HTML tag name to use for strong.
+    ///
+    /// The default value is `"strong"`.
+    /// Change it to use a different element, such as `"b"`.
+    /// Invalid tag names (such as `"b onclick=x"`) are an error.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `"strong"` is used by default:
+    /// assert_eq!(
+    ///     to_html("**a**"),
+    ///     "<p><strong>a</strong></p>"
+    /// );
+    ///
+    /// // Pass `strong_tag_name` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "**a**",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               strong_tag_name: Some("b".into()),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><b>a</b></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strong_tag_name: Option<String>,
+
+    /// Attributes to use on thematic breaks.
+    ///
+    /// The default is no attributes.
+    /// Pass a list of name and value pairs to add them to each `<hr>`.
+    /// Names are used as they are, values are encoded.
+    /// Invalid names (such as `a onclick` or `a"`) are skipped.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` adds no attributes to thematic breaks by default:
+    /// assert_eq!(
+    ///     to_html("***"),
+    ///     "<hr />"
+    /// );
+    ///
+    /// // Pass `thematic_break_attributes` to add some:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "***",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               thematic_break_attributes: vec![("class".into(), "divider".into())],
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<hr class=\"divider\" />"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub thematic_break_attributes: Vec<(String, String)>,
+
+    /// Whether to leave out line endings between blocks.
+    ///
+    /// The default is `false`, which puts a line ending between block
+    /// elements (such as between `</p>` and `<p>`, and between `<ul>` and
+    /// `<li>`), which is easier to read.
+    /// Pass `true` to leave those out, which results in smaller (minified)
+    /// HTML.
+    ///
+    /// Line endings in content, such as in paragraphs and code (flow), are
+    /// kept.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` puts line endings between blocks by default:
+    /// assert_eq!(
+    ///     to_html("# a\n\n* b\n* c"),
+    ///     "<h1>a</h1>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>"
+    /// );
+    ///
+    /// // Pass `tight_output: true` to leave them out:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a\n\n* b\n* c",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               tight_output: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1>a</h1><ul><li>b</li><li>c</li></ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub tight_output: bool,
+
+    /// Whether to generate a table of contents.
+    ///
+    /// The default is `false`.
+    /// Pass `true` to give headings an `id` (a slug of their text, such as
+    /// `hello-world` for `Hello, World!`), and to generate a nested list of
+    /// links to them.
+    /// That list is available as [`HtmlOutput.toc`][crate::HtmlOutput] from
+    /// [`to_html_with_output()`][crate::to_html_with_output()].
+    ///
+    /// When headings have the same text, numbers are added to their slugs
+    /// (`a`, `a-1`, `a-2`).
+    /// When levels are skipped (such as a `h3` after a `h1`), the heading is
+    /// nested only one level deeper.
+    /// Which headings are included can be configured with
+    /// [`toc_min_depth`][CompileOptions::toc_min_depth] and
+    /// [`toc_max_depth`][CompileOptions::toc_max_depth].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_output, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let result = to_html_with_output(
+    ///     "# Mercury\n\n## Orbit",
+    ///     &Options {
+    ///         compile: CompileOptions {
+    ///           toc: true,
+    ///           ..CompileOptions::default()
+    ///         },
+    ///         ..Options::default()
+    ///     }
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     result.value,
+    ///     "<h1 id=\"mercury\">Mercury</h1>\n<h2 id=\"orbit\">Orbit</h2>"
+    /// );
+    /// assert_eq!(
+    ///     result.toc,
+    ///     Some("<ul>\n<li><a href=\"#mercury\">Mercury</a>\n<ul>\n<li><a href=\"#orbit\">Orbit</a></li>\n</ul>\n</li>\n</ul>".into())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub toc: bool,
+
+    /// Deepest heading level to include in the table of contents.
+    ///
+    /// The default is `6`, which includes all headings.
+    /// Pass a lower number, such as `3`, to leave out `h4`, `h5`, and `h6`.
+    /// Headings that are not included do not get an `id`.
+    ///
+    /// This option does nothing if [`toc`][CompileOptions::toc] is off.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_output, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let result = to_html_with_output(
+    ///     "# a\n\n## b",
+    ///     &Options {
+    ///         compile: CompileOptions {
+    ///           toc: true,
+    ///           toc_max_depth: 1,
+    ///           ..CompileOptions::default()
+    ///         },
+    ///         ..Options::default()
+    ///     }
+    /// )?;
+    ///
+    /// assert_eq!(result.value, "<h1 id=\"a\">a</h1>\n<h2>b</h2>");
+    /// assert_eq!(
+    ///     result.toc,
+    ///     Some("<ul>\n<li><a href=\"#a\">a</a></li>\n</ul>".into())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub toc_max_depth: u8,
+
+    /// Shallowest heading level to include in the table of contents.
+    ///
+    /// The default is `1`, which includes all headings.
+    /// Pass a higher number, such as `2`, to leave out `h1` (typically the
+    /// title of a document).
+    /// Headings that are not included do not get an `id`.
+    ///
+    /// This option does nothing if [`toc`][CompileOptions::toc] is off.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_output, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let result = to_html_with_output(
+    ///     "# a\n\n## b",
+    ///     &Options {
+    ///         compile: CompileOptions {
+    ///           toc: true,
+    ///           toc_min_depth: 2,
+    ///           ..CompileOptions::default()
+    ///         },
+    ///         ..Options::default()
+    ///     }
+    /// )?;
+    ///
+    /// assert_eq!(result.value, "<h1>a</h1>\n<h2 id=\"b\">b</h2>");
+    /// assert_eq!(
+    ///     result.toc,
+    ///     Some("<ul>\n<li><a href=\"#b\">b</a></li>\n</ul>".into())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub toc_min_depth: u8,
+
+    /// Whether to close void elements XHTML-style.
+    ///
+    /// Void elements, such as `<br>`, `<hr>`, `<img>`, and `<input>` (for GFM
+    /// task list items), cannot have content.
+    ///
+    /// The default is `true`, which closes them with a slash (`<br />`), which
+    /// works in both HTML and XHTML.
+    /// Pass `false` to not add that slash (`<br>`), which is how HTML5
+    /// typically writes them.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` closes void elements XHTML-style by default:
+    /// assert_eq!(
+    ///     to_html("a\\\nb\n\n***"),
+    ///     "<p>a<br />\nb</p>\n<hr />"
+    /// );
+    ///
+    /// // Pass `xhtml: false` to turn that off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\\\nb\n\n***",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               xhtml: false,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br>\nb</p>\n<hr>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub xhtml: bool,
+}
+
+impl Default for CompileOptions {
+    /// Safe defaults.
+    fn default() -> Self {
+        Self {
+            allow_dangerous_html: false,
+            allow_dangerous_protocol: false,
+            allow_html_flow: false,
+            allow_html_text: false,
+            allowed_elements: None,
+            base_url: None,
+            block_quote_cite: false,
+            canonical: false,
+            code_line_numbers: false,
+            code_meta_attribute: false,
+            collect_stats: false,
+            default_inline_code_class: None,
+            default_line_ending: LineEnding::default(),
+            emphasis_tag_name: None,
+            encode_urls: true,
+            external_link_rel: None,
+            external_link_target: None,
+            force_tight_lists: false,
+            gfm_footnote_label: None,
+            gfm_footnote_label_tag_name: None,
+            gfm_footnote_label_attributes: None,
+            gfm_footnote_back_label: None,
+            gfm_footnote_clobber_prefix: None,
+            gfm_footnote_call_template: None,
+            gfm_tagfilter: false,
+            hard_break_all: false,
+            heading_offset: 0,
+            image_empty_alt: ImageEmptyAlt::Keep,
+            math_flow_class: None,
+            math_flow_tag_name: None,
+            max_url_length: None,
+            normalize_whitespace: false,
+            on_disallowed_html: DisallowedHtml::Escape,
+            strong_tag_name: None,
+            thematic_break_attributes: Vec::new(),
+            tight_output: false,
+            toc: false,
+            toc_max_depth: 6,
+            toc_min_depth: 1,
+            xhtml: true,
+        }
+    }
+}
+
+impl CompileOptions {
+    /// GFM.
+    ///
+    /// GFM stands for **GitHub flavored markdown**.
+    /// On the compilation side, GFM turns on the GFM tag filter.
+    /// The tagfilter is useless, but it’s included here for consistency, and
+    /// this method exists for parity to parse options.
+    ///
+    /// For more information, see the GFM specification:
+    /// <https://github.github.com/gfm/>.
+    pub fn gfm() -> Self {
+        Self {
+            gfm_tagfilter: true,
+            ..Self::default()
+        }
+    }
+}
+
+/// What to do with images that have an empty alt.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ImageEmptyAlt;
+/// # fn main() {
+///
+/// // Use the default trait to render them as normal images:
+/// let keep = ImageEmptyAlt::default();
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ImageEmptyAlt {
+    /// Render them as normal images.
+    ///
+    /// Yields `<img src="a.png" alt="" />` for `![](a.png)`.
+    #[default]
+    Keep,
+    /// Render them with a `presentation` role.
+    ///
+    /// Yields `<img src="a.png" alt="" role="presentation" />` for
+    /// `![](a.png)`.
+    Presentation,
+    /// Leave them out.
+    ///
+    /// Yields nothing for `![](a.png)`.
+    /// Links that are empty because of this are left out too, so
+    /// `[![](a.png)](b)` also yields nothing.
+    /// Whitespace around dropped images is kept, so `a ![](b.png) c` yields
+    /// `a  c`.
+    Drop,
+}
+
+/// What to do with HTML that is not allowed.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::DisallowedHtml;
+/// # fn main() {
+///
+/// // Use the default trait to show it as text:
+/// let escape = DisallowedHtml::default();
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DisallowedHtml {
+    /// Show it as text.
+    ///
+    /// Yields `<p>&lt;b&gt;a&lt;/b&gt;</p>` for `<b>a</b>`.
+    #[default]
+    Escape,
+    /// Leave it out.
+    ///
+    /// Yields `<p>a</p>` for `<b>a</b>`.
+    Remove,
+    /// Pass it through untouched, as if it was allowed.
+    ///
+    /// Yields `<p><b>a</b></p>` for `<b>a</b>`.
+    Keep,
+}
+
+/// How much indentation content in list items needs.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ListIndent;
+/// # fn main() {
+///
+/// // Use the default trait to get `CommonMark` behavior:
+/// let mixed = ListIndent::default();
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ListIndent {
+    /// Indent to where the content on the first line starts, as in
+    /// `CommonMark`.
+    ///
+    /// ```markdown
+    /// > | -   a
+    ///     ^^^^
+    /// > |     b
+    ///     ^^^^
+    /// ```
+    #[default]
+    Mixed,
+    /// Indent by one space.
+    ///
+    /// ```markdown
+    /// > | -   a
+    /// > |  b
+    ///     ^
+    /// ```
+    One,
+    /// Indent to the next tab stop (a multiple of 4 columns), from where the
+    /// content on the first line starts.
+    ///
+    /// ```markdown
+    /// > | - a
+    /// > |     b
+    ///     ^^^^
+    /// ```
+    Tab,
+}
+
+/// Flow construct, to order in [`ParseOptions::flow_construct_order`][].
+///
+/// Containers (block quotes, list items, GFM footnote definitions), blank
+/// lines, and paragraphs are not included: containers are parsed before
+/// flow, blank lines are always tried first, and paragraphs last.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::FlowConstruct;
+/// # fn main() {
+///
+/// let thematic_break = FlowConstruct::ThematicBreak;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FlowConstruct {
+    /// Abbreviation (definition).
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Abbreviation,
+    /// Code (indented).
+    ///
+    /// ```markdown
+    /// > |     a
+    ///     ^^^^^
+    /// ```
+    CodeIndented,
+    /// Definition list (description).
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^^^
+    /// ```
+    DefinitionList,
+    /// GFM table.
+    ///
+    /// ```markdown
+    /// > | | a |
+    ///     ^^^^^
+    /// > | | - |
+    ///     ^^^^^
+    /// ```
+    GfmTable,
+    /// Heading (atx).
+    ///
+    /// ```markdown
+    /// > | # a
+    ///     ^^^
+    /// ```
+    HeadingAtx,
+    /// Heading (setext).
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | ---
+    ///     ^^^
+    /// ```
+    HeadingSetext,
+    /// HTML (flow).
+    ///
+    /// ```markdown
+    /// > | <div>
+    ///     ^^^^^
+    /// ```
+    HtmlFlow,
+    /// MDX ESM.
+    ///
+    /// ```markdown
+    /// > | import a from 'b'
+    ///     ^^^^^^^^^^^^^^^^^
+    /// ```
+    MdxEsm,
+    /// MDX expression (flow).
+    ///
+    /// ```markdown
+    /// > | {a}
+    ///     ^^^
+    /// ```
+    MdxExpressionFlow,
+    /// MDX JSX (flow).
+    ///
+    /// ```markdown
+    /// > | <A />
+    ///     ^^^^^
+    /// ```
+    MdxJsxFlow,
+    /// Percent comment (flow).
+    ///
+    /// ```markdown
+    /// > | %%
+    ///     ^^
+    /// > | a
+    ///     ^
+    /// > | %%
+    ///     ^^
+    /// ```
+    PercentComment,
+    /// Raw (flow): code (fenced) and math (flow).
+    ///
+    /// ````markdown
+    /// > | ```
+    ///     ^^^
+    /// > | a
+    ///     ^
+    /// > | ```
+    ///     ^^^
+    /// ````
+    RawFlow,
+    /// Thematic break.
+    ///
+    /// ```markdown
+    /// > | ***
+    ///     ^^^
+    /// ```
+    ThematicBreak,
+}
+
+/// Configuration that describes how to parse from markdown.
+///
+/// You can use this:
+///
+/// *   To control what markdown constructs are turned on and off
+/// *   To control some of those constructs
+/// *   To add support for certain programming languages when parsing MDX
+///
+/// In most cases, you will want to use the default trait or `gfm` method.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ParseOptions;
+/// # fn main() {
+///
+/// // Use the default trait to parse markdown according to `CommonMark`:
+/// let commonmark = ParseOptions::default();
+///
+/// // Use the `gfm` method to parse markdown according to GFM:
+/// let gfm = ParseOptions::gfm();
+/// # }
+/// ```
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseOptions {
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
+    /// Which constructs to enable and disable.
+    ///
+    /// The default is to follow `CommonMark`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` follows CommonMark by default:
+    /// assert_eq!(
+    ///     to_html("    indented code?"),
+    ///     "<pre><code>indented code?\n</code></pre>"
+    /// );
+    ///
+    /// // Pass `constructs` to choose what to enable and disable:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "    indented code?",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 code_indented: false,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>indented code?</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub constructs: Constructs,
+
+    /// Whether to relax emphasis and strong rules next to CJK characters.
+    ///
+    /// `CommonMark` decides whether a sequence of asterisks, underscores, or
+    /// tildes can open or close based on the characters around it.
+    /// Those rules assume words are separated by spaces, which is not the
+    /// case in Chinese, Japanese, or Korean.
+    /// For example, a closing `**` that is preceded by punctuation (such as
+    /// `：`) and followed by a CJK character (such as `こ`) cannot close
+    /// strong.
+    ///
+    /// The default is `false`, which follows `CommonMark`.
+    /// Pass `true` to also let such sequences open or close when the
+    /// character on the other side is CJK, which follows the
+    /// [CJK friendly amendments](https://github.com/tats-u/markdown-cjk-friendly).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` follows CommonMark by default:
+    /// assert_eq!(
+    ///     to_html("**この文を強調：**この後"),
+    ///     "<p>**この文を強調：**この後</p>"
+    /// );
+    ///
+    /// // Pass `cjk_friendly_emphasis: true` to relax that:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "**この文を強調：**この後",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               cjk_friendly_emphasis: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><strong>この文を強調：</strong>この後</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub cjk_friendly_emphasis: bool,
+
+    /// Extra emoji shortcodes.
+    ///
+    /// This option does nothing if `emoji` is not turned on in
+    /// `constructs`.
+    ///
+    /// Each tuple is a shortcode name (without `:`s) and the value to
+    /// replace it with.
+    /// These are checked before the built-in shortcodes, so they can also be
+    /// used to change what those are replaced with.
+    ///
+    /// The default is an empty list, which supports only the built-in
+    /// shortcodes.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` supports common shortcodes when `emoji` is on:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         ":smile: :shipit:",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 emoji: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>😄 :shipit:</p>"
+    /// );
+    ///
+    /// // Pass `emoji_shortcodes` to support more:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         ":smile: :shipit:",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 emoji: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               emoji_shortcodes: vec![("shipit".into(), "🐿️".into())],
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>😄 🐿️</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    ///
-    /// ## References
-    ///
-    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
-    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
-    pub gfm_tagfilter: bool,
-}
-
-impl CompileOptions {
-    /// GFM.
-    ///
-    /// GFM stands for **GitHub flavored markdown**.
-    /// On the compilation side, GFM turns on the GFM tag filter.
-    /// The tagfilter is useless, but it’s included here for consistency, and
-    /// this method exists for parity to parse options.
-    ///
-    /// For more information, see the GFM specification:
-    /// <https://github.github.com/gfm/>.
-    pub fn gfm() -> Self {
-        Self {
-            gfm_tagfilter: true,
-            ..Self::default()
-        }
-    }
-}
+    pub emoji_shortcodes: Vec<(String, String)>,
 
-/// Configuration that describes how to parse from markdown.
-///
-/// You can use this:
-///
-/// *   To control what markdown constructs are turned on and off
-/// *   To control some of those constructs
-/// *   To add support for certain programming languages when parsing MDX
-///
-/// In most cases, you will want to use the default trait or `gfm` method.
-///
-/// ## Examples
-///
-/// ```
-/// use markdown::ParseOptions;
-/// # fn main() {
-///
-/// // Use the default trait to parse markdown according to `CommonMark`:
-/// let commonmark = ParseOptions::default();
-///
-/// // Use the `gfm` method to parse markdown according to GFM:
-/// let gfm = ParseOptions::gfm();
-/// # }
-/// ```
-#[allow(clippy::struct_excessive_bools)]
-pub struct ParseOptions {
-    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
-    /// Which constructs to enable and disable.
-    ///
-    /// The default is to follow `CommonMark`.
+    /// Order in which flow constructs are tried, at the start of a line.
+    ///
+    /// When several constructs could match a line, the first one in this
+    /// list wins.
+    /// The default order follows `CommonMark`:
+    ///
+    /// 1.  code (indented)
+    /// 2.  raw (flow): code (fenced), math (flow)
+    /// 3.  HTML (flow)
+    /// 4.  MDX JSX (flow)
+    /// 5.  heading (atx)
+    /// 6.  heading (setext)
+    /// 7.  definition list (description)
+    /// 8.  thematic break
+    /// 9.  abbreviation (definition)
+    /// 10. MDX ESM
+    /// 11. MDX expression (flow)
+    /// 12. percent comment (flow)
+    /// 13. GFM table
+    ///
+    /// Blank lines are always tried before these, and paragraphs after them.
+    /// Constructs left out of the list are never tried, and constructs that
+    /// are turned off in `constructs` are skipped.
+    /// Some lines can only start a few constructs, regardless of the order:
+    /// for example, a line starting with `#` is a heading (atx) or a
+    /// paragraph, and MDX ESM is only tried on lines starting with `e` or
+    /// `i`.
+    ///
+    /// Changing the order is mostly useful for ambiguous lines, such as `---`
+    /// after a paragraph, which is a heading (setext) underline by default,
+    /// but a thematic break when that comes first.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, Constructs, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, FlowConstruct, Options, ParseOptions};
     /// # fn main() -> Result<(), String> {
     ///
-    /// // `markdown-rs` follows CommonMark by default:
+    /// // `markdown-rs` follows `CommonMark` by default:
     /// assert_eq!(
-    ///     to_html("    indented code?"),
-    ///     "<pre><code>indented code?\n</code></pre>"
+    ///     to_html("a\n---"),
+    ///     "<h2>a</h2>"
     /// );
     ///
-    /// // Pass `constructs` to choose what to enable and disable:
+    /// // Move `ThematicBreak` before `HeadingSetext` to prefer it:
+    /// let mut flow_construct_order = ParseOptions::default().flow_construct_order;
+    /// flow_construct_order.retain(|d| *d != FlowConstruct::ThematicBreak);
+    /// flow_construct_order.insert(0, FlowConstruct::ThematicBreak);
+    ///
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "    indented code?",
+    ///         "a\n---",
     ///         &Options {
     ///             parse: ParseOptions {
-    ///               constructs: Constructs {
-    ///                 code_indented: false,
-    ///                 ..Constructs::default()
-    ///               },
+    ///               flow_construct_order,
     ///               ..ParseOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>indented code?</p>"
+    ///     "<p>a</p>\n<hr />"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub constructs: Constructs,
+    pub flow_construct_order: Vec<FlowConstruct>,
 
     /// Whether to support GFM strikethrough with a single tilde
     ///
@@ -1022,6 +2921,92 @@ pub struct ParseOptions {
     /// ```
     pub gfm_strikethrough_single_tilde: bool,
 
+    /// Whether to parse the input as a single run of text.
+    ///
+    /// The default is `false`, which parses the input as a document: made of
+    /// blocks (such as paragraphs and headings) that contain text.
+    /// Pass `true` to parse the whole input as text instead, which is useful
+    /// for inline snippets such as titles.
+    /// Things that only exist as blocks, such as headings or block quotes, are
+    /// then not recognized, and neither are definitions, so references
+    /// (such as `[a]`) do not form either.
+    /// See also [`to_html_inline()`][crate::to_html_inline].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` parses documents by default:
+    /// assert_eq!(
+    ///     to_html_with_options("# **hi**", &Options::default())?,
+    ///     "<h1><strong>hi</strong></h1>"
+    /// );
+    ///
+    /// // Pass `inline: true` to parse text:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# **hi**",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               inline: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "# <strong>hi</strong>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub inline: bool,
+
+    /// How much indentation content in list items needs.
+    ///
+    /// The default is [`ListIndent::Mixed`][], which follows `CommonMark`:
+    /// further lines must be indented to where the content on the first line
+    /// starts.
+    /// Pass [`ListIndent::One`][] to accept a single space, which is more
+    /// lenient with inconsistently indented lists.
+    /// Pass [`ListIndent::Tab`][] to require indenting to the next tab stop.
+    ///
+    /// Lines that are indented less are not part of the list item, but they
+    /// can still be lazy continuation of a paragraph, or the start of
+    /// another item.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, ListIndent, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` follows `CommonMark` by default:
+    /// assert_eq!(
+    ///     to_html("-   a\n\n  b"),
+    ///     "<ul>\n<li>a</li>\n</ul>\n<p>b</p>"
+    /// );
+    ///
+    /// // Pass `list_item_indent: ListIndent::One` to be more lenient:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "-   a\n\n  b",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               list_item_indent: ListIndent::One,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub list_item_indent: ListIndent,
+
     /// Whether to support math (text) with a single dollar
     ///
     /// This option does nothing if `math_text` is not turned on in
@@ -1108,6 +3093,101 @@ pub struct ParseOptions {
     /// For an example that adds support for JavaScript with SWC, see
     /// `tests/test_utils/mod.rs`.
     pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
+
+    /// Whether to error on ambiguous markdown.
+    ///
+    /// The default is `false`, which follows `CommonMark`: markdown never
+    /// errors, and things that do not form, turn into text.
+    /// Pass `true` to get an error instead, for authoring pipelines that
+    /// would rather catch mistakes.
+    /// The error includes the line and column of the problem.
+    /// Parsing stops at the first problem found.
+    ///
+    /// The following are errors in strict mode:
+    ///
+    /// *   attention (emphasis, strong, strikethrough) sequences that could
+    ///     open or close but are not matched (such as `*` in `*a`)
+    /// *   labels that match nothing (such as `[a]` or `[b][a]` without a
+    ///     definition for `a`)
+    /// *   code (fenced) and math (flow) without closing fence
+    ///
+    /// Use character escapes (such as `\*` and `\[`) for literal characters.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` is lenient by default:
+    /// assert_eq!(
+    ///     to_html("[a] b"),
+    ///     "<p>[a] b</p>"
+    /// );
+    ///
+    /// // Pass `strict: true` to error instead:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[a] b",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               strict: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )
+    ///     .unwrap_err(),
+    ///     "1:1: Unexpected reference to an undefined definition, expected a definition, a resource, or escaped brackets (strict:undefined-reference)"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strict: bool,
+
+    /// Whether to require a dot in the domain of email autolinks.
+    ///
+    /// This option does nothing if `autolink` is not turned on in
+    /// `constructs`.
+    /// It does not affect URL autolinks (such as `<https://a.b>`), or GFM
+    /// autolink literals.
+    ///
+    /// The default is `false`, which follows `CommonMark`: it allows domains
+    /// without dots, such as `<a@b>`, which are valid for local hosts but
+    /// rarely intended in prose.
+    /// Pass `true` to require at least one dot in the domain (such as
+    /// `<a@b.c>`).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // `markdown-rs` follows `CommonMark` by default:
+    /// assert_eq!(
+    ///     to_html("<a@b>"),
+    ///     "<p><a href=\"mailto:a@b\">a@b</a></p>"
+    /// );
+    ///
+    /// // Pass `strict_email_autolink: true` to require a dot:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<a@b>",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               strict_email_autolink: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>&lt;a@b&gt;</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strict_email_autolink: bool,
     // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
 }
 
@@ -1115,10 +3195,15 @@ impl fmt::Debug for ParseOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ParseOptions")
             .field("constructs", &self.constructs)
+            .field("cjk_friendly_emphasis", &self.cjk_friendly_emphasis)
+            .field("emoji_shortcodes", &self.emoji_shortcodes)
+            .field("flow_construct_order", &self.flow_construct_order)
             .field(
                 "gfm_strikethrough_single_tilde",
                 &self.gfm_strikethrough_single_tilde,
             )
+            .field("inline", &self.inline)
+            .field("list_item_indent", &self.list_item_indent)
             .field("math_text_single_dollar", &self.math_text_single_dollar)
             .field(
                 "mdx_expression_parse",
@@ -1128,6 +3213,8 @@ impl fmt::Debug for ParseOptions {
                 "mdx_esm_parse",
                 &self.mdx_esm_parse.as_ref().map(|_d| "[Function]"),
             )
+            .field("strict", &self.strict)
+            .field("strict_email_autolink", &self.strict_email_autolink)
             .finish()
     }
 }
@@ -1137,10 +3224,31 @@ impl Default for ParseOptions {
     fn default() -> Self {
         Self {
             constructs: Constructs::default(),
+            cjk_friendly_emphasis: false,
+            emoji_shortcodes: Vec::new(),
+            flow_construct_order: vec![
+                FlowConstruct::CodeIndented,
+                FlowConstruct::RawFlow,
+                FlowConstruct::HtmlFlow,
+                FlowConstruct::MdxJsxFlow,
+                FlowConstruct::HeadingAtx,
+                FlowConstruct::HeadingSetext,
+                FlowConstruct::DefinitionList,
+                FlowConstruct::ThematicBreak,
+                FlowConstruct::Abbreviation,
+                FlowConstruct::MdxEsm,
+                FlowConstruct::MdxExpressionFlow,
+                FlowConstruct::PercentComment,
+                FlowConstruct::GfmTable,
+            ],
             gfm_strikethrough_single_tilde: true,
+            inline: false,
+            list_item_indent: ListIndent::default(),
             math_text_single_dollar: true,
             mdx_expression_parse: None,
             mdx_esm_parse: None,
+            strict: false,
+            strict_email_autolink: false,
         }
     }
 }
@@ -1206,7 +3314,6 @@ impl ParseOptions {
 /// let gfm = Options::gfm();
 /// # }
 /// ```
-#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Default)]
 pub struct Options {
     /// Configuration that describes how to parse from markdown.
@@ -1224,8 +3331,45 @@ impl Options {
     /// On the compilation side, GFM turns on the GFM tag filter.
     /// The tagfilter is useless, but it’s included here for consistency.
     ///
+    /// This is a preset: it sets the individual fields, which can then be
+    /// changed.
+    /// Fields that are set explicitly take precedence over the preset.
+    ///
     /// For more information, see the GFM specification:
     /// <https://github.github.com/gfm/>
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, Options, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// // Use GFM:
+    /// assert_eq!(
+    ///     to_html_with_options("~a~ www.b.c", &Options::gfm())?,
+    ///     "<p><del>a</del> <a href=\"http://www.b.c\">www.b.c</a></p>"
+    /// );
+    ///
+    /// // Use GFM, but turn off one construct:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~ www.b.c",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///                 constructs: Constructs {
+    ///                     gfm_autolink_literal: false,
+    ///                     ..Constructs::gfm()
+    ///                 },
+    ///                 ..ParseOptions::gfm()
+    ///             },
+    ///             ..Options::gfm()
+    ///         }
+    ///     )?,
+    ///     "<p><del>a</del> www.b.c</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn gfm() -> Self {
         Self {
             parse: ParseOptions::gfm(),
@@ -1234,6 +3378,151 @@ impl Options {
     }
 }
 
+/// Configuration that describes how to serialize a syntax tree to markdown.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_markdown, to_mdast, ParseOptions, SerializeOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let tree = to_mdast("* a\n\n_b_", &ParseOptions::default())?;
+///
+/// // Use the default trait to get `*` for bullets and emphasis:
+/// assert_eq!(
+///     to_markdown(&tree, &SerializeOptions::default())?,
+///     "* a\n\n*b*\n"
+/// );
+///
+/// // Pass other markers to use them instead:
+/// assert_eq!(
+///     to_markdown(
+///         &tree,
+///         &SerializeOptions {
+///             bullet: '-',
+///             emphasis: '_',
+///             ..SerializeOptions::default()
+///         }
+///     )?,
+///     "- a\n\n_b_\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SerializeOptions {
+    /// Marker to use for bullets of unordered list items.
+    ///
+    /// Must be `*`, `+`, or `-`.
+    /// The default is `*`.
+    ///
+    /// When two unordered lists are next to each other, the second uses a
+    /// different marker, as otherwise they would form one list.
+    ///
+    /// ```markdown
+    /// > | * a
+    ///     ^
+    /// ```
+    pub bullet: char,
+
+    /// Marker to use for emphasis.
+    ///
+    /// Must be `*` or `_`.
+    /// The default is `*`.
+    ///
+    /// ```markdown
+    /// > | *a*
+    ///     ^ ^
+    /// ```
+    pub emphasis: char,
+
+    /// Marker to use for strong.
+    ///
+    /// Must be `*` or `_`.
+    /// The default is `*`.
+    ///
+    /// ```markdown
+    /// > | **a**
+    ///     ^^ ^^
+    /// ```
+    pub strong: char,
+
+    /// Whether to use fenced code.
+    ///
+    /// The default is `true`.
+    /// Pass `false` to use indented code when possible: that is, when there
+    /// is no info string, when the code is not empty, and when it does not
+    /// follow a list.
+    ///
+    /// ```markdown
+    /// > | ```js
+    ///     ^^^^^
+    ///   | a
+    /// > | ```
+    ///     ^^^
+    /// ```
+    pub fences: bool,
+}
+
+impl Default for SerializeOptions {
+    /// `CommonMark` defaults.
+    fn default() -> Self {
+        Self {
+            bullet: '*',
+            emphasis: '*',
+            strong: '*',
+            fences: true,
+        }
+    }
+}
+
+/// Configuration that describes how to turn markdown into speech segments.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_speech_segments, ParseOptions, Segment, SegmentKind, SpeechOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// // Use the default trait to speak the contents of code:
+/// assert_eq!(
+///     to_speech_segments("    a", &ParseOptions::default(), &SpeechOptions::default())?,
+///     vec![Segment { kind: SegmentKind::Code, value: "a".into() }]
+/// );
+///
+/// // Pass `summarize_code: true` to summarize it instead:
+/// assert_eq!(
+///     to_speech_segments(
+///         "    a",
+///         &ParseOptions::default(),
+///         &SpeechOptions {
+///             summarize_code: true,
+///             ..SpeechOptions::default()
+///         }
+///     )?,
+///     vec![Segment { kind: SegmentKind::Code, value: "code block, 1 line".into() }]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SpeechOptions {
+    /// Whether to replace the contents of code (flow) with a summary.
+    ///
+    /// The default is `false`, which speaks the contents.
+    /// Pass `true` to speak a summary such as `code block, 5 lines` instead.
+    ///
+    /// ```markdown
+    /// > | ~~~
+    ///     ^^^
+    /// > | a
+    ///     ^
+    /// > | ~~~
+    ///     ^^^
+    /// ```
+    pub summarize_code: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1245,6 +3534,8 @@ mod tests {
         Constructs::default();
         Constructs::gfm();
         Constructs::mdx();
+        Constructs::text();
+        Constructs::flow();
 
         let constructs = Constructs::default();
         assert!(constructs.attention, "should default to `CommonMark` (1)");
@@ -1281,6 +3572,21 @@ mod tests {
         );
         assert!(constructs.mdx_jsx_flow, "should support `mdx` shortcut (3)");
         assert!(!constructs.frontmatter, "should support `mdx` shortcut (4)");
+
+        let constructs = Constructs::text();
+        assert!(constructs.attention, "should support `text` shortcut (1)");
+        assert!(constructs.label_end, "should support `text` shortcut (2)");
+        assert!(
+            !constructs.heading_atx,
+            "should support `text` shortcut (3)"
+        );
+        assert!(!constructs.html_text, "should support `text` shortcut (4)");
+
+        let constructs = Constructs::flow();
+        assert!(constructs.attention, "should support `flow` shortcut (1)");
+        assert!(constructs.heading_atx, "should support `flow` shortcut (2)");
+        assert!(!constructs.html_flow, "should support `flow` shortcut (3)");
+        assert!(!constructs.definition, "should support `flow` shortcut (4)");
     }
 
     #[test]
@@ -1333,7 +3639,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", ParseOptions::default()),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None }",
+            "ParseOptions { constructs: Constructs { abbreviation: false, attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, definition_list: false, emoji: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, percent_comment: false, thematic_break: true }, cjk_friendly_emphasis: false, emoji_shortcodes: [], flow_construct_order: [CodeIndented, RawFlow, HtmlFlow, MdxJsxFlow, HeadingAtx, HeadingSetext, DefinitionList, ThematicBreak, Abbreviation, MdxEsm, MdxExpressionFlow, PercentComment, GfmTable], gfm_strikethrough_single_tilde: true, inline: false, list_item_indent: Mixed, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None, strict: false, strict_email_autolink: false }",
             "should support `Debug` trait"
         );
         assert_eq!(
@@ -1346,7 +3652,7 @@ mod tests {
                 })),
                 ..Default::default()
             }),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
+            "ParseOptions { constructs: Constructs { abbreviation: false, attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, definition_list: false, emoji: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, percent_comment: false, thematic_break: true }, cjk_friendly_emphasis: false, emoji_shortcodes: [], flow_construct_order: [CodeIndented, RawFlow, HtmlFlow, MdxJsxFlow, HeadingAtx, HeadingSetext, DefinitionList, ThematicBreak, Abbreviation, MdxEsm, MdxExpressionFlow, PercentComment, GfmTable], gfm_strikethrough_single_tilde: true, inline: false, list_item_indent: Mixed, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\"), strict: false, strict_email_autolink: false }",
             "should support `Debug` trait on mdx functions"
         );
     }
@@ -0,0 +1,360 @@
+//! Abbreviations occur in the [flow][] content type (definitions) and in the
+//! [text][] content type (abbreviations).
+//!
+//! ## Grammar
+//!
+//! Abbreviation definitions form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! abbreviation_definition ::= '*' '[' label ']' ':' [space_or_tab] [value]
+//!
+//! ; Restriction: maximum `3` leading spaces.
+//! label ::= 1*(byte - eol - ']')
+//! value ::= 1*(byte - eol)
+//! ```
+//!
+//! As this construct occurs in flow, like all flow constructs, it must be
+//! followed by an eol (line ending) or eof (end of file).
+//!
+//! Abbreviation definitions cannot interrupt paragraphs.
+//!
+//! Abbreviations themselves are not parsed with a grammar: after all flow is
+//! parsed, every text that matches the label of a definition is marked.
+//! Only standalone occurrences are marked: the characters before and after
+//! the match cannot be alphanumeric or `_`.
+//! When several labels match at the same place, the longest one is used.
+//! Labels are case-sensitive.
+//! Abbreviations are not found in code (text), autolinks, or links.
+//!
+//! Like definitions, when an abbreviation is defined several times, the first
+//! definition is used.
+//!
+//! ## HTML
+//!
+//! Abbreviation definitions do not relate to anything in HTML.
+//! Abbreviations relate to the `<abbr>` element in HTML, with the value of
+//! the definition as its `title` attribute.
+//! See [*§ 4.5.9 The `abbr` element*][html-abbr] in the HTML spec for more
+//! info.
+//!
+//! ## Recommendation
+//!
+//! Abbreviations are not part of `CommonMark` or GFM.
+//! They come from PHP Markdown Extra.
+//! They are mostly useful for technical writing that uses a lot of acronyms.
+//! They are HTML-only: mdast does not support them, so definitions are
+//! dropped and abbreviations are turned into text there.
+//!
+//! ## Tokens
+//!
+//! *   [`Abbreviation`][Name::Abbreviation]
+//! *   [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+//! *   [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel]
+//! *   [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker]
+//! *   [`AbbreviationDefinitionValue`][Name::AbbreviationDefinitionValue]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*PHP Markdown Extra: Abbreviations*](https://michelf.ca/projects/php-markdown/extra/#abbr)
+//!
+//! [flow]: crate::construct::flow
+//! [text]: crate::construct::text
+//! [html-abbr]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-abbr-element
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::{Event, Kind, Name};
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    abbreviation::{find, word_char},
+    char::{after_index, before_index},
+    constant::TAB_SIZE,
+    slice::{Position, Slice},
+};
+use alloc::vec::Vec;
+
+/// Start of abbreviation definition.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.constructs.abbreviation && !tokenizer.interrupt {
+        tokenizer.enter(Name::AbbreviationDefinition);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::AbbreviationBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::AbbreviationBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at `*`.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'*') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `*`, at `[`.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///      ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'[') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationLabelBefore)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `[`, at label.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///       ^
+/// ```
+pub fn label_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b']') => State::Nok,
+        Some(_) => {
+            tokenizer.enter(Name::AbbreviationDefinitionLabel);
+            State::Retry(StateName::AbbreviationLabel)
+        }
+    }
+}
+
+/// In label.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///       ^^^^
+/// ```
+pub fn label(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Nok,
+        Some(b']') => {
+            tokenizer.exit(Name::AbbreviationDefinitionLabel);
+            tokenizer.enter(Name::AbbreviationDefinitionMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::AbbreviationDefinitionMarker);
+            State::Next(StateName::AbbreviationLabelAfter)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationLabel)
+        }
+    }
+}
+
+/// After `]`, at `:`.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///            ^
+/// ```
+pub fn label_after(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::AbbreviationDefinitionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::AbbreviationDefinitionMarker);
+        State::Next(StateName::AbbreviationMarkerAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `:`, at optional whitespace.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///             ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::AbbreviationValueBefore), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::AbbreviationValueBefore)
+    }
+}
+
+/// After `:` and optional whitespace, at value.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///              ^
+/// ```
+pub fn value_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::AbbreviationDefinition);
+            State::Ok
+        }
+        Some(_) => {
+            tokenizer.enter(Name::AbbreviationDefinitionValue);
+            State::Retry(StateName::AbbreviationValue)
+        }
+    }
+}
+
+/// In value.
+///
+/// ```markdown
+/// > | *[HTML]: Hyper Text Markup Language
+///              ^^^^^^^^^^^^^^^^^^^^^^^^^^^
+/// ```
+pub fn value(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::AbbreviationDefinitionValue);
+            tokenizer.exit(Name::AbbreviationDefinition);
+            State::Ok
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::AbbreviationValue)
+        }
+    }
+}
+
+/// Resolve abbreviations.
+///
+/// Looks for the labels of known abbreviation definitions in data, and marks
+/// them as abbreviations.
+pub fn resolve(tokenizer: &mut Tokenizer) {
+    tokenizer.map.consume(&mut tokenizer.events);
+
+    let mut index = 0;
+    let mut links = 0;
+
+    while index < tokenizer.events.len() {
+        let event = &tokenizer.events[index];
+
+        if event.kind == Kind::Enter {
+            if event.name == Name::Link {
+                links += 1;
+            }
+        } else {
+            if event.name == Name::Data && links == 0 {
+                let position = Position::from_exit_event(&tokenizer.events, index);
+                let start_index = position.start.index;
+                let end_index = position.end.index;
+                let slice = Slice::from_position(tokenizer.parse_state.bytes, &position);
+                let value = slice.as_str();
+                let mut replace = Vec::new();
+                let mut point = position.start.clone();
+                let mut min = 0;
+                // Whether the char before the current one is a word char.
+                let mut word_before = matches!(
+                    before_index(tokenizer.parse_state.bytes, start_index),
+                    Some(char) if word_char(char)
+                );
+
+                for (offset, char) in value.char_indices() {
+                    if offset >= min && !word_before {
+                        if let Some(size) = find(
+                            &value[offset..],
+                            after_index(tokenizer.parse_state.bytes, end_index),
+                            &tokenizer.parse_state.abbreviations,
+                        ) {
+                            // If there is something between the last
+                            // abbreviation (or `min`) and this one.
+                            if min != offset {
+                                replace.push(Event {
+                                    kind: Kind::Enter,
+                                    name: Name::Data,
+                                    point: point.clone(),
+                                    link: None,
+                                });
+                                point = point
+                                    .shift_to(tokenizer.parse_state.bytes, start_index + offset);
+                                replace.push(Event {
+                                    kind: Kind::Exit,
+                                    name: Name::Data,
+                                    point: point.clone(),
+                                    link: None,
+                                });
+                            }
+
+                            replace.push(Event {
+                                kind: Kind::Enter,
+                                name: Name::Abbreviation,
+                                point: point.clone(),
+                                link: None,
+                            });
+                            point = point
+                                .shift_to(tokenizer.parse_state.bytes, start_index + offset + size);
+                            replace.push(Event {
+                                kind: Kind::Exit,
+                                name: Name::Abbreviation,
+                                point: point.clone(),
+                                link: None,
+                            });
+                            min = offset + size;
+                        }
+                    }
+
+                    word_before = word_char(char);
+                }
+
+                // If there was an abbreviation, and we have more bytes left.
+                if min != 0 && min < value.len() {
+                    replace.push(Event {
+                        kind: Kind::Enter,
+                        name: Name::Data,
+                        point,
+                        link: None,
+                    });
+                    replace.push(Event {
+                        kind: Kind::Exit,
+                        name: Name::Data,
+                        point: position.end.clone(),
+                        link: None,
+                    });
+                }
+
+                // If there were abbreviations.
+                if !replace.is_empty() {
+                    tokenizer.map.add(index - 1, 2, replace);
+                }
+            }
+
+            if event.name == Name::Link {
+                links -= 1;
+            }
+        }
+
+        index += 1;
+    }
+}
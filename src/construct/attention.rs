@@ -82,10 +82,10 @@ use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
 use crate::util::char::{
-    after_index as char_after_index, before_index as char_before_index, classify_opt,
+    after_index as char_after_index, before_index as char_before_index, classify_opt, is_cjk,
     Kind as CharacterKind,
 };
-use alloc::{vec, vec::Vec};
+use alloc::{format, string::String, vec, vec::Vec};
 
 /// Attentention sequence that we can take markers from.
 #[derive(Debug)]
@@ -108,6 +108,10 @@ struct Sequence {
     open: bool,
     /// Whether this sequence can close attention.
     close: bool,
+    /// Index of the previous sequence that can still be matched, if any.
+    previous: Option<usize>,
+    /// Index of the next sequence that can still be matched, if any.
+    next: Option<usize>,
 }
 
 /// At start of attention.
@@ -118,10 +122,10 @@ struct Sequence {
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // Emphasis/strong:
-    if (tokenizer.parse_state.options.constructs.attention
+    if (tokenizer.parse_state.constructs.attention
         && matches!(tokenizer.current, Some(b'*' | b'_')))
         // GFM strikethrough:
-        || (tokenizer.parse_state.options.constructs.gfm_strikethrough && tokenizer.current == Some(b'~'))
+        || (tokenizer.parse_state.constructs.gfm_strikethrough && tokenizer.current == Some(b'~'))
     {
         tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
         tokenizer.enter(Name::AttentionSequence);
@@ -150,11 +154,21 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
 }
 
 /// Resolve sequences.
-pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+///
+/// Errors in strict mode if a sequence that could open or close is left
+/// unmatched.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Result<Option<Subresult>, String> {
     // Find all sequences, gather info about them.
     let mut sequences = get_sequences(tokenizer);
 
     // Now walk through them and match them.
+    //
+    // Sequences that can no longer be matched are unlinked, so that walking
+    // back to find an opener does not go over them again, and
+    // `openers_bottom` tracks, per kind of closer, below which sequence no
+    // opener was found, so that we don’t look there again.
+    // Both prevent quadratic behavior on long runs of text.
+    let mut openers_bottom: [Option<(usize, Option<usize>)>; 18] = [None; 18];
     let mut close = 0;
 
     while close < sequences.len() {
@@ -163,13 +177,23 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
 
         // Find a sequence that can close.
         if sequence_close.close {
-            let mut open = close;
+            let kind = closer_kind(sequence_close);
+            let stack_top = sequence_close.stack.last().copied();
+            let bottom = match openers_bottom[kind] {
+                Some((index, top)) if top == stack_top => index,
+                _ => 0,
+            };
+            let mut previous = sequence_close.previous;
+            let mut opener = None;
 
             // Now walk back to find an opener.
-            while open > 0 {
-                open -= 1;
+            while let Some(open) = previous {
+                if open < bottom {
+                    break;
+                }
 
                 let sequence_open = &sequences[open];
+                previous = sequence_open.previous;
 
                 // An opener matching our closer:
                 if sequence_open.open
@@ -201,11 +225,53 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                     }
 
                     // We found a match!
-                    next_index = match_sequences(tokenizer, &mut sequences, open, close);
-
+                    opener = Some(open);
                     break;
                 }
             }
+
+            if let Some(open) = opener {
+                match_sequences(tokenizer, &mut sequences, open, close);
+
+                // The opener is smaller now, so it might match closers it
+                // did not match before.
+                for bottom in &mut openers_bottom {
+                    if matches!(bottom, Some((index, _)) if open < *index) {
+                        *bottom = None;
+                    }
+                }
+
+                // Make sure that we can’t have misnested attention:
+                //
+                // ```html
+                // <em>a <strong>b</em> c</strong>
+                // ```
+                //
+                // Do that by unlinking everything between them, so that it
+                // can no longer open anything.
+                // Those sequences were already seen as closers.
+                sequences[open].next = Some(close);
+                sequences[close].previous = Some(open);
+
+                if sequences[open].size == 0 {
+                    unlink(&mut sequences, open);
+                }
+
+                if sequences[close].size == 0 {
+                    unlink(&mut sequences, close);
+                } else {
+                    // Stay on this closing sequence for the next iteration:
+                    // it might close more things.
+                    next_index = close;
+                }
+            } else {
+                openers_bottom[kind] = Some((close, stack_top));
+            }
+        }
+
+        // A sequence that cannot open will never be matched again.
+        if next_index != close && sequences[close].size > 0 && !sequences[close].open {
+            unlink(&mut sequences, close);
         }
 
         close = next_index;
@@ -215,13 +281,26 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     let mut index = 0;
     while index < sequences.len() {
         let sequence = &sequences[index];
-        tokenizer.events[sequence.index].name = Name::Data;
-        tokenizer.events[sequence.index + 1].name = Name::Data;
+
+        if sequence.size > 0 {
+            if tokenizer.parse_state.options.strict && (sequence.open || sequence.close) {
+                return Err(format!(
+                    "{}:{}: Unexpected unmatched `{}`, expected a matching sequence or a character escape (strict:unmatched-attention)",
+                    sequence.start_point.line,
+                    sequence.start_point.column,
+                    sequence.marker as char,
+                ));
+            }
+
+            tokenizer.events[sequence.index].name = Name::Data;
+            tokenizer.events[sequence.index + 1].name = Name::Data;
+        }
+
         index += 1;
     }
 
     tokenizer.map.consume(&mut tokenizer.events);
-    None
+    Ok(None)
 }
 
 /// Get sequences.
@@ -239,18 +318,22 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
                 let exit = &tokenizer.events[end];
 
                 let marker = tokenizer.parse_state.bytes[enter.point.index];
-                let before = classify_opt(char_before_index(
-                    tokenizer.parse_state.bytes,
-                    enter.point.index,
-                ));
-                let after = classify_opt(char_after_index(
-                    tokenizer.parse_state.bytes,
-                    exit.point.index,
-                ));
+                let char_before = char_before_index(tokenizer.parse_state.bytes, enter.point.index);
+                let char_after = char_after_index(tokenizer.parse_state.bytes, exit.point.index);
+                let before = classify_opt(char_before);
+                let after = classify_opt(char_after);
+                // When CJK friendly, a CJK character next to a sequence acts
+                // like whitespace or punctuation would when the other side is
+                // punctuation.
+                let cjk_friendly = tokenizer.parse_state.options.cjk_friendly_emphasis;
+                let before_cjk = cjk_friendly && matches!(char_before, Some(char) if is_cjk(char));
+                let after_cjk = cjk_friendly && matches!(char_after, Some(char) if is_cjk(char));
                 let open = after == CharacterKind::Other
-                    || (after == CharacterKind::Punctuation && before != CharacterKind::Other);
+                    || (after == CharacterKind::Punctuation
+                        && (before != CharacterKind::Other || before_cjk));
                 let close = before == CharacterKind::Other
-                    || (before == CharacterKind::Punctuation && after != CharacterKind::Other);
+                    || (before == CharacterKind::Punctuation
+                        && (after != CharacterKind::Other || after_cjk));
 
                 sequences.push(Sequence {
                     index,
@@ -269,6 +352,8 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
                         close
                     },
                     marker,
+                    previous: sequences.len().checked_sub(1),
+                    next: Some(sequences.len() + 1),
                 });
             }
         } else if enter.kind == Kind::Enter {
@@ -280,23 +365,53 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
         index += 1;
     }
 
+    if let Some(last) = sequences.last_mut() {
+        last.next = None;
+    }
+
     sequences
 }
 
+/// Remove a sequence from the list of sequences that can still be matched.
+fn unlink(sequences: &mut [Sequence], index: usize) {
+    let previous = sequences[index].previous;
+    let next = sequences[index].next;
+
+    if let Some(previous) = previous {
+        sequences[previous].next = next;
+    }
+
+    if let Some(next) = next {
+        sequences[next].previous = previous;
+    }
+}
+
+/// Get the kind of a closing sequence, as an index into `openers_bottom`.
+///
+/// Closers of the same kind (and in the same events) are rejected by the
+/// same openers.
+fn closer_kind(sequence: &Sequence) -> usize {
+    let marker = match sequence.marker {
+        b'*' => 0,
+        b'_' => 1,
+        _ => 2,
+    };
+    let size = if sequence.marker == b'~' {
+        sequence.size.min(3) - 1
+    } else {
+        sequence.size % 3
+    };
+
+    marker * 6 + usize::from(sequence.open) * 3 + size
+}
+
 /// Match two sequences.
-#[allow(clippy::too_many_lines)]
 fn match_sequences(
     tokenizer: &mut Tokenizer,
-    sequences: &mut Vec<Sequence>,
+    sequences: &mut [Sequence],
     open: usize,
     close: usize,
-) -> usize {
-    // Where to move to next.
-    // Stay on this closing sequence for the next iteration: it
-    // might close more things.
-    // It’s changed if sequences are removed.
-    let mut next = close;
-
+) {
     // Number of markers to use from the sequence.
     let take = if sequences[open].size > 1 && sequences[close].size > 1 {
         2
@@ -304,25 +419,6 @@ fn match_sequences(
         1
     };
 
-    // We’re *on* a closing sequence, with a matching opening
-    // sequence.
-    // Now we make sure that we can’t have misnested attention:
-    //
-    // ```html
-    // <em>a <strong>b</em> c</strong>
-    // ```
-    //
-    // Do that by marking everything between it as no longer
-    // possible to open anything.
-    // Theoretically we should mark as `close: false` too, but
-    // we don’t look for closers backwards, so it’s not needed.
-    let mut between = open + 1;
-
-    while between < close {
-        sequences[between].open = false;
-        between += 1;
-    }
-
     let (group_name, seq_name, text_name) = if sequences[open].marker == b'~' {
         (
             Name::GfmStrikethrough,
@@ -413,7 +509,6 @@ fn match_sequences(
 
     // Remove closing sequence if fully used.
     if sequences[close].size == 0 {
-        sequences.remove(close);
         tokenizer.map.add(close_index, 2, vec![]);
     } else {
         // Shift remaining closing sequence forward.
@@ -424,13 +519,8 @@ fn match_sequences(
     }
 
     if sequences[open].size == 0 {
-        sequences.remove(open);
         tokenizer.map.add(open_index, 2, vec![]);
-        // Everything shifts one to the left, account for it in next iteration.
-        next -= 1;
     } else {
         tokenizer.events[open_index + 1].point = sequences[open].end_point.clone();
     }
-
-    next
 }
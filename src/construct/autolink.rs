@@ -20,6 +20,10 @@
 //! ascii_atext ::= ascii_alphanumeric | '!' | '"' | '#' | '$' | '%' | '&' | '\'' | '*' | '+' | '-' | '/' | '=' | '?' | '^' | '_' | '`' | '{' | '|' | '}' | '~'
 //! ```
 //!
+//! When [`strict_email_autolink`][strict_email_autolink] is on, the domain
+//! of an email must also include at least one dot (so `<a@b>` is not an
+//! autolink, but `<a@b.c>` is).
+//!
 //! The maximum allowed size of a scheme is `31` (inclusive), which is defined
 //! in [`AUTOLINK_SCHEME_SIZE_MAX`][autolink_scheme_size_max].
 //! The maximum allowed size of a domain is `63` (inclusive), which is defined
@@ -119,6 +123,7 @@
 //! [autolink_scheme_size_max]: crate::util::constant::AUTOLINK_SCHEME_SIZE_MAX
 //! [autolink_domain_size_max]: crate::util::constant::AUTOLINK_DOMAIN_SIZE_MAX
 //! [sanitize_uri]: crate::util::sanitize_uri
+//! [strict_email_autolink]: crate::ParseOptions::strict_email_autolink
 //! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
 
 use crate::event::Name;
@@ -135,7 +140,7 @@ use crate::util::constant::{AUTOLINK_DOMAIN_SIZE_MAX, AUTOLINK_SCHEME_SIZE_MAX};
 ///      ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.autolink && tokenizer.current == Some(b'<') {
+    if tokenizer.parse_state.constructs.autolink && tokenizer.current == Some(b'<') {
         tokenizer.enter(Name::Autolink);
         tokenizer.enter(Name::AutolinkMarker);
         tokenizer.consume();
@@ -287,12 +292,15 @@ pub fn email_atext(tokenizer: &mut Tokenizer) -> State {
 ///                 ^       ^
 /// ```
 pub fn email_at_sign_or_dot(tokenizer: &mut Tokenizer) -> State {
-    match tokenizer.current {
-        // ASCII alphanumeric.
-        Some(b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z') => {
-            State::Retry(StateName::AutolinkEmailValue)
-        }
-        _ => State::Nok,
+    // ASCII alphanumeric.
+    if matches!(
+        tokenizer.current,
+        Some(b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z')
+    ) {
+        State::Retry(StateName::AutolinkEmailValue)
+    } else {
+        tokenizer.tokenize_state.seen = false;
+        State::Nok
     }
 }
 
@@ -306,11 +314,21 @@ pub fn email_label(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         Some(b'.') => {
             tokenizer.tokenize_state.size = 0;
+            tokenizer.tokenize_state.seen = true;
             tokenizer.consume();
             State::Next(StateName::AutolinkEmailAtSignOrDot)
         }
         Some(b'>') => {
+            let seen = tokenizer.tokenize_state.seen;
             tokenizer.tokenize_state.size = 0;
+            tokenizer.tokenize_state.seen = false;
+
+            // A domain without dot (such as `b` in `a@b`) is not allowed in
+            // strict mode.
+            if tokenizer.parse_state.options.strict_email_autolink && !seen {
+                return State::Nok;
+            }
+
             let index = tokenizer.events.len();
             tokenizer.exit(Name::AutolinkProtocol);
             // Change the event name.
@@ -351,6 +369,7 @@ pub fn email_value(tokenizer: &mut Tokenizer) -> State {
         }
         _ => {
             tokenizer.tokenize_state.size = 0;
+            tokenizer.tokenize_state.seen = false;
             State::Nok
         }
     }
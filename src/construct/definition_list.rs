@@ -0,0 +1,439 @@
+//! Definition lists occur in the [flow][] content type.
+//!
+//! ## Grammar
+//!
+//! Definition descriptions form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! definition_description ::= ':' 1*space_or_tab text *(eol 1*space_or_tab text)
+//!
+//! ; Restriction: maximum `3` leading spaces.
+//! ; Restriction: must be preceded by a paragraph or another description,
+//! ; optionally with blank lines in between.
+//! ```
+//!
+//! As this construct occurs in flow, like all flow constructs, it must be
+//! followed by an eol (line ending) or eof (end of file).
+//!
+//! A description can interrupt a paragraph.
+//! That paragraph then turns into the term that is described.
+//! Several descriptions can follow one term.
+//! Several terms and their descriptions that follow each other form one list.
+//!
+//! When a description is preceded by a blank line, it is loose, and its
+//! content is wrapped in a paragraph.
+//! Otherwise, it is tight.
+//!
+//! Descriptions can continue on the lines after them, when those lines are
+//! indented and not blank (and do not start another description).
+//! They cannot contain other flow constructs: such continuation lines are
+//! part of the text.
+//!
+//! When there is no term before a description (such as when the paragraph
+//! before it is in a different container), the description is turned into a
+//! paragraph.
+//!
+//! ## HTML
+//!
+//! Definition lists relate to the `<dl>`, `<dt>`, and `<dd>` elements in
+//! HTML.
+//! See [*§ 4.4.9 The `dl` element*][html-dl] in the HTML spec for more info.
+//!
+//! ## Recommendation
+//!
+//! Definition lists are not part of `CommonMark` or GFM.
+//! They come from PHP Markdown Extra.
+//! They are not part of mdast either, which is why this crate adds the
+//! [`DefinitionList`][crate::mdast::DefinitionList],
+//! [`DefinitionListTerm`][crate::mdast::DefinitionListTerm], and
+//! [`DefinitionListDescription`][crate::mdast::DefinitionListDescription]
+//! nodes.
+//!
+//! ## Tokens
+//!
+//! *   [`DefinitionList`][Name::DefinitionList]
+//! *   [`DefinitionListDescription`][Name::DefinitionListDescription]
+//! *   [`DefinitionListDescriptionMarker`][Name::DefinitionListDescriptionMarker]
+//! *   [`DefinitionListTerm`][Name::DefinitionListTerm]
+//! *   [`LineEnding`][Name::LineEnding]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*PHP Markdown Extra: Definition lists*](https://michelf.ca/projects/php-markdown/extra/#def-list)
+//!
+//! [flow]: crate::construct::flow
+//! [html-dl]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-dl-element
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::{Content, Kind, Link, Name};
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::subtokenize::Subresult;
+use crate::tokenizer::Tokenizer;
+use crate::util::{constant::TAB_SIZE, skip};
+use alloc::{vec, vec::Vec};
+
+/// Events that can occur between terms and descriptions.
+const WHITESPACE: [Name; 4] = [
+    Name::SpaceOrTab,
+    Name::LineEnding,
+    Name::BlankLineEnding,
+    Name::BlockQuotePrefix,
+];
+
+/// At start of definition description.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.constructs.definition_list
+        && !tokenizer.lazy
+        && !tokenizer.pierce
+        // Require a paragraph or description before.
+        && (!tokenizer.events.is_empty()
+            && matches!(tokenizer.events[skip::opt_back(
+                &tokenizer.events,
+                tokenizer.events.len() - 1,
+                &[Name::BlankLineEnding, Name::LineEnding, Name::SpaceOrTab],
+            )]
+            .name, Name::Content | Name::DefinitionListDescription))
+    {
+        tokenizer.enter(Name::DefinitionListDescription);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(State::Next(StateName::DefinitionListBefore), State::Nok);
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::DefinitionListBefore)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at `:`.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^
+/// ```
+pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.enter(Name::DefinitionListDescriptionMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::DefinitionListDescriptionMarker);
+        State::Next(StateName::DefinitionListMarkerAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `:`, at whitespace.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///      ^
+/// ```
+pub fn marker_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::DefinitionListTextBefore), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Nok
+    }
+}
+
+/// After whitespace, at text.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///       ^
+/// ```
+pub fn text_before(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => State::Nok,
+        Some(_) => {
+            tokenizer.enter_link(
+                Name::Data,
+                Link {
+                    previous: None,
+                    next: None,
+                    content: Content::Text,
+                },
+            );
+            State::Retry(StateName::DefinitionListText)
+        }
+    }
+}
+
+/// In text.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///       ^
+/// ```
+pub fn text(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None => {
+            tokenizer.exit(Name::Data);
+            State::Retry(StateName::DefinitionListAfter)
+        }
+        Some(b'\n') => {
+            tokenizer.exit(Name::Data);
+            tokenizer.attempt(
+                State::Next(StateName::DefinitionListContinuation),
+                State::Next(StateName::DefinitionListAfter),
+            );
+            State::Retry(StateName::DefinitionListFurtherStart)
+        }
+        Some(_) => {
+            tokenizer.consume();
+            State::Next(StateName::DefinitionListText)
+        }
+    }
+}
+
+/// At eol, trying to parse an indented continuation line.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///        ^
+///   |   c
+/// ```
+pub fn further_start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.enter(Name::LineEnding);
+    tokenizer.consume();
+    tokenizer.exit(Name::LineEnding);
+    State::Next(StateName::DefinitionListFurtherBegin)
+}
+
+/// At the beginning of a line, trying to parse an indented continuation
+/// line.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |   c
+///     ^
+/// ```
+pub fn further_begin(tokenizer: &mut Tokenizer) -> State {
+    if !tokenizer.lazy && !tokenizer.pierce && matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(
+            State::Next(StateName::DefinitionListFurtherAfter),
+            State::Nok,
+        );
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Nok
+    }
+}
+
+/// After the indent of a continuation line.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |   c
+///       ^
+/// ```
+pub fn further_after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        // Blank line.
+        None | Some(b'\n') => State::Nok,
+        // Another description.
+        Some(b':') => {
+            tokenizer.check(State::Nok, State::Ok);
+            State::Retry(StateName::DefinitionListFurtherMarker)
+        }
+        Some(_) => State::Ok,
+    }
+}
+
+/// At a `:` that could start another description.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |  : c
+///      ^
+/// ```
+pub fn further_marker(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b':') {
+        tokenizer.consume();
+        State::Next(StateName::DefinitionListFurtherMarkerAfter)
+    } else {
+        State::Nok
+    }
+}
+
+/// After a `:` that could start another description.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |  : c
+///       ^
+/// ```
+pub fn further_marker_after(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        State::Ok
+    } else {
+        State::Nok
+    }
+}
+
+/// At text of a continuation line.
+///
+/// ```markdown
+///   | a
+///   | : b
+/// > |   c
+///       ^
+/// ```
+pub fn continuation(tokenizer: &mut Tokenizer) -> State {
+    // Note: the text of each line is linked together when resolving.
+    tokenizer.enter_link(
+        Name::Data,
+        Link {
+            previous: None,
+            next: None,
+            content: Content::Text,
+        },
+    );
+    State::Retry(StateName::DefinitionListText)
+}
+
+/// After definition description.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///        ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::DefinitionListDescription);
+    // Feel free to interrupt.
+    tokenizer.interrupt = false;
+    tokenizer.register_resolver(ResolveName::DefinitionList);
+    State::Ok
+}
+
+/// Resolve definition lists.
+///
+/// Turns paragraphs before descriptions into terms, and wraps terms and
+/// descriptions that follow each other in lists.
+pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    // Enter and exit indices of lists.
+    let mut lists: Vec<(usize, usize)> = vec![];
+    let mut index = 0;
+
+    while index < tokenizer.events.len() {
+        if tokenizer.events[index].kind == Kind::Enter
+            && tokenizer.events[index].name == Name::DefinitionListDescription
+        {
+            let exit = skip::opt(&tokenizer.events, index, &[Name::DefinitionListDescription]) - 1;
+            let mut data_enter = index;
+
+            while tokenizer.events[data_enter].name != Name::Data {
+                data_enter += 1;
+            }
+
+            // Link the text of continuation lines to the text before, and
+            // move the line endings between them into the text.
+            let mut previous = data_enter;
+            let mut inner = data_enter + 2;
+
+            while inner < exit {
+                if tokenizer.events[inner].kind == Kind::Enter {
+                    if tokenizer.events[inner].name == Name::LineEnding {
+                        tokenizer.events[previous + 1].point =
+                            tokenizer.events[inner + 1].point.clone();
+                        tokenizer.map.add(inner, 2, vec![]);
+                    } else if tokenizer.events[inner].name == Name::Data {
+                        tokenizer.events[previous].link.as_mut().unwrap().next = Some(inner);
+                        tokenizer.events[inner].link.as_mut().unwrap().previous = Some(previous);
+                        previous = inner;
+                    }
+                }
+
+                inner += 1;
+            }
+
+            let before = skip::opt_back(&tokenizer.events, index - 1, &WHITESPACE);
+            let mut start = None;
+
+            // There’s a paragraph before: turn it into a term.
+            if tokenizer.events[before].name == Name::Paragraph {
+                let paragraph_enter =
+                    skip::to_back(&tokenizer.events, before - 1, &[Name::Paragraph]);
+                tokenizer.events[paragraph_enter].name = Name::DefinitionListTerm;
+                tokenizer.events[before].name = Name::DefinitionListTerm;
+                start = Some(paragraph_enter);
+            }
+            // There’s a description before: continue its list.
+            else if tokenizer.events[before].name == Name::DefinitionListDescription {
+                start = Some(index);
+            }
+
+            if let Some(start) = start {
+                // Continue the previous list if there is only whitespace
+                // between it and this item.
+                if let Some(list) = lists.last_mut() {
+                    if skip::opt(&tokenizer.events, list.1 + 1, &WHITESPACE) == start {
+                        list.1 = exit;
+                    } else {
+                        lists.push((start, exit));
+                    }
+                } else {
+                    lists.push((start, exit));
+                }
+            } else {
+                // Swap type.
+                tokenizer.events[index].name = Name::Paragraph;
+                tokenizer.events[exit].name = Name::Paragraph;
+                // Drop what’s before the text (whitespace, marker), and
+                // start the text there instead.
+                tokenizer.events[data_enter].point = tokenizer.events[index].point.clone();
+                tokenizer.map.add(index + 1, data_enter - index - 1, vec![]);
+            }
+
+            index = exit;
+        }
+
+        index += 1;
+    }
+
+    // Inject events.
+    let mut index = 0;
+    while index < lists.len() {
+        let list = &lists[index];
+        let mut list_start = tokenizer.events[list.0].clone();
+        let mut list_end = tokenizer.events[list.1].clone();
+        list_start.name = Name::DefinitionList;
+        list_end.name = Name::DefinitionList;
+        tokenizer.map.add(list.0, 0, vec![list_start]);
+        tokenizer.map.add(list.1 + 1, 0, vec![list_end]);
+        index += 1;
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+    None
+}
@@ -0,0 +1,132 @@
+//! Emoji occur in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Emoji form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! emoji ::= ':' name ':'
+//!
+//! ; Restriction: the opening marker cannot be preceded by an ASCII alphanumeric.
+//! ; Restriction: the name must be a known shortcode (see `constants.rs`), or
+//! ; one passed in `emoji_shortcodes`.
+//! name ::= 1*(ascii_alphanumeric | '+' | '-' | '_')
+//! ```
+//!
+//! Emoji are shortcodes, such as `:smile:`, that are replaced by the
+//! corresponding Unicode emoji (`😄`).
+//! Shortcodes that are not known stay as they are.
+//! See [`EMOJI`][emoji] for which names are built in.
+//! More (or different) shortcodes can be passed with
+//! [`emoji_shortcodes`][emoji_shortcodes].
+//!
+//! The restriction on what can come before the opening marker prevents
+//! things such as times (`10:30:00`) or URLs (`https://example.com`) from
+//! forming emoji.
+//!
+//! Emoji do not relate to an element in HTML: they are compiled to the
+//! emoji itself.
+//!
+//! ## Tokens
+//!
+//! *   [`Emoji`][Name::Emoji]
+//! *   [`EmojiMarker`][Name::EmojiMarker]
+//! *   [`EmojiName`][Name::EmojiName]
+//!
+//! ## References
+//!
+//! *   [`github/gemoji`](https://github.com/github/gemoji)
+//!
+//! [text]: crate::construct::text
+//! [emoji]: crate::util::constant::EMOJI
+//! [emoji_shortcodes]: crate::ParseOptions::emoji_shortcodes
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::{
+    emoji::{decode, name_byte},
+    slice::Slice,
+};
+
+/// Start of emoji.
+///
+/// ```markdown
+/// > | a :smile: b
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.constructs.emoji
+        && tokenizer.current == Some(b':')
+        && !matches!(tokenizer.previous, Some(byte) if byte.is_ascii_alphanumeric())
+    {
+        tokenizer.enter(Name::Emoji);
+        tokenizer.enter(Name::EmojiMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::EmojiMarker);
+        State::Next(StateName::EmojiOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After opening `:`, at name.
+///
+/// ```markdown
+/// > | a :smile: b
+///        ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    if matches!(tokenizer.current, Some(byte) if name_byte(byte)) {
+        tokenizer.enter(Name::EmojiName);
+        State::Retry(StateName::EmojiName)
+    } else {
+        State::Nok
+    }
+}
+
+/// In name.
+///
+/// ```markdown
+/// > | a :smile: b
+///        ^^^^^
+/// ```
+pub fn name(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(byte) if name_byte(byte) => {
+            tokenizer.tokenize_state.size += 1;
+            tokenizer.consume();
+            State::Next(StateName::EmojiName)
+        }
+        Some(b':') => {
+            // Guaranteed to be valid ASCII bytes.
+            let slice = Slice::from_indices(
+                tokenizer.parse_state.bytes,
+                tokenizer.point.index - tokenizer.tokenize_state.size,
+                tokenizer.point.index,
+            );
+            tokenizer.tokenize_state.size = 0;
+
+            if decode(
+                slice.as_str(),
+                &tokenizer.parse_state.options.emoji_shortcodes,
+            )
+            .is_none()
+            {
+                return State::Nok;
+            }
+
+            tokenizer.exit(Name::EmojiName);
+            tokenizer.enter(Name::EmojiMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::EmojiMarker);
+            tokenizer.exit(Name::Emoji);
+            State::Ok
+        }
+        _ => {
+            tokenizer.tokenize_state.size = 0;
+            State::Nok
+        }
+    }
+}
@@ -10,20 +10,30 @@
 //!
 //! The constructs found in flow are:
 //!
+//! *   [Abbreviation (definition)][crate::construct::abbreviation]
 //! *   [Blank line][crate::construct::blank_line]
 //! *   [Code (indented)][crate::construct::code_indented]
+//! *   [Definition list (description)][crate::construct::definition_list]
 //! *   [Heading (atx)][crate::construct::heading_atx]
 //! *   [Heading (setext)][crate::construct::heading_setext]
 //! *   [HTML (flow)][crate::construct::html_flow]
 //! *   [MDX esm][crate::construct::mdx_esm]
 //! *   [MDX expression (flow)][crate::construct::mdx_expression_flow]
 //! *   [MDX JSX (flow)][crate::construct::mdx_jsx_flow]
+//! *   [Percent comment (flow)][crate::construct::percent_comment_flow]
 //! *   [Raw (flow)][crate::construct::raw_flow] (code (fenced), math (flow))
 //! *   [Thematic break][crate::construct::thematic_break]
+//!
+//! Blank lines are tried first.
+//! Then, the other constructs are tried in the order of
+//! [`flow_construct_order`][crate::ParseOptions::flow_construct_order],
+//! which defaults to the order above, from code (indented) to GFM tables.
+//! Finally, if nothing else matches, the line is content.
 
 use crate::event::Name;
 use crate::state::{Name as StateName, State};
 use crate::tokenizer::Tokenizer;
+use crate::FlowConstruct;
 
 /// Start of flow.
 //
@@ -36,50 +46,12 @@ use crate::tokenizer::Tokenizer;
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.flow_construct_index = 0;
+
     match tokenizer.current {
-        Some(b'#') => {
-            tokenizer.attempt(
-                State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
-            );
-            State::Retry(StateName::HeadingAtxStart)
-        }
-        Some(b'$' | b'`' | b'~') => {
-            tokenizer.attempt(
-                State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
-            );
-            State::Retry(StateName::RawFlowStart)
-        }
-        // Note: `-` is also used in setext heading underline so it’s not
-        // included here.
-        Some(b'*' | b'_') => {
-            tokenizer.attempt(
-                State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
-            );
-            State::Retry(StateName::ThematicBreakStart)
-        }
-        Some(b'<') => {
-            tokenizer.attempt(
-                State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeMdxJsx),
-            );
-            State::Retry(StateName::HtmlFlowStart)
-        }
-        Some(b'e' | b'i') => {
-            tokenizer.attempt(
-                State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
-            );
-            State::Retry(StateName::MdxEsmStart)
-        }
-        Some(b'{') => {
-            tokenizer.attempt(
-                State::Next(StateName::FlowAfter),
-                State::Next(StateName::FlowBeforeContent),
-            );
-            State::Retry(StateName::MdxExpressionFlowStart)
+        // Some bytes can only start a few constructs, see `can_start`.
+        Some(b'#' | b'$' | b'`' | b'~' | b'*' | b'_' | b'<' | b'e' | b'i' | b'{') => {
+            State::Retry(StateName::FlowBeforeConstruct)
         }
         // Actual parsing: blank line? Indented code? Indented anything?
         // Tables, setext heading underlines, definitions, and Contents are
@@ -97,136 +69,49 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 pub fn blank_line_before(tokenizer: &mut Tokenizer) -> State {
     tokenizer.attempt(
         State::Next(StateName::FlowBlankLineAfter),
-        State::Next(StateName::FlowBeforeCodeIndented),
+        State::Next(StateName::FlowBeforeConstruct),
     );
     State::Retry(StateName::BlankLineStart)
 }
 
-/// At code (indented).
-///
-/// ```markdown
-/// > | ␠␠␠␠a
-///     ^
-/// ```
-pub fn before_code_indented(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeRaw),
-    );
-    State::Retry(StateName::CodeIndentedStart)
-}
-
-/// At raw.
-///
-/// ````markdown
-/// > | ```
-///     ^
-/// ````
-pub fn before_raw(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeHtml),
-    );
-    State::Retry(StateName::RawFlowStart)
-}
-
-/// At html (flow).
-///
-/// ```markdown
-/// > | <a>
-///     ^
-/// ```
-pub fn before_html(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeMdxJsx),
-    );
-    State::Retry(StateName::HtmlFlowStart)
-}
-
-/// At mdx jsx (flow).
-///
-/// ```markdown
-/// > | <A />
-///     ^
-/// ```
-pub fn before_mdx_jsx(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeHeadingAtx),
-    );
-    State::Retry(StateName::MdxJsxFlowStart)
-}
-
-/// At heading (atx).
-///
-/// ```markdown
-/// > | # a
-///     ^
-/// ```
-pub fn before_heading_atx(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeHeadingSetext),
-    );
-    State::Retry(StateName::HeadingAtxStart)
-}
-
-/// At heading (setext).
-///
-/// ```markdown
-///   | a
-/// > | =
-///     ^
-/// ```
-pub fn before_heading_setext(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeThematicBreak),
-    );
-    State::Retry(StateName::HeadingSetextStart)
-}
-
-/// At thematic break.
+/// Before a construct, in the order of `flow_construct_order`.
 ///
 /// ```markdown
 /// > | ***
 ///     ^
 /// ```
-pub fn before_thematic_break(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeMdxExpression),
-    );
-    State::Retry(StateName::ThematicBreakStart)
-}
+pub fn before_construct(tokenizer: &mut Tokenizer) -> State {
+    let order = &tokenizer.parse_state.options.flow_construct_order;
 
-/// At MDX expression (flow).
-///
-/// ```markdown
-/// > | {Math.PI}
-///     ^
-/// ```
-pub fn before_mdx_expression(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeGfmTable),
-    );
-    State::Retry(StateName::MdxExpressionFlowStart)
-}
+    while tokenizer.tokenize_state.flow_construct_index < order.len() {
+        let construct = order[tokenizer.tokenize_state.flow_construct_index];
+        tokenizer.tokenize_state.flow_construct_index += 1;
 
-/// At GFM table.
-///
-/// ```markdown
-/// > | | a |
-///     ^
-/// ```
-pub fn before_gfm_table(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.attempt(
-        State::Next(StateName::FlowAfter),
-        State::Next(StateName::FlowBeforeContent),
-    );
-    State::Retry(StateName::GfmTableStart)
+        if can_start(construct, tokenizer.current) {
+            tokenizer.attempt(
+                State::Next(StateName::FlowAfter),
+                State::Next(StateName::FlowBeforeConstruct),
+            );
+            return State::Retry(match construct {
+                FlowConstruct::Abbreviation => StateName::AbbreviationStart,
+                FlowConstruct::CodeIndented => StateName::CodeIndentedStart,
+                FlowConstruct::DefinitionList => StateName::DefinitionListStart,
+                FlowConstruct::GfmTable => StateName::GfmTableStart,
+                FlowConstruct::HeadingAtx => StateName::HeadingAtxStart,
+                FlowConstruct::HeadingSetext => StateName::HeadingSetextStart,
+                FlowConstruct::HtmlFlow => StateName::HtmlFlowStart,
+                FlowConstruct::MdxEsm => StateName::MdxEsmStart,
+                FlowConstruct::MdxExpressionFlow => StateName::MdxExpressionFlowStart,
+                FlowConstruct::MdxJsxFlow => StateName::MdxJsxFlowStart,
+                FlowConstruct::PercentComment => StateName::PercentCommentFlowStart,
+                FlowConstruct::RawFlow => StateName::RawFlowStart,
+                FlowConstruct::ThematicBreak => StateName::ThematicBreakStart,
+            });
+        }
+    }
+
+    tokenizer.tokenize_state.flow_construct_index = 0;
+    State::Retry(StateName::FlowBeforeContent)
 }
 
 /// At content.
@@ -279,3 +164,38 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
         _ => unreachable!("expected eol/eof"),
     }
 }
+
+/// Check whether `construct` can start at `byte`.
+///
+/// Most bytes, such as whitespace, can start anything, but some bytes can
+/// only start a few constructs, which are the only ones tried.
+fn can_start(construct: FlowConstruct, byte: Option<u8>) -> bool {
+    match byte {
+        Some(b'#') => construct == FlowConstruct::HeadingAtx,
+        Some(b'$' | b'`' | b'~') => construct == FlowConstruct::RawFlow,
+        // Note: `-` is also used in setext heading underline so it’s not
+        // included here.
+        Some(b'*' | b'_') => matches!(
+            construct,
+            FlowConstruct::ThematicBreak | FlowConstruct::Abbreviation
+        ),
+        // Note: a line that is not HTML or JSX can still be a table row, so
+        // only constructs that start with a different byte are excluded.
+        Some(b'<') => !matches!(
+            construct,
+            FlowConstruct::Abbreviation
+                | FlowConstruct::CodeIndented
+                | FlowConstruct::DefinitionList
+                | FlowConstruct::HeadingAtx
+                | FlowConstruct::MdxEsm
+                | FlowConstruct::MdxExpressionFlow
+                | FlowConstruct::PercentComment
+                | FlowConstruct::RawFlow
+                | FlowConstruct::ThematicBreak
+        ),
+        Some(b'e' | b'i') => construct == FlowConstruct::MdxEsm,
+        Some(b'{') => construct == FlowConstruct::MdxExpressionFlow,
+        // ESM can only start at `e` or `i`.
+        _ => construct != FlowConstruct::MdxEsm,
+    }
+}
@@ -199,7 +199,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             State::Retry(space_or_tab_min_max(
                 tokenizer,
                 1,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.parse_state.constructs.code_indented {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
@@ -248,7 +248,7 @@ use alloc::vec;
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.gfm_table {
+    if tokenizer.parse_state.constructs.gfm_table {
         if !tokenizer.pierce
             && !tokenizer.events.is_empty()
             && matches!(
@@ -286,7 +286,7 @@ pub fn head_row_before(tokenizer: &mut Tokenizer) -> State {
         State::Retry(space_or_tab_min_max(
             tokenizer,
             0,
-            if tokenizer.parse_state.options.constructs.code_indented {
+            if tokenizer.parse_state.constructs.code_indented {
                 TAB_SIZE - 1
             } else {
                 usize::MAX
@@ -459,7 +459,7 @@ pub fn head_delimiter_start(tokenizer: &mut Tokenizer) -> State {
                 State::Retry(space_or_tab_min_max(
                     tokenizer,
                     0,
-                    if tokenizer.parse_state.options.constructs.code_indented {
+                    if tokenizer.parse_state.constructs.code_indented {
                         TAB_SIZE - 1
                     } else {
                         usize::MAX
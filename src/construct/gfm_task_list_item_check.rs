@@ -56,7 +56,7 @@ use crate::tokenizer::Tokenizer;
 ///       ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.gfm_task_list_item
+    if tokenizer.parse_state.constructs.gfm_task_list_item
         && tokenizer
             .tokenize_state
             .document_at_first_paragraph_of_list_item
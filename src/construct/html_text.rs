@@ -64,7 +64,7 @@ use crate::util::constant::HTML_CDATA_PREFIX;
 ///       ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if Some(b'<') == tokenizer.current && tokenizer.parse_state.options.constructs.html_text {
+    if Some(b'<') == tokenizer.current && tokenizer.parse_state.constructs.html_text {
         tokenizer.enter(Name::HtmlText);
         tokenizer.enter(Name::HtmlTextData);
         tokenizer.consume();
@@ -631,8 +631,8 @@ pub fn end(tokenizer: &mut Tokenizer) -> State {
 
 /// At eol.
 ///
-/// > 👉 **Note**: we can’t have blank lines in text, so no need to worry about
-/// > empty tokens.
+/// > 👉 **Note**: blank lines are not allowed, so no need to worry about empty
+/// > tokens: see [`line_ending_after_prefix`][].
 ///
 /// ```markdown
 /// > | a <!--a
@@ -654,9 +654,6 @@ pub fn line_ending_before(tokenizer: &mut Tokenizer) -> State {
 
 /// After eol, at optional whitespace.
 ///
-/// > 👉 **Note**: we can’t have blank lines in text, so no need to worry about
-/// > empty tokens.
-///
 /// ```markdown
 ///   | a <!--a
 /// > | b-->
@@ -676,8 +673,9 @@ pub fn line_ending_after(tokenizer: &mut Tokenizer) -> State {
 
 /// After eol, after optional whitespace.
 ///
-/// > 👉 **Note**: we can’t have blank lines in text, so no need to worry about
-/// > empty tokens.
+/// > 👉 **Note**: blank lines can’t occur in text parsed from flow, but they
+/// > can when parsing text by itself (with `inline`), so they are not
+/// > allowed here.
 ///
 /// ```markdown
 ///   | a <!--a
@@ -685,6 +683,10 @@ pub fn line_ending_after(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn line_ending_after_prefix(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.enter(Name::HtmlTextData);
-    State::Ok
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        State::Nok
+    } else {
+        tokenizer.enter(Name::HtmlTextData);
+        State::Ok
+    }
 }
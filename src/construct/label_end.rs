@@ -191,7 +191,7 @@ use crate::util::{
     skip,
     slice::{Position, Slice},
 };
-use alloc::{string::String, vec};
+use alloc::{format, string::String, vec};
 
 /// Start of label end.
 ///
@@ -205,7 +205,7 @@ use alloc::{string::String, vec};
 /// > | [a] b
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if Some(b']') == tokenizer.current && tokenizer.parse_state.options.constructs.label_end {
+    if Some(b']') == tokenizer.current && tokenizer.parse_state.constructs.label_end {
         // If there is an okay opening:
         if !tokenizer.tokenize_state.label_starts.is_empty() {
             let label_start = tokenizer.tokenize_state.label_starts.last().unwrap();
@@ -658,7 +658,10 @@ pub fn reference_collapsed_open(tokenizer: &mut Tokenizer) -> State {
 ///
 /// This turns matching label starts and label ends into links, images, and
 /// footnotes, and turns unmatched label starts back into data.
-pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+///
+/// Errors in strict mode if a label was closed but matched nothing (such as
+/// `[a]` without a definition for `a`).
+pub fn resolve(tokenizer: &mut Tokenizer) -> Result<Option<Subresult>, String> {
     // Inject labels.
     let labels = tokenizer.tokenize_state.labels.split_off(0);
     inject_labels(tokenizer, &labels);
@@ -666,10 +669,28 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     let starts = tokenizer.tokenize_state.label_starts.split_off(0);
     mark_as_data(tokenizer, &starts);
     let starts = tokenizer.tokenize_state.label_starts_loose.split_off(0);
+
+    if tokenizer.parse_state.options.strict {
+        // Inactive starts are balanced brackets around a link, which are fine.
+        let first = starts
+            .iter()
+            .filter(|start| !start.inactive)
+            .map(|start| start.start.0)
+            .min();
+
+        if let Some(index) = first {
+            let point = &tokenizer.events[index].point;
+            return Err(format!(
+                "{}:{}: Unexpected reference to an undefined definition, expected a definition, a resource, or escaped brackets (strict:undefined-reference)",
+                point.line, point.column,
+            ));
+        }
+    }
+
     mark_as_data(tokenizer, &starts);
 
     tokenizer.map.consume(&mut tokenizer.events);
-    None
+    Ok(None)
 }
 
 /// Inject links/images/footnotes.
@@ -739,16 +760,16 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
         // Though: if this was what looked like a footnote, but didn’t match,
         // it’s a link instead, and we need to inject the `^`.
         if label.start.1 != label.end.0 || !caret.is_empty() {
-            tokenizer.map.add(
-                label.start.1 + 1,
-                0,
-                vec![Event {
-                    kind: Kind::Enter,
-                    name: Name::LabelText,
-                    point: tokenizer.events[label.start.1].point.clone(),
-                    link: None,
-                }],
-            );
+            let mut enter = vec![Event {
+                kind: Kind::Enter,
+                name: Name::LabelText,
+                point: tokenizer.events[label.start.1].point.clone(),
+                link: None,
+            }];
+            enter.append(&mut caret);
+            // Inner labels are injected first, so to have this enter before
+            // an image or link that starts the text, insert it before them.
+            tokenizer.map.add_before(label.start.1 + 1, 0, enter);
             tokenizer.map.add(
                 label.end.0,
                 0,
@@ -761,10 +782,6 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
             );
         }
 
-        if !caret.is_empty() {
-            tokenizer.map.add(label.start.1 + 1, 0, caret);
-        }
-
         // Insert a label exit.
         tokenizer.map.add(
             label.end.0 + 4,
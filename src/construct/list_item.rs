@@ -13,6 +13,7 @@
 //!
 //! ; Restriction: blank line allowed, except when this is the first continuation after a blank start.
 //! ; Restriction: if not blank, the line must be indented, exactly `n` times.
+//! ; Restriction: `n` is the size of `list_item_start`, unless configured otherwise with `list_item_indent`.
 //! list_item_cont ::= [n(space_or_tab)]
 //! ```
 //!
@@ -58,6 +59,7 @@
 //! [html_ul]: https://html.spec.whatwg.org/multipage/grouping-content.html#the-ul-element
 //! [commonmark_block]: https://spec.commonmark.org/0.30/#phase-1-block-structure
 
+use crate::configuration::ListIndent;
 use crate::construct::partial_space_or_tab::space_or_tab_min_max;
 use crate::event::{Kind, Name};
 use crate::resolve::Name as ResolveName;
@@ -78,7 +80,7 @@ use alloc::{vec, vec::Vec};
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.list_item {
+    if tokenizer.parse_state.constructs.list_item {
         tokenizer.enter(Name::ListItem);
 
         if matches!(tokenizer.current, Some(b'\t' | b' ')) {
@@ -86,7 +88,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             State::Retry(space_or_tab_min_max(
                 tokenizer,
                 0,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.parse_state.constructs.code_indented {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
@@ -298,11 +300,17 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
             prefix += 1;
         }
 
+        let size = match tokenizer.parse_state.options.list_item_indent {
+            ListIndent::Mixed => prefix,
+            ListIndent::One => 1,
+            ListIndent::Tab => prefix + (TAB_SIZE - prefix % TAB_SIZE) % TAB_SIZE,
+        };
+
         let container = &mut tokenizer.tokenize_state.document_container_stack
             [tokenizer.tokenize_state.document_continued];
 
         container.blank_initial = blank;
-        container.size = prefix;
+        container.size = size;
 
         tokenizer.exit(Name::ListItemPrefix);
         tokenizer.register_resolver_before(ResolveName::ListItem);
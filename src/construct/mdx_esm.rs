@@ -44,7 +44,7 @@ use alloc::format;
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // If it’s turned on.
-    if tokenizer.parse_state.options.constructs.mdx_esm
+    if tokenizer.parse_state.constructs.mdx_esm
         // If there is a gnostic parser.
         && tokenizer.parse_state.options.mdx_esm_parse.is_some()
         // When not interrupting.
@@ -62,6 +62,9 @@
 //!
 //! The following constructs are extensions found in markdown:
 //!
+//! *   [abbreviation][]
+//! *   [definition list][definition_list]
+//! *   [emoji][]
 //! *   [frontmatter][]
 //! *   [gfm autolink literal][gfm_autolink_literal]
 //! *   [gfm footnote definition][gfm_footnote_definition]
@@ -73,6 +76,8 @@
 //! *   [mdx expression (text)][mdx_expression_text]
 //! *   [mdx jsx (flow)][mdx_jsx_flow]
 //! *   [mdx jsx (text)][mdx_jsx_text]
+//! *   [percent comment (flow)][percent_comment_flow]
+//! *   [percent comment (text)][percent_comment_text]
 //!
 //! There are also several small subroutines typically used in different places:
 //!
@@ -148,6 +153,7 @@
 //!
 //! [bnf]: http://trevorjim.com/a-specification-for-markdown/
 
+pub mod abbreviation;
 pub mod attention;
 pub mod autolink;
 pub mod blank_line;
@@ -157,7 +163,9 @@ pub mod character_reference;
 pub mod code_indented;
 pub mod content;
 pub mod definition;
+pub mod definition_list;
 pub mod document;
+pub mod emoji;
 pub mod flow;
 pub mod frontmatter;
 pub mod gfm_autolink_literal;
@@ -191,6 +199,8 @@ pub mod partial_space_or_tab;
 pub mod partial_space_or_tab_eol;
 pub mod partial_title;
 pub mod partial_whitespace;
+pub mod percent_comment_flow;
+pub mod percent_comment_text;
 pub mod raw_flow;
 pub mod raw_text;
 pub mod string;
@@ -4,6 +4,8 @@
 //! the following line is not lazy.
 //! For example, [html (flow)][html_flow] and ([raw (flow)][raw_flow],
 //! [indented][code_indented]), stop when the next line is lazy.
+//! They also stop when a new container was formed on the next line, which
+//! can happen when a construct tried before them looked ahead past the eol.
 //!
 //! [flow]: crate::construct::flow
 //! [raw_flow]: crate::construct::raw_flow
@@ -41,7 +43,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn after(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.lazy {
+    if tokenizer.lazy || tokenizer.pierce {
         State::Nok
     } else {
         State::Ok
@@ -194,15 +194,15 @@ pub fn after_eol(tokenizer: &mut Tokenizer) -> State {
 ///       ^
 /// ```
 pub fn after_more(tokenizer: &mut Tokenizer) -> State {
-    debug_assert!(
-        !matches!(tokenizer.current, None | Some(b'\n')),
-        "did not expect blank line"
-    );
-    // If the above ever starts erroring, gracefully `State::Nok` on it.
-    // Currently it doesn’t happen, as we only use this in content, which does
-    // not allow blank lines.
     tokenizer.tokenize_state.space_or_tab_eol_content = None;
     tokenizer.tokenize_state.space_or_tab_eol_connect = false;
     tokenizer.tokenize_state.space_or_tab_eol_ok = false;
-    State::Ok
+
+    // Blank lines can’t occur in content, but they can when parsing text by
+    // itself (with `inline`), so they are not allowed.
+    if matches!(tokenizer.current, None | Some(b'\n')) {
+        State::Nok
+    } else {
+        State::Ok
+    }
 }
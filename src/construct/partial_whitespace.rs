@@ -60,7 +60,7 @@
 use crate::event::{Event, Kind, Name};
 use crate::tokenizer::Tokenizer;
 use crate::util::{
-    constant::HARD_BREAK_PREFIX_SIZE_MIN,
+    constant::{HARD_BREAK_PREFIX_SIZE_MIN, TAB_SIZE},
     slice::{Position, Slice},
 };
 use alloc::vec;
@@ -184,6 +184,15 @@ fn trim_data(
         if index > 0 || slice.before > 0 {
             let enter_point = tokenizer.events[exit_index - 1].point.clone();
             let mut exit_point = enter_point.clone();
+
+            // If we start in a tab, of which some virtual spaces were already
+            // used by a container, move past the rest of it, to the next tab
+            // stop.
+            if enter_point.vs > 0 {
+                exit_point.index += 1;
+                exit_point.column = (enter_point.column - 1) / TAB_SIZE * TAB_SIZE + TAB_SIZE + 1;
+            }
+
             exit_point.index += index;
             exit_point.column += index;
             exit_point.vs = 0;
@@ -0,0 +1,261 @@
+//! Percent comment (flow) occurs in the [flow][] content type.
+//!
+//! ## Grammar
+//!
+//! Percent comment (flow) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: the value cannot contain `%%`.
+//! percent_comment_flow ::= '%%' *(eol | byte) '%%' *space_or_tab
+//! ```
+//!
+//! As this construct occurs in flow, like all flow constructs, it must be
+//! followed by an eol (line ending) or eof (end of file).
+//!
+//! Percent comments are comments in [Obsidian][obsidian]: the text between
+//! them is only visible when editing.
+//! They are not compiled to anything: nothing is generated in HTML (not even
+//! an HTML comment), and nothing is added to mdast.
+//!
+//! The comment ends at the first `%%`: comments cannot be nested.
+//! When there is no closing `%%`, or when it is followed by something other
+//! than whitespace, the lines are not a comment, and are parsed as normal
+//! markdown instead (which can still include
+//! [percent comment (text)][percent_comment_text]).
+//! Like [raw (flow)][raw_flow], the comment can interrupt a paragraph, and
+//! it does not form containers on the lines it spans: it ends (and so it is
+//! not a comment) when its container ends.
+//!
+//! ```markdown
+//! %%
+//! This is a comment.
+//!
+//! # So is this.
+//! %%
+//!
+//! %% This too. %%
+//! ```
+//!
+//! ## Tokens
+//!
+//! *   [`LineEnding`][Name::LineEnding]
+//! *   [`PercentCommentFlow`][Name::PercentCommentFlow]
+//! *   [`PercentCommentFlowSequence`][Name::PercentCommentFlowSequence]
+//! *   [`PercentCommentFlowValue`][Name::PercentCommentFlowValue]
+//! *   [`SpaceOrTab`][Name::SpaceOrTab]
+//!
+//! ## References
+//!
+//! *   [*Comments* in Obsidian](https://help.obsidian.md/Editing+and+formatting/Basic+formatting+syntax#Comments)
+//!
+//! [flow]: crate::construct::flow
+//! [raw_flow]: crate::construct::raw_flow
+//! [percent_comment_text]: crate::construct::percent_comment_text
+//! [obsidian]: https://obsidian.md
+
+use crate::construct::partial_space_or_tab::{space_or_tab, space_or_tab_min_max};
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::constant::TAB_SIZE;
+
+/// Start of percent comment (flow).
+///
+/// ```markdown
+/// > | %%
+///     ^
+///   | a
+///   | %%
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.constructs.percent_comment {
+        tokenizer.enter(Name::PercentCommentFlow);
+
+        if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+            tokenizer.attempt(
+                State::Next(StateName::PercentCommentFlowBeforeSequenceOpen),
+                State::Nok,
+            );
+            State::Retry(space_or_tab_min_max(
+                tokenizer,
+                0,
+                if tokenizer.parse_state.constructs.code_indented {
+                    TAB_SIZE - 1
+                } else {
+                    usize::MAX
+                },
+            ))
+        } else {
+            State::Retry(StateName::PercentCommentFlowBeforeSequenceOpen)
+        }
+    } else {
+        State::Nok
+    }
+}
+
+/// After optional whitespace, at opening sequence.
+///
+/// ```markdown
+/// > | %%
+///     ^
+///   | a
+///   | %%
+/// ```
+pub fn before_sequence_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'%') {
+        tokenizer.enter(Name::PercentCommentFlowSequence);
+        tokenizer.consume();
+        State::Next(StateName::PercentCommentFlowSequenceOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// In opening sequence, after the first `%`.
+///
+/// ```markdown
+/// > | %%
+///      ^
+///   | a
+///   | %%
+/// ```
+pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'%') {
+        tokenizer.consume();
+        tokenizer.exit(Name::PercentCommentFlowSequence);
+        // Do not form containers.
+        tokenizer.concrete = true;
+        State::Next(StateName::PercentCommentFlowBetween)
+    } else {
+        State::Nok
+    }
+}
+
+/// Between something and something else.
+///
+/// ```markdown
+/// > | %%
+///       ^
+/// > | a
+///     ^
+///   | %%
+/// ```
+pub fn between(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        // Not closed.
+        None => State::Retry(StateName::PercentCommentFlowNok),
+        Some(b'\n') => {
+            tokenizer.attempt(
+                State::Next(StateName::PercentCommentFlowBetween),
+                State::Next(StateName::PercentCommentFlowNok),
+            );
+            State::Retry(StateName::NonLazyContinuationStart)
+        }
+        Some(b'%') => {
+            tokenizer.enter(Name::PercentCommentFlowSequence);
+            tokenizer.consume();
+            State::Next(StateName::PercentCommentFlowSequenceClose)
+        }
+        _ => {
+            tokenizer.enter(Name::PercentCommentFlowValue);
+            State::Retry(StateName::PercentCommentFlowValue)
+        }
+    }
+}
+
+/// In value.
+///
+/// ```markdown
+///   | %%
+/// > | a
+///     ^
+///   | %%
+/// ```
+pub fn value(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'%') => {
+            tokenizer.exit(Name::PercentCommentFlowValue);
+            State::Retry(StateName::PercentCommentFlowBetween)
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::PercentCommentFlowValue)
+        }
+    }
+}
+
+/// In closing sequence, after the first `%`.
+///
+/// ```markdown
+///   | %%
+///   | a
+/// > | %%
+///      ^
+/// ```
+pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'%') {
+        tokenizer.consume();
+        State::Next(StateName::PercentCommentFlowAfterSequenceClose)
+    } else {
+        // Just one `%`: mark as value.
+        tokenizer.exit(Name::PercentCommentFlowSequence);
+        let len = tokenizer.events.len();
+        tokenizer.events[len - 2].name = Name::PercentCommentFlowValue;
+        tokenizer.events[len - 1].name = Name::PercentCommentFlowValue;
+        State::Retry(StateName::PercentCommentFlowBetween)
+    }
+}
+
+/// After closing sequence.
+///
+/// ```markdown
+///   | %%
+///   | a
+/// > | %%
+///       ^
+/// ```
+pub fn sequence_close_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.exit(Name::PercentCommentFlowSequence);
+
+    if matches!(tokenizer.current, Some(b'\t' | b' ')) {
+        tokenizer.attempt(State::Next(StateName::PercentCommentFlowAfter), State::Nok);
+        State::Retry(space_or_tab(tokenizer))
+    } else {
+        State::Retry(StateName::PercentCommentFlowAfter)
+    }
+}
+
+/// After closing sequence, after optional whitespace.
+///
+/// ```markdown
+///   | %%
+///   | a
+/// > | %%
+///       ^
+/// ```
+pub fn after(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n') => {
+            tokenizer.exit(Name::PercentCommentFlow);
+            // No longer concrete.
+            tokenizer.concrete = false;
+            // Feel free to interrupt.
+            tokenizer.interrupt = false;
+            State::Ok
+        }
+        // Something after the closing sequence: not a comment.
+        _ => State::Retry(StateName::PercentCommentFlowNok),
+    }
+}
+
+/// Not a comment, such as when there is no closing sequence.
+///
+/// ```markdown
+/// > | %%
+///       ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.concrete = false;
+    State::Nok
+}
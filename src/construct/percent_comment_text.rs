@@ -0,0 +1,175 @@
+//! Percent comment (text) occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Percent comment (text) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! ; Restriction: the value cannot contain `%%`.
+//! percent_comment_text ::= '%%' *(eol | byte) '%%'
+//! ```
+//!
+//! Percent comments are comments in [Obsidian][obsidian]: the text between
+//! them is only visible when editing.
+//! They are not compiled to anything: nothing is generated in HTML (not even
+//! an HTML comment), and nothing is added to mdast.
+//!
+//! The comment ends at the first `%%`: comments cannot be nested.
+//! When there is no closing `%%`, the opening `%%` is not a comment, and is
+//! treated as normal text instead.
+//! Like [raw (text)][raw_text], comments can span several lines, and they
+//! take precedence over constructs that start later, such as emphasis:
+//!
+//! ```markdown
+//! a %%b *c%% d*
+//! ```
+//!
+//! Yields:
+//!
+//! ```html
+//! <p>a  d*</p>
+//! ```
+//!
+//! To comment out whole lines, it is also possible to use
+//! [percent comment (flow)][percent_comment_flow].
+//!
+//! ## Tokens
+//!
+//! *   [`LineEnding`][Name::LineEnding]
+//! *   [`PercentCommentText`][Name::PercentCommentText]
+//! *   [`PercentCommentTextSequence`][Name::PercentCommentTextSequence]
+//! *   [`PercentCommentTextValue`][Name::PercentCommentTextValue]
+//!
+//! ## References
+//!
+//! *   [*Comments* in Obsidian](https://help.obsidian.md/Editing+and+formatting/Basic+formatting+syntax#Comments)
+//!
+//! [text]: crate::construct::text
+//! [raw_text]: crate::construct::raw_text
+//! [percent_comment_flow]: crate::construct::percent_comment_flow
+//! [obsidian]: https://obsidian.md
+
+use crate::event::Name;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+
+/// Start of percent comment (text).
+///
+/// ```markdown
+/// > | a %%b%% c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.constructs.percent_comment && tokenizer.current == Some(b'%') {
+        tokenizer.enter(Name::PercentCommentText);
+        tokenizer.enter(Name::PercentCommentTextSequence);
+        tokenizer.consume();
+        State::Next(StateName::PercentCommentTextSequenceOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// In opening sequence, after the first `%`.
+///
+/// ```markdown
+/// > | a %%b%% c
+///        ^
+/// ```
+pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'%') {
+        tokenizer.consume();
+        tokenizer.exit(Name::PercentCommentTextSequence);
+        State::Next(StateName::PercentCommentTextBetween)
+    } else {
+        State::Nok
+    }
+}
+
+/// Between something and something else.
+///
+/// ```markdown
+/// > | a %%b%% c
+///         ^^
+/// ```
+pub fn between(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        // Not closed.
+        None => State::Nok,
+        Some(b'\n') => {
+            tokenizer.enter(Name::LineEnding);
+            tokenizer.consume();
+            tokenizer.exit(Name::LineEnding);
+            State::Next(StateName::PercentCommentTextLineEndingAfter)
+        }
+        Some(b'%') => {
+            tokenizer.enter(Name::PercentCommentTextSequence);
+            tokenizer.consume();
+            State::Next(StateName::PercentCommentTextSequenceClose)
+        }
+        _ => {
+            tokenizer.enter(Name::PercentCommentTextValue);
+            State::Retry(StateName::PercentCommentTextValue)
+        }
+    }
+}
+
+/// After eol.
+///
+/// Blank lines can’t occur in text parsed from flow, but they can when
+/// parsing text by itself (with `inline`), so they are not allowed.
+///
+/// ```markdown
+///   | a %%b
+/// > | c%% d
+///     ^
+/// ```
+pub fn line_ending_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.check(
+        State::Nok,
+        State::Next(StateName::PercentCommentTextBetween),
+    );
+    State::Retry(StateName::BlankLineStart)
+}
+
+/// In value.
+///
+/// ```markdown
+/// > | a %%b%% c
+///         ^
+/// ```
+pub fn value(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        None | Some(b'\n' | b'%') => {
+            tokenizer.exit(Name::PercentCommentTextValue);
+            State::Retry(StateName::PercentCommentTextBetween)
+        }
+        _ => {
+            tokenizer.consume();
+            State::Next(StateName::PercentCommentTextValue)
+        }
+    }
+}
+
+/// In closing sequence, after the first `%`.
+///
+/// ```markdown
+/// > | a %%b%% c
+///           ^
+/// ```
+pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.current == Some(b'%') {
+        tokenizer.consume();
+        tokenizer.exit(Name::PercentCommentTextSequence);
+        tokenizer.exit(Name::PercentCommentText);
+        State::Ok
+    } else {
+        // Just one `%`: mark as value.
+        tokenizer.exit(Name::PercentCommentTextSequence);
+        let len = tokenizer.events.len();
+        tokenizer.events[len - 2].name = Name::PercentCommentTextValue;
+        tokenizer.events[len - 1].name = Name::PercentCommentTextValue;
+        State::Retry(StateName::PercentCommentTextBetween)
+    }
+}
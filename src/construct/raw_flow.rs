@@ -156,9 +156,7 @@ use crate::util::{
 ///   | ~~~
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.code_fenced
-        || tokenizer.parse_state.options.constructs.math_flow
-    {
+    if tokenizer.parse_state.constructs.code_fenced || tokenizer.parse_state.constructs.math_flow {
         if matches!(tokenizer.current, Some(b'\t' | b' ')) {
             tokenizer.attempt(
                 State::Next(StateName::RawFlowBeforeSequenceOpen),
@@ -167,7 +165,7 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
             return State::Retry(space_or_tab_min_max(
                 tokenizer,
                 0,
-                if tokenizer.parse_state.options.constructs.code_indented {
+                if tokenizer.parse_state.constructs.code_indented {
                     TAB_SIZE - 1
                 } else {
                     usize::MAX
@@ -206,10 +204,10 @@ pub fn before_sequence_open(tokenizer: &mut Tokenizer) -> State {
     }
 
     // Code (fenced).
-    if (tokenizer.parse_state.options.constructs.code_fenced
+    if (tokenizer.parse_state.constructs.code_fenced
         && matches!(tokenizer.current, Some(b'`' | b'~')))
         // Math (flow).
-        || (tokenizer.parse_state.options.constructs.math_flow && tokenizer.current == Some(b'$'))
+        || (tokenizer.parse_state.constructs.math_flow && tokenizer.current == Some(b'$'))
     {
         tokenizer.tokenize_state.marker = tokenizer.current.unwrap();
         tokenizer.tokenize_state.size_c = prefix;
@@ -474,7 +472,7 @@ pub fn close_start(tokenizer: &mut Tokenizer) -> State {
         State::Retry(space_or_tab_min_max(
             tokenizer,
             0,
-            if tokenizer.parse_state.options.constructs.code_indented {
+            if tokenizer.parse_state.constructs.code_indented {
                 TAB_SIZE - 1
             } else {
                 usize::MAX
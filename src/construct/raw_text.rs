@@ -129,9 +129,9 @@ use crate::tokenizer::Tokenizer;
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
     // Code (text):
-    if ((tokenizer.parse_state.options.constructs.code_text && tokenizer.current == Some(b'`'))
+    if ((tokenizer.parse_state.constructs.code_text && tokenizer.current == Some(b'`'))
         // Math (text):
-        || (tokenizer.parse_state.options.constructs.math_text && tokenizer.current == Some(b'$')))
+        || (tokenizer.parse_state.constructs.math_text && tokenizer.current == Some(b'$')))
         // Not the same marker (except when escaped).
         && (tokenizer.previous != tokenizer.current
             || (!tokenizer.events.is_empty()
@@ -193,19 +193,12 @@ pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn between(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
-        None => {
-            tokenizer.tokenize_state.marker = 0;
-            tokenizer.tokenize_state.size = 0;
-            tokenizer.tokenize_state.token_1 = Name::Data;
-            tokenizer.tokenize_state.token_2 = Name::Data;
-            tokenizer.tokenize_state.token_3 = Name::Data;
-            State::Nok
-        }
+        None => State::Retry(StateName::RawTextNok),
         Some(b'\n') => {
             tokenizer.enter(Name::LineEnding);
             tokenizer.consume();
             tokenizer.exit(Name::LineEnding);
-            State::Next(StateName::RawTextBetween)
+            State::Next(StateName::RawTextLineEndingAfter)
         }
         _ => {
             if tokenizer.current == Some(tokenizer.tokenize_state.marker) {
@@ -219,6 +212,39 @@ pub fn between(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// After eol.
+///
+/// Blank lines can’t occur in text parsed from flow, but they can when
+/// parsing text by itself (with `inline`), so they are not allowed.
+///
+/// ```markdown
+///   | `a
+/// > | b`
+///     ^
+/// ```
+pub fn line_ending_after(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.check(
+        State::Next(StateName::RawTextNok),
+        State::Next(StateName::RawTextBetween),
+    );
+    State::Retry(StateName::BlankLineStart)
+}
+
+/// Not raw (text).
+///
+/// ```markdown
+/// > | `a
+///       ^
+/// ```
+pub fn nok(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.marker = 0;
+    tokenizer.tokenize_state.size = 0;
+    tokenizer.tokenize_state.token_1 = Name::Data;
+    tokenizer.tokenize_state.token_2 = Name::Data;
+    tokenizer.tokenize_state.token_3 = Name::Data;
+    State::Nok
+}
+
 /// In data.
 ///
 /// ```markdown
@@ -6,10 +6,12 @@
 //!
 //! The constructs found in text are:
 //!
+//! *   [Abbreviation][crate::construct::abbreviation]
 //! *   [Attention][crate::construct::attention] (emphasis, gfm strikethrough, strong)
 //! *   [Autolink][crate::construct::autolink]
 //! *   [Character escape][crate::construct::character_escape]
 //! *   [Character reference][crate::construct::character_reference]
+//! *   [Emoji][crate::construct::emoji]
 //! *   [Raw (text)][crate::construct::raw_text] (code (text), math (text))
 //! *   [GFM: Label start (footnote)][crate::construct::gfm_label_start_footnote]
 //! *   [GFM: Task list item check][crate::construct::gfm_task_list_item_check]
@@ -20,10 +22,12 @@
 //! *   [Label end][crate::construct::label_end]
 //! *   [MDX: expression (text)][crate::construct::mdx_expression_text]
 //! *   [MDX: JSX (text)][crate::construct::mdx_jsx_text]
+//! *   [Percent comment (text)][crate::construct::percent_comment_text]
 //!
 //! > 👉 **Note**: for performance reasons, hard break (trailing) is formed by
 //! > [whitespace][crate::construct::partial_whitespace].
 
+use crate::construct::abbreviation::resolve as resolve_abbreviation;
 use crate::construct::gfm_autolink_literal::resolve as resolve_gfm_autolink_literal;
 use crate::construct::partial_whitespace::resolve_whitespace;
 use crate::resolve::Name as ResolveName;
@@ -32,11 +36,13 @@ use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
 
 /// Characters that can start something in text.
-const MARKERS: [u8; 16] = [
+const MARKERS: [u8; 18] = [
     b'!',  // `label_start_image`
     b'$',  // `raw_text` (math (text))
+    b'%',  // `percent_comment_text`
     b'&',  // `character_reference`
     b'*',  // `attention` (emphasis, strong)
+    b':',  // `emoji`
     b'<',  // `autolink`, `html_text`, `mdx_jsx_text`
     b'H',  // `gfm_autolink_literal` (`protocol` kind)
     b'W',  // `gfm_autolink_literal` (`www.` kind)
@@ -98,6 +104,13 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::RawTextStart)
         }
+        Some(b'%') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
+            );
+            State::Retry(StateName::PercentCommentTextStart)
+        }
         Some(b'&') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
@@ -105,6 +118,13 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::CharacterReferenceStart)
         }
+        Some(b':') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
+            );
+            State::Retry(StateName::EmojiStart)
+        }
         // attention (emphasis, gfm strikethrough, strong)
         Some(b'*' | b'_' | b'~') => {
             tokenizer.attempt(
@@ -246,7 +266,7 @@ pub fn before_data(tokenizer: &mut Tokenizer) -> State {
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     resolve_whitespace(
         tokenizer,
-        tokenizer.parse_state.options.constructs.hard_break_trailing,
+        tokenizer.parse_state.constructs.hard_break_trailing,
         true,
     );
 
@@ -259,6 +279,12 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
         resolve_gfm_autolink_literal(tokenizer);
     }
 
+    if tokenizer.parse_state.constructs.abbreviation
+        && !tokenizer.parse_state.abbreviations.is_empty()
+    {
+        resolve_abbreviation(tokenizer);
+    }
+
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
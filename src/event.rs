@@ -5,6 +5,99 @@ use crate::util::constant::TAB_SIZE;
 /// Semantic label of a span.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Name {
+    /// Abbreviation.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a HTML b
+    ///       ^^^^
+    /// ```
+    Abbreviation,
+    /// Abbreviation definition.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`AbbreviationDefinitionLabel`][Name::AbbreviationDefinitionLabel],
+    ///     [`AbbreviationDefinitionMarker`][Name::AbbreviationDefinitionMarker],
+    ///     [`AbbreviationDefinitionValue`][Name::AbbreviationDefinitionValue],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    AbbreviationDefinition,
+    /// Abbreviation definition label.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///       ^^^^
+    /// ```
+    AbbreviationDefinitionLabel,
+    /// Abbreviation definition marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///     ^^    ^^
+    /// ```
+    AbbreviationDefinitionMarker,
+    /// Abbreviation definition value.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`AbbreviationDefinition`][Name::AbbreviationDefinition]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`abbreviation`][crate::construct::abbreviation]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | *[HTML]: Hyper Text Markup Language
+    ///              ^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    AbbreviationDefinitionValue,
     /// Attention sequence.
     ///
     /// > 👉 **Note**: this is used while parsing but compiled away.
@@ -770,6 +863,90 @@ pub enum Name {
     ///      ^
     /// ```
     DefinitionLabelString,
+    /// Definition list.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`BlankLineEnding`][Name::BlankLineEnding],
+    ///     [`BlockQuotePrefix`][Name::BlockQuotePrefix],
+    ///     [`DefinitionListDescription`][Name::DefinitionListDescription],
+    ///     [`DefinitionListTerm`][Name::DefinitionListTerm],
+    ///     [`LineEnding`][Name::LineEnding],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// > | : b
+    ///     ^^^
+    /// ```
+    DefinitionList,
+    /// Definition list description.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionList`][Name::DefinitionList]
+    /// *   **Content model**:
+    ///     [`DefinitionListDescriptionMarker`][Name::DefinitionListDescriptionMarker],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^^^
+    /// ```
+    DefinitionListDescription,
+    /// Definition list description marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionListDescription`][Name::DefinitionListDescription]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    ///   | a
+    /// > | : b
+    ///     ^
+    /// ```
+    DefinitionListDescriptionMarker,
+    /// Definition list term.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`DefinitionList`][Name::DefinitionList]
+    /// *   **Content model**:
+    ///     [text content][crate::construct::text]
+    /// *   **Construct**:
+    ///     [`definition_list`][crate::construct::definition_list]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    ///   | : b
+    /// ```
+    DefinitionListTerm,
     /// Definition marker.
     ///
     /// ## Info
@@ -846,6 +1023,61 @@ pub enum Name {
     ///             ^
     /// ```
     DefinitionTitleString,
+    /// Whole emoji.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`EmojiMarker`][Name::EmojiMarker],
+    ///     [`EmojiName`][Name::EmojiName]
+    /// *   **Construct**:
+    ///     [`emoji`][crate::construct::emoji]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a :smile: b
+    ///       ^^^^^^^
+    /// ```
+    Emoji,
+    /// Emoji marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Emoji`][Name::Emoji]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`emoji`][crate::construct::emoji]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a :smile: b
+    ///       ^     ^
+    /// ```
+    EmojiMarker,
+    /// Emoji name.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Emoji`][Name::Emoji]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`emoji`][crate::construct::emoji]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a :smile: b
+    ///        ^^^^^
+    /// ```
+    EmojiName,
     /// Emphasis.
     ///
     /// ## Info
@@ -2993,6 +3225,123 @@ pub enum Name {
     ///          ^
     /// ```
     MdxJsxTagSelfClosingMarker,
+    /// Percent comment (flow).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [flow content][crate::construct::flow]
+    /// *   **Content model**:
+    ///     [`PercentCommentFlowSequence`][Name::PercentCommentFlowSequence],
+    ///     [`PercentCommentFlowValue`][Name::PercentCommentFlowValue],
+    ///     [`SpaceOrTab`][Name::SpaceOrTab],
+    ///     [`LineEnding`][Name::LineEnding]
+    /// *   **Construct**:
+    ///     [`percent_comment_flow`][crate::construct::percent_comment_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | %%
+    ///     ^^
+    /// > | a
+    ///     ^
+    /// > | %%
+    ///     ^^
+    /// ```
+    PercentCommentFlow,
+    /// Percent comment (flow) sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`PercentCommentFlow`][Name::PercentCommentFlow]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`percent_comment_flow`][crate::construct::percent_comment_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | %% a %%
+    ///     ^^   ^^
+    /// ```
+    PercentCommentFlowSequence,
+    /// Percent comment (flow) value.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`PercentCommentFlow`][Name::PercentCommentFlow]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`percent_comment_flow`][crate::construct::percent_comment_flow]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | %% a %%
+    ///       ^^^
+    /// ```
+    PercentCommentFlowValue,
+    /// Percent comment (text).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`PercentCommentTextSequence`][Name::PercentCommentTextSequence],
+    ///     [`PercentCommentTextValue`][Name::PercentCommentTextValue],
+    ///     [`LineEnding`][Name::LineEnding]
+    /// *   **Construct**:
+    ///     [`percent_comment_text`][crate::construct::percent_comment_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a %%b%% c
+    ///       ^^^^^
+    /// ```
+    PercentCommentText,
+    /// Percent comment (text) sequence.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`PercentCommentText`][Name::PercentCommentText]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`percent_comment_text`][crate::construct::percent_comment_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a %%b%% c
+    ///       ^^ ^^
+    /// ```
+    PercentCommentTextSequence,
+    /// Percent comment (text) value.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`PercentCommentText`][Name::PercentCommentText]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`percent_comment_text`][crate::construct::percent_comment_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a %%b%% c
+    ///         ^
+    /// ```
+    PercentCommentTextValue,
     /// Paragraph.
     ///
     /// ## Info
@@ -3375,7 +3724,11 @@ pub enum Name {
 }
 
 /// List of void events, used to make sure everything is working well.
-pub const VOID_EVENTS: [Name; 76] = [
+pub const VOID_EVENTS: [Name; 87] = [
+    Name::Abbreviation,
+    Name::AbbreviationDefinitionLabel,
+    Name::AbbreviationDefinitionMarker,
+    Name::AbbreviationDefinitionValue,
     Name::AttentionSequence,
     Name::AutolinkEmail,
     Name::AutolinkMarker,
@@ -3397,8 +3750,11 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::Data,
     Name::DefinitionDestinationLiteralMarker,
     Name::DefinitionLabelMarker,
+    Name::DefinitionListDescriptionMarker,
     Name::DefinitionMarker,
     Name::DefinitionTitleMarker,
+    Name::EmojiMarker,
+    Name::EmojiName,
     Name::EmphasisSequence,
     Name::FrontmatterChunk,
     Name::GfmAutolinkLiteralEmail,
@@ -3446,6 +3802,10 @@ pub const VOID_EVENTS: [Name; 76] = [
     Name::MdxJsxTagAttributeNameLocal,
     Name::MdxJsxTagAttributeValueLiteralMarker,
     Name::MdxJsxEsWhitespace,
+    Name::PercentCommentFlowSequence,
+    Name::PercentCommentFlowValue,
+    Name::PercentCommentTextSequence,
+    Name::PercentCommentTextValue,
     Name::ReferenceMarker,
     Name::ResourceMarker,
     Name::ResourceTitleMarker,
@@ -0,0 +1,285 @@
+//! Turn markdown into a syntax tree again, after an edit, reusing the parts
+//! of the previous tree that the edit did not affect.
+//!
+//! This works at the granularity of blocks at the top level of the document
+//! (children of the root): the blocks that the edit touches are parsed again,
+//! together with the block before and after them.
+//! Blocks further away are reused, with their positions moved.
+//!
+//! Parsing from the start of a block, in the middle of a document, gives the
+//! same result as parsing the whole document: the lines before it, and
+//! whether they are closed, do not change.
+//! Where to stop is trickier: the edit could open something that runs into
+//! the following blocks, such as code (fenced) without closing fence.
+//! So, the block after the edit is parsed too, and checked to be the same as
+//! before.
+//!
+//! In some cases the whole document is parsed instead, because the result of
+//! one block can depend on other blocks:
+//!
+//! *   when there are definitions or GFM footnote definitions, in the previous
+//!     tree or in the parsed blocks, as references anywhere can match them
+//! *   when abbreviations are on, as they are also defined anywhere
+//! *   when MDX is on, as its nodes include more positional info
+//! *   when parsing as `inline`, as there are no blocks
+//! *   when the block after the edit changed
+
+use crate::mdast::{Node, Root};
+use crate::parser::parse_with_constructs;
+use crate::to_mdast::compile;
+use crate::unist::{Point, Position};
+use crate::{to_mdast, ParseOptions};
+use alloc::{string::String, vec::Vec};
+
+/// Change to a markdown document, as byte offsets.
+///
+/// Describes that the bytes from `start` to `old_end` (exclusive) in the
+/// previous value were replaced by the bytes from `start` to `new_end`
+/// (exclusive) in the current value.
+///
+/// ## Examples
+///
+/// Typing `b` after `a` in `a c` gives `ab c`, which is:
+///
+/// ```
+/// use markdown::Edit;
+///
+/// let edit = Edit { start: 1, old_end: 1, new_end: 2 };
+/// # assert_eq!(edit.start, 1);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Edit {
+    /// Offset where the change starts, the same in the previous and current
+    /// value.
+    pub start: usize,
+    /// Offset where the change ended in the previous value.
+    pub old_end: usize,
+    /// Offset where the change ends in the current value.
+    pub new_end: usize,
+}
+
+/// Turn `value` into a syntax tree, reusing blocks from `previous`.
+pub fn reparse(
+    previous: &Node,
+    value: &str,
+    edit: &Edit,
+    options: &ParseOptions,
+) -> Result<Node, String> {
+    let constructs = &options.constructs;
+
+    // Too global or too positional to reuse anything.
+    if options.inline
+        || constructs.abbreviation
+        || constructs.mdx_esm
+        || constructs.mdx_expression_flow
+        || constructs.mdx_expression_text
+        || constructs.mdx_jsx_flow
+        || constructs.mdx_jsx_text
+        || has_definitions(previous)
+    {
+        return to_mdast(value, options);
+    }
+
+    let (children, root_end) = match previous {
+        Node::Root(root) => match root.position {
+            Some(ref position) => (&root.children, &position.end),
+            None => return to_mdast(value, options),
+        },
+        _ => return to_mdast(value, options),
+    };
+
+    let mut offsets = Vec::with_capacity(children.len());
+    for child in children {
+        match child.position() {
+            Some(position) => offsets.push((position.start.offset, position.end.offset)),
+            None => return to_mdast(value, options),
+        }
+    }
+
+    let bytes = value.as_bytes();
+
+    if edit.start > edit.old_end
+        || edit.start > edit.new_end
+        || edit.old_end > root_end.offset
+        || edit.new_end > bytes.len()
+        // The rest of the document must be the same length as before.
+        || root_end.offset - edit.old_end != bytes.len() - edit.new_end
+    {
+        return to_mdast(value, options);
+    }
+
+    // First block that touches the edit, and the last block that starts
+    // before it ends.
+    let first = offsets
+        .iter()
+        .position(|d| d.1 >= edit.start)
+        .unwrap_or(children.len());
+    let last = offsets.iter().rposition(|d| d.0 <= edit.old_end);
+
+    // Include one block before, and one block after.
+    // Then, include more blocks until there is a blank line between them and
+    // the blocks that are reused, as the state of the parser can otherwise
+    // leak from one block into the next (such as whether a paragraph was
+    // interrupted).
+    let mut before = first.saturating_sub(1);
+    while before > 0 && !blank_between(bytes, offsets[before - 1].1, offsets[before].0) {
+        before -= 1;
+    }
+
+    let mut after = last.map_or(0, |last| last + 1).max(first);
+    let move_offset = |offset: usize| offset + edit.new_end - edit.old_end;
+    while after + 1 < children.len()
+        && !blank_between(
+            bytes,
+            move_offset(offsets[after].1),
+            move_offset(offsets[after + 1].0),
+        )
+    {
+        after += 1;
+    }
+
+    // Where to start parsing again: the start of the line of the block
+    // before, or the start of the document.
+    let (start, start_line) = if before == 0 {
+        (0, 1)
+    } else {
+        let point = &children[before].position().unwrap().start;
+        (line_start(bytes, point.offset), point.line)
+    };
+
+    // Where to stop parsing again: the start of the line of the block after
+    // the block after, or the end of the document.
+    let (end, shift) = if after + 1 < children.len() {
+        let point = &children[after + 1].position().unwrap().start;
+        let end = line_start(bytes, move_offset(point.offset));
+        let end_line = start_line + line_endings(&bytes[start..end]);
+        (end, Some((point.line, end_line)))
+    } else {
+        (bytes.len(), None)
+    };
+
+    // Frontmatter can only occur at the start of the document.
+    let mut constructs = options.constructs.clone();
+    constructs.frontmatter = constructs.frontmatter && start == 0;
+    let (events, parse_state) = parse_with_constructs(&value[start..end], &constructs, options)?;
+    let mut tree = compile(&events, parse_state.bytes, &options.emoji_shortcodes)?;
+
+    if has_definitions(&tree) {
+        return to_mdast(value, options);
+    }
+
+    move_tree(&mut tree, |line, offset| {
+        (line + start_line - 1, offset + start)
+    });
+
+    let (mut parsed, mut parsed_end) = match tree {
+        Node::Root(root) => (root.children, root.position.unwrap().end),
+        _ => unreachable!("expected root"),
+    };
+
+    let mut reused = children[(after + 1).min(children.len())..].to_vec();
+
+    if let Some((old_line, new_line)) = shift {
+        let move_point =
+            |line: usize, offset: usize| (line + new_line - old_line, move_offset(offset));
+
+        // The block after the edit must not have changed.
+        let mut expected = children[after].clone();
+        move_tree(&mut expected, move_point);
+
+        if parsed.last() != Some(&expected) {
+            return to_mdast(value, options);
+        }
+
+        for node in &mut reused {
+            move_tree(node, move_point);
+        }
+
+        let (line, offset) = move_point(root_end.line, root_end.offset);
+        parsed_end.line = line;
+        parsed_end.offset = offset;
+        parsed_end.column = root_end.column;
+    }
+
+    let mut result = children[..before].to_vec();
+    result.append(&mut parsed);
+    result.append(&mut reused);
+
+    Ok(Node::Root(Root {
+        children: result,
+        position: Some(Position {
+            start: Point::new(1, 1, 0),
+            end: parsed_end,
+        }),
+    }))
+}
+
+/// Check if there are definitions or footnote definitions in `node`.
+fn has_definitions(node: &Node) -> bool {
+    match node {
+        Node::Definition(_) | Node::FootnoteDefinition(_) => true,
+        _ => matches!(node.children(), Some(children) if children.iter().any(has_definitions)),
+    }
+}
+
+/// Move the positions in `node` (and its descendants), with a function that
+/// maps a line and offset to new ones.
+///
+/// Columns do not change, as only whole lines are moved.
+fn move_tree<F>(node: &mut Node, map: F)
+where
+    F: Fn(usize, usize) -> (usize, usize) + Copy,
+{
+    if let Some(position) = node.position_mut() {
+        let (line, offset) = map(position.start.line, position.start.offset);
+        position.start.line = line;
+        position.start.offset = offset;
+        let (line, offset) = map(position.end.line, position.end.offset);
+        position.end.line = line;
+        position.end.offset = offset;
+    }
+
+    if let Some(children) = node.children_mut() {
+        for child in children {
+            move_tree(child, map);
+        }
+    }
+}
+
+/// Check if there is a blank line between two blocks, from the end of one
+/// to the start of the other.
+fn blank_between(bytes: &[u8], from: usize, to: usize) -> bool {
+    line_endings(&bytes[from..to]) > 1
+}
+
+/// Get the offset of the start of the line that `offset` is on.
+fn line_start(bytes: &[u8], offset: usize) -> usize {
+    let mut index = offset;
+
+    while index > 0 && !matches!(bytes[index - 1], b'\n' | b'\r') {
+        index -= 1;
+    }
+
+    index
+}
+
+/// Count line endings (`\n`, `\r`, or `\r\n`) in `bytes`.
+fn line_endings(bytes: &[u8]) -> usize {
+    let mut index = 0;
+    let mut count = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\r' if index + 1 < bytes.len() && bytes[index + 1] == b'\n' => {
+                index += 1;
+                count += 1;
+            }
+            b'\n' | b'\r' => count += 1,
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    count
+}
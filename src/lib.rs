@@ -9,8 +9,23 @@
 //!     — like `to_html` but lets you configure how markdown is turned into
 //!     HTML, such as allowing dangerous HTML or turning on/off different
 //!     constructs (GFM, MDX, and the like)
+//! *   [`to_html_from_bytes()`][]
+//!     — like `to_html_with_options` but takes bytes, checked to be UTF-8
+//! *   [`to_html_with_constructs()`][]
+//!     — like `to_html_with_options` but with other constructs
+//! *   [`to_html_with_output()`][]
+//!     — like `to_html_with_options` but also returns extra info, such as
+//!     the raw frontmatter
 //! *   [`to_mdast()`][]
 //!     — turn markdown into a syntax tree
+//! *   [`to_mdast_incremental()`][]
+//!     — like `to_mdast` but reuses a previous tree after an edit, such as
+//!     in editors
+//! *   [`to_markdown()`][]
+//!     — turn a syntax tree back into markdown
+//! *   [`to_speech_segments()`][]
+//!     — turn markdown into a flat list of plain-text utterances, such as for
+//!     text-to-speech
 #![no_std]
 #![deny(clippy::pedantic)]
 #![allow(clippy::doc_link_with_quotes)]
@@ -25,12 +40,15 @@ extern crate alloc;
 mod configuration;
 mod construct;
 mod event;
+mod incremental;
 mod parser;
 mod resolve;
 mod state;
 mod subtokenize;
 mod to_html;
+mod to_markdown;
 mod to_mdast;
+mod to_speech;
 mod tokenizer;
 mod util;
 
@@ -53,9 +71,21 @@ pub use util::mdx::{
     ExpressionParse as MdxExpressionParse, Signal as MdxSignal,
 };
 
-pub use configuration::{CompileOptions, Constructs, Options, ParseOptions};
+pub use configuration::{
+    CompileOptions, Constructs, DisallowedHtml, FlowConstruct, ImageEmptyAlt, ListIndent, Options,
+    ParseOptions, SerializeOptions, SpeechOptions,
+};
+
+pub use incremental::Edit;
+
+pub use to_html::HtmlOutput;
+
+pub use parser::Stats;
 
-use alloc::string::String;
+pub use to_speech::{Segment, SegmentKind};
+
+use alloc::{format, string::String, vec::Vec};
+use core::str;
 
 /// Turn markdown into HTML.
 ///
@@ -74,6 +104,34 @@ pub fn to_html(value: &str) -> String {
     to_html_with_options(value, &Options::default()).unwrap()
 }
 
+/// Turn markdown text into HTML.
+///
+/// Like [`to_html()`][], but parses the whole input as text (see
+/// [`inline`][ParseOptions::inline]), so there are no blocks such as
+/// paragraphs.
+/// This is useful for inline snippets, such as titles.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::to_html_inline;
+///
+/// assert_eq!(to_html_inline("**Hello**, [world](#)!"), "<strong>Hello</strong>, <a href=\"#\">world</a>!");
+/// ```
+pub fn to_html_inline(value: &str) -> String {
+    to_html_with_options(
+        value,
+        &Options {
+            parse: ParseOptions {
+                inline: true,
+                ..ParseOptions::default()
+            },
+            ..Options::default()
+        },
+    )
+    .unwrap()
+}
+
 /// Turn markdown into HTML, with configuration.
 ///
 /// ## Errors
@@ -83,6 +141,8 @@ pub fn to_html(value: &str) -> String {
 /// However, MDX does have syntax errors.
 /// When MDX is turned on, there are several errors that can occur with how
 /// expressions, ESM, and JSX are written.
+/// It also errors when `options` contains an invalid tag name, such as in
+/// [`emphasis_tag_name`][CompileOptions::emphasis_tag_name].
 ///
 /// ## Examples
 ///
@@ -110,10 +170,167 @@ pub fn to_html(value: &str) -> String {
 /// # }
 /// ```
 pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, String> {
-    let (events, parse_state) = parser::parse(value, &options.parse)?;
+    Ok(to_html_with_output(value, options)?.value)
+}
+
+/// Turn markdown, as bytes, into HTML, with configuration.
+///
+/// Like [`to_html_with_options()`][], but takes bytes (such as read from a
+/// file), which are checked to be valid UTF-8 once.
+///
+/// ## Errors
+///
+/// `to_html_from_bytes()` errors when `value` is not valid UTF-8, instead of
+/// panicking.
+/// Otherwise, it errors like [`to_html_with_options()`][]: never with normal
+/// markdown, but it can with MDX.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_from_bytes, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let result = to_html_from_bytes(b"# Hello, world!", &Options::default())?;
+///
+/// assert_eq!(result, "<h1>Hello, world!</h1>");
+///
+/// // Invalid UTF-8 is an error:
+/// assert!(to_html_from_bytes(b"a\xFFb", &Options::default()).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_from_bytes(value: &[u8], options: &Options) -> Result<String, String> {
+    match str::from_utf8(value) {
+        Ok(value) => to_html_with_options(value, options),
+        Err(error) => Err(format!(
+            "Unexpected invalid UTF-8 at byte {}, expected valid UTF-8",
+            error.valid_up_to()
+        )),
+    }
+}
+
+/// Turn markdown into HTML, with configuration, but with other constructs.
+///
+/// Like [`to_html_with_options()`][], but uses `constructs` instead of the
+/// constructs in `options`.
+/// This is useful to turn constructs on or off per call, without rebuilding
+/// options (which cannot be cloned, as they can include functions to parse
+/// MDX).
+/// It goes through the same steps as [`to_html_with_output()`][], but, like
+/// [`to_html_with_options()`][], only returns the HTML: the extra info, such
+/// as [`toc`][CompileOptions::toc] or
+/// [`collect_stats`][CompileOptions::collect_stats], is not returned.
+///
+/// ## Errors
+///
+/// `to_html_with_constructs()` never errors with normal markdown because
+/// markdown does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// expressions, ESM, and JSX are written.
+/// It also errors when `options` contains an invalid tag name, such as in
+/// [`emphasis_tag_name`][CompileOptions::emphasis_tag_name].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_constructs, Constructs, Options};
+/// # fn main() -> Result<(), String> {
+///
+/// let options = Options::default();
+///
+/// let result = to_html_with_constructs(
+///     "*a* `b`",
+///     &Constructs {
+///         attention: false,
+///         ..Constructs::default()
+///     },
+///     &options,
+/// )?;
+///
+/// assert_eq!(result, "<p>*a* <code>b</code></p>");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_with_constructs(
+    value: &str,
+    constructs: &Constructs,
+    options: &Options,
+) -> Result<String, String> {
+    Ok(to_html_with_output_impl(value, constructs, options)?.value)
+}
+
+/// Turn markdown into HTML, with configuration, and get some extra info.
+///
+/// Like [`to_html_with_options()`][], but also returns info found while
+/// compiling, such as the raw value of frontmatter.
+///
+/// ## Errors
+///
+/// `to_html_with_output()` never errors with normal markdown because markdown
+/// does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+/// When MDX is turned on, there are several errors that can occur with how
+/// expressions, ESM, and JSX are written.
+/// It also errors when `options` contains an invalid tag name, such as in
+/// [`emphasis_tag_name`][CompileOptions::emphasis_tag_name].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_output, Constructs, Options, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let result = to_html_with_output(
+///     "---\ntitle: Neptune\n---\n# Hi",
+///     &Options {
+///         parse: ParseOptions {
+///             constructs: Constructs {
+///                 frontmatter: true,
+///                 ..Constructs::default()
+///             },
+///             ..ParseOptions::default()
+///         },
+///         ..Options::default()
+///     },
+/// )?;
+///
+/// assert_eq!(result.value, "<h1>Hi</h1>");
+/// assert_eq!(result.frontmatter, Some("title: Neptune".into()));
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_with_output(value: &str, options: &Options) -> Result<HtmlOutput, String> {
+    to_html_with_output_impl(value, &options.parse.constructs, options)
+}
+
+/// Turn markdown into HTML, with other constructs, and get some extra info.
+fn to_html_with_output_impl(
+    value: &str,
+    constructs: &Constructs,
+    options: &Options,
+) -> Result<HtmlOutput, String> {
+    to_html::check_options(&options.compile)?;
+
+    if options.compile.collect_stats {
+        let (events, parse_state, stats) =
+            parser::parse_with_stats(value, constructs, &options.parse)?;
+        let mut output = to_html::compile(
+            &events,
+            parse_state.bytes,
+            &options.parse.emoji_shortcodes,
+            &options.compile,
+        );
+        output.stats = Some(stats);
+        return Ok(output);
+    }
+
+    let (events, parse_state) = parser::parse_with_constructs(value, constructs, &options.parse)?;
     Ok(to_html::compile(
         &events,
         parse_state.bytes,
+        &options.parse.emoji_shortcodes,
         &options.compile,
     ))
 }
@@ -143,6 +360,128 @@ pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, St
 /// ```
 pub fn to_mdast(value: &str, options: &ParseOptions) -> Result<mdast::Node, String> {
     let (events, parse_state) = parser::parse(value, options)?;
-    let node = to_mdast::compile(&events, parse_state.bytes)?;
+    let node = to_mdast::compile(&events, parse_state.bytes, &options.emoji_shortcodes)?;
     Ok(node)
 }
+
+/// Turn markdown into a syntax tree again, after an edit.
+///
+/// This gives the same result as [`to_mdast()`][], but parses only the
+/// blocks around `edit` again, and reuses the other blocks from `previous`
+/// (with their positions moved).
+/// This is useful in editors, where parsing the whole document after each
+/// keystroke is wasteful.
+///
+/// `previous` must be the result of parsing the value before the edit with
+/// the same `options`.
+/// When reusing is not possible, such as when there are definitions or when
+/// `edit` does not fit `previous`, the whole document is parsed.
+///
+/// ## Errors
+///
+/// See [`to_mdast()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_mdast, to_mdast_incremental, Edit, ParseOptions};
+/// # fn main() -> Result<(), String> {
+/// let options = ParseOptions::default();
+///
+/// let tree = to_mdast("# a\n\nb\n\nc", &options)?;
+///
+/// // Replace `b` with `*b*`:
+/// let tree = to_mdast_incremental(
+///     &tree,
+///     "# a\n\n*b*\n\nc",
+///     &Edit { start: 5, old_end: 6, new_end: 8 },
+///     &options,
+/// )?;
+///
+/// assert_eq!(tree, to_mdast("# a\n\n*b*\n\nc", &options)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_mdast_incremental(
+    previous: &mdast::Node,
+    value: &str,
+    edit: &Edit,
+    options: &ParseOptions,
+) -> Result<mdast::Node, String> {
+    incremental::reparse(previous, value, edit, options)
+}
+
+/// Turn a syntax tree into markdown.
+///
+/// This is the inverse of [`to_mdast()`][]: the result, when parsed again,
+/// results in an equivalent tree.
+/// Characters that would otherwise be seen as markdown syntax are escaped.
+///
+/// ## Errors
+///
+/// `to_markdown()` errors if `options` contains an invalid marker.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_markdown, to_mdast, ParseOptions, SerializeOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let tree = to_mdast("Hey, __you__!\n===", &ParseOptions::default())?;
+///
+/// assert_eq!(
+///     to_markdown(&tree, &SerializeOptions::default())?,
+///     "# Hey, **you**!\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_markdown(node: &mdast::Node, options: &SerializeOptions) -> Result<String, String> {
+    to_markdown::serialize(node, options)
+}
+
+/// Turn markdown into speech segments.
+///
+/// Each segment is a plain-text utterance, with a hint about what kind of
+/// content it came from (heading, paragraph, list item, code), so that a
+/// text-to-speech engine can adjust prosody.
+/// Markup, such as HTML, is not spoken.
+/// Pass [`SpeechOptions`][] to configure how things are spoken, such as
+/// whether to summarize code.
+///
+/// ## Errors
+///
+/// `to_speech_segments()` never errors with normal markdown because markdown
+/// does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors, see [`to_mdast()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_speech_segments, ParseOptions, Segment, SegmentKind, SpeechOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let segments = to_speech_segments(
+///     "# Hi\n\n*Mercury*.",
+///     &ParseOptions::default(),
+///     &SpeechOptions::default(),
+/// )?;
+///
+/// assert_eq!(
+///     segments,
+///     vec![
+///         Segment { kind: SegmentKind::Heading, value: "Hi".into() },
+///         Segment { kind: SegmentKind::Paragraph, value: "Mercury.".into() },
+///     ]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_speech_segments(
+    value: &str,
+    options: &ParseOptions,
+    speech_options: &SpeechOptions,
+) -> Result<Vec<Segment>, String> {
+    let tree = to_mdast(value, options)?;
+    Ok(to_speech::compile(&tree, speech_options))
+}
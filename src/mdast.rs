@@ -16,7 +16,7 @@ pub type Stop = (usize, usize);
 /// Explicitness of a reference.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(
-    feature = "json",
+    feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "lowercase")
 )]
@@ -34,7 +34,7 @@ pub enum ReferenceKind {
 /// Used to align the contents of table cells within a table.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(
-    feature = "json",
+    feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "lowercase")
 )]
@@ -84,9 +84,9 @@ pub enum AlignKind {
 /// Nodes.
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(
-    feature = "json",
+    feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "type")
+    serde(tag = "type", rename_all = "camelCase")
 )]
 pub enum Node {
     // Document:
@@ -95,11 +95,14 @@ pub enum Node {
 
     // Container:
     /// Block quote.
+    #[cfg_attr(feature = "serde", serde(rename = "blockquote"))]
     BlockQuote(BlockQuote),
     /// Footnote definition.
     FootnoteDefinition(FootnoteDefinition),
     /// MDX: JSX element (container).
     MdxJsxFlowElement(MdxJsxFlowElement),
+    /// Definition list.
+    DefinitionList(DefinitionList),
     /// List.
     List(List),
 
@@ -171,6 +174,12 @@ pub enum Node {
     /// List item.
     ListItem(ListItem),
 
+    // Definition list content.
+    /// Definition list term.
+    DefinitionListTerm(DefinitionListTerm),
+    /// Definition list description.
+    DefinitionListDescription(DefinitionListDescription),
+
     // Content.
     /// Definition.
     Definition(Definition),
@@ -187,6 +196,7 @@ impl fmt::Debug for Node {
             Node::FootnoteDefinition(x) => write!(f, "{:?}", x),
             Node::MdxJsxFlowElement(x) => write!(f, "{:?}", x),
             Node::List(x) => write!(f, "{:?}", x),
+            Node::DefinitionList(x) => write!(f, "{:?}", x),
             Node::MdxjsEsm(x) => write!(f, "{:?}", x),
             Node::Toml(x) => write!(f, "{:?}", x),
             Node::Yaml(x) => write!(f, "{:?}", x),
@@ -214,6 +224,8 @@ impl fmt::Debug for Node {
             Node::TableRow(x) => write!(f, "{:?}", x),
             Node::TableCell(x) => write!(f, "{:?}", x),
             Node::ListItem(x) => write!(f, "{:?}", x),
+            Node::DefinitionListTerm(x) => write!(f, "{:?}", x),
+            Node::DefinitionListDescription(x) => write!(f, "{:?}", x),
             Node::Definition(x) => write!(f, "{:?}", x),
             Node::Paragraph(x) => write!(f, "{:?}", x),
         }
@@ -233,6 +245,7 @@ impl ToString for Node {
             Node::FootnoteDefinition(x) => children_to_string(&x.children),
             Node::MdxJsxFlowElement(x) => children_to_string(&x.children),
             Node::List(x) => children_to_string(&x.children),
+            Node::DefinitionList(x) => children_to_string(&x.children),
             Node::Delete(x) => children_to_string(&x.children),
             Node::Emphasis(x) => children_to_string(&x.children),
             Node::MdxJsxTextElement(x) => children_to_string(&x.children),
@@ -244,6 +257,8 @@ impl ToString for Node {
             Node::TableRow(x) => children_to_string(&x.children),
             Node::TableCell(x) => children_to_string(&x.children),
             Node::ListItem(x) => children_to_string(&x.children),
+            Node::DefinitionListTerm(x) => children_to_string(&x.children),
+            Node::DefinitionListDescription(x) => children_to_string(&x.children),
             Node::Paragraph(x) => children_to_string(&x.children),
 
             // Literals.
@@ -280,7 +295,10 @@ impl Node {
             Node::Heading(x) => Some(&x.children),
             Node::BlockQuote(x) => Some(&x.children),
             Node::List(x) => Some(&x.children),
+            Node::DefinitionList(x) => Some(&x.children),
             Node::ListItem(x) => Some(&x.children),
+            Node::DefinitionListTerm(x) => Some(&x.children),
+            Node::DefinitionListDescription(x) => Some(&x.children),
             Node::Emphasis(x) => Some(&x.children),
             Node::Strong(x) => Some(&x.children),
             Node::Link(x) => Some(&x.children),
@@ -305,7 +323,10 @@ impl Node {
             Node::Heading(x) => Some(&mut x.children),
             Node::BlockQuote(x) => Some(&mut x.children),
             Node::List(x) => Some(&mut x.children),
+            Node::DefinitionList(x) => Some(&mut x.children),
             Node::ListItem(x) => Some(&mut x.children),
+            Node::DefinitionListTerm(x) => Some(&mut x.children),
+            Node::DefinitionListDescription(x) => Some(&mut x.children),
             Node::Emphasis(x) => Some(&mut x.children),
             Node::Strong(x) => Some(&mut x.children),
             Node::Link(x) => Some(&mut x.children),
@@ -330,6 +351,7 @@ impl Node {
             Node::FootnoteDefinition(x) => x.position.as_ref(),
             Node::MdxJsxFlowElement(x) => x.position.as_ref(),
             Node::List(x) => x.position.as_ref(),
+            Node::DefinitionList(x) => x.position.as_ref(),
             Node::MdxjsEsm(x) => x.position.as_ref(),
             Node::Toml(x) => x.position.as_ref(),
             Node::Yaml(x) => x.position.as_ref(),
@@ -357,6 +379,8 @@ impl Node {
             Node::TableRow(x) => x.position.as_ref(),
             Node::TableCell(x) => x.position.as_ref(),
             Node::ListItem(x) => x.position.as_ref(),
+            Node::DefinitionListTerm(x) => x.position.as_ref(),
+            Node::DefinitionListDescription(x) => x.position.as_ref(),
             Node::Definition(x) => x.position.as_ref(),
             Node::Paragraph(x) => x.position.as_ref(),
         }
@@ -369,6 +393,7 @@ impl Node {
             Node::FootnoteDefinition(x) => x.position.as_mut(),
             Node::MdxJsxFlowElement(x) => x.position.as_mut(),
             Node::List(x) => x.position.as_mut(),
+            Node::DefinitionList(x) => x.position.as_mut(),
             Node::MdxjsEsm(x) => x.position.as_mut(),
             Node::Toml(x) => x.position.as_mut(),
             Node::Yaml(x) => x.position.as_mut(),
@@ -396,6 +421,8 @@ impl Node {
             Node::TableRow(x) => x.position.as_mut(),
             Node::TableCell(x) => x.position.as_mut(),
             Node::ListItem(x) => x.position.as_mut(),
+            Node::DefinitionListTerm(x) => x.position.as_mut(),
+            Node::DefinitionListDescription(x) => x.position.as_mut(),
             Node::Definition(x) => x.position.as_mut(),
             Node::Paragraph(x) => x.position.as_mut(),
         }
@@ -408,6 +435,7 @@ impl Node {
             Node::FootnoteDefinition(x) => x.position = position,
             Node::MdxJsxFlowElement(x) => x.position = position,
             Node::List(x) => x.position = position,
+            Node::DefinitionList(x) => x.position = position,
             Node::MdxjsEsm(x) => x.position = position,
             Node::Toml(x) => x.position = position,
             Node::Yaml(x) => x.position = position,
@@ -435,18 +463,111 @@ impl Node {
             Node::TableRow(x) => x.position = position,
             Node::TableCell(x) => x.position = position,
             Node::ListItem(x) => x.position = position,
+            Node::DefinitionListTerm(x) => x.position = position,
+            Node::DefinitionListDescription(x) => x.position = position,
             Node::Definition(x) => x.position = position,
             Node::Paragraph(x) => x.position = position,
         }
     }
 }
 
+/// What to do after visiting a node.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Visit {
+    /// Visit the children of the node (if any), then continue.
+    Continue,
+    /// Do not visit the children of the node, but continue with its
+    /// siblings.
+    Skip,
+}
+
+/// Visit `node` and its descendants, in preorder.
+///
+/// `visitor` is called with each node, before its children.
+/// Return [`Visit::Skip`][] from it to not visit the children of that node.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::{visit, Node, Visit}, to_mdast, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let tree = to_mdast("# a\n\n[b](c) and [d](e)", &ParseOptions::default())?;
+/// let mut urls = vec![];
+///
+/// visit(&tree, &mut |node| {
+///     if let Node::Link(link) = node {
+///         urls.push(link.url.clone());
+///     }
+///     Visit::Continue
+/// });
+///
+/// assert_eq!(urls, vec!["c", "e"]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn visit(node: &Node, visitor: &mut dyn FnMut(&Node) -> Visit) {
+    if visitor(node) == Visit::Continue {
+        if let Some(children) = node.children() {
+            for child in children {
+                visit(child, visitor);
+            }
+        }
+    }
+}
+
+/// Visit `node` and its descendants, in preorder, allowing changes.
+///
+/// Like [`visit()`][], but `visitor` gets mutable nodes.
+/// Children are visited after `visitor` is called with their parent, so
+/// changes to the children of a node are also visited.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::{visit_mut, Node, Visit}, to_mdast, ParseOptions};
+/// # fn main() -> Result<(), String> {
+///
+/// let mut tree = to_mdast("[a](b)", &ParseOptions::default())?;
+///
+/// visit_mut(&mut tree, &mut |node| {
+///     if let Node::Link(link) = node {
+///         link.url = format!("https://example.com/{}", link.url);
+///     }
+///     Visit::Continue
+/// });
+///
+/// assert_eq!(
+///     tree.children().unwrap()[0].children().unwrap()[0],
+///     Node::Link(markdown::mdast::Link {
+///         url: "https://example.com/b".into(),
+///         title: None,
+///         children: vec![Node::Text(markdown::mdast::Text {
+///             value: "a".into(),
+///             position: Some(markdown::unist::Position::new(1, 2, 1, 1, 3, 2))
+///         })],
+///         position: Some(markdown::unist::Position::new(1, 1, 0, 1, 7, 6))
+///     })
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn visit_mut(node: &mut Node, visitor: &mut dyn FnMut(&mut Node) -> Visit) {
+    if visitor(node) == Visit::Continue {
+        if let Some(children) = node.children_mut() {
+            for child in children {
+                visit_mut(child, visitor);
+            }
+        }
+    }
+}
+
 /// MDX: attribute content.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
-    feature = "json",
+    feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxJsxExpressionAttribute")
+    serde(tag = "type")
 )]
 pub enum AttributeContent {
     /// JSX expression.
@@ -455,6 +576,7 @@ pub enum AttributeContent {
     /// > | <a {...b} />
     ///        ^^^^^^
     /// ```
+    #[cfg_attr(feature = "serde", serde(rename = "mdxJsxExpressionAttribute"))]
     Expression { value: String, stops: Vec<Stop> },
     /// JSX property.
     ///
@@ -462,12 +584,13 @@ pub enum AttributeContent {
     /// > | <a b />
     ///        ^
     /// ```
+    #[cfg_attr(feature = "serde", serde(rename = "mdxJsxAttribute"))]
     Property(MdxJsxAttribute),
 }
 //
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
-    feature = "json",
+    feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
     serde(tag = "type", rename = "mdxJsxAttributeValueExpression")
 )]
@@ -479,9 +602,9 @@ pub struct AttributeValueExpression {
 /// MDX: attribute value.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(
-    feature = "json",
+    feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "type")
+    serde(untagged)
 )]
 pub enum AttributeValue {
     /// Expression value.
@@ -497,7 +620,6 @@ pub enum AttributeValue {
     /// > | <a b="c" />
     ///          ^^^
     /// ```
-    #[cfg_attr(feature = "json", serde(rename = "literal"))]
     Literal(String),
 }
 
@@ -508,11 +630,7 @@ pub enum AttributeValue {
 ///     ^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "root")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Root {
     // Parent.
     /// Content model.
@@ -528,11 +646,7 @@ pub struct Root {
 ///     ^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "paragraph")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Paragraph {
     // Parent.
     /// Content model.
@@ -548,11 +662,7 @@ pub struct Paragraph {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "heading")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Heading {
     // Parent.
     /// Content model.
@@ -571,11 +681,7 @@ pub struct Heading {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "thematicBreak")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThematicBreak {
     // Void.
     /// Positional info.
@@ -589,11 +695,7 @@ pub struct ThematicBreak {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "blockquote")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlockQuote {
     // Parent.
     /// Content model.
@@ -609,11 +711,7 @@ pub struct BlockQuote {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "list")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct List {
     // Parent.
     /// Content model.
@@ -638,11 +736,7 @@ pub struct List {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "listItem")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListItem {
     // Parent.
     /// Content model.
@@ -658,6 +752,62 @@ pub struct ListItem {
     pub checked: Option<bool>,
 }
 
+/// Definition list.
+///
+/// ```markdown
+/// > | a
+///     ^
+/// > | : b
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionList {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Definition list term.
+///
+/// ```markdown
+/// > | a
+///     ^
+///   | : b
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionListTerm {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+}
+
+/// Definition list description.
+///
+/// ```markdown
+///   | a
+/// > | : b
+///     ^^^
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionListDescription {
+    // Parent.
+    /// Content model.
+    pub children: Vec<Node>,
+    /// Positional info.
+    pub position: Option<Position>,
+    // Extra.
+    /// The description is separated with a blank line from the term or
+    /// description before it (when `true`), or not (when `false`).
+    pub spread: bool,
+}
+
 /// Html (flow or phrasing).
 ///
 /// ```markdown
@@ -665,11 +815,7 @@ pub struct ListItem {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "html")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Html {
     // Text.
     /// Content model.
@@ -689,11 +835,7 @@ pub struct Html {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "code")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Code {
     // Text.
     /// Content model.
@@ -718,11 +860,7 @@ pub struct Code {
 ///     ^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "math")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Math {
     // Text.
     /// Content model.
@@ -741,11 +879,7 @@ pub struct Math {
 ///     ^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "definition")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Definition {
     // Void.
     /// Positional info.
@@ -778,11 +912,7 @@ pub struct Definition {
 ///     ^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "text")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Text {
     // Text.
     /// Content model.
@@ -798,11 +928,7 @@ pub struct Text {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "emphasis")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Emphasis {
     // Parent.
     /// Content model.
@@ -818,11 +944,7 @@ pub struct Emphasis {
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "strong")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Strong {
     // Parent.
     /// Content model.
@@ -838,11 +960,7 @@ pub struct Strong {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "inlineCode")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InlineCode {
     // Text.
     /// Content model.
@@ -858,11 +976,7 @@ pub struct InlineCode {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "inlineMath")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InlineMath {
     // Text.
     /// Content model.
@@ -879,11 +993,7 @@ pub struct InlineMath {
 ///   | b
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "break")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Break {
     // Void.
     /// Positional info.
@@ -897,11 +1007,7 @@ pub struct Break {
 ///     ^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "link")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Link {
     // Parent.
     /// Content model.
@@ -923,11 +1029,7 @@ pub struct Link {
 ///     ^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "image")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Image {
     // Void.
     /// Positional info.
@@ -951,11 +1053,7 @@ pub struct Image {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "linkReference")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinkReference {
     // Parent.
     /// Content model.
@@ -964,7 +1062,7 @@ pub struct LinkReference {
     pub position: Option<Position>,
     // Reference.
     /// Explicitness of a reference.
-    #[cfg_attr(feature = "json", serde(rename = "referenceType"))]
+    #[cfg_attr(feature = "serde", serde(rename = "referenceType"))]
     pub reference_kind: ReferenceKind,
     // Association.
     /// Value that can match another node.
@@ -988,11 +1086,7 @@ pub struct LinkReference {
 ///     ^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "imageReference")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImageReference {
     // Void.
     /// Positional info.
@@ -1003,7 +1097,7 @@ pub struct ImageReference {
     pub alt: String,
     // Reference.
     /// Explicitness of a reference.
-    #[cfg_attr(feature = "json", serde(rename = "referenceType"))]
+    #[cfg_attr(feature = "serde", serde(rename = "referenceType"))]
     pub reference_kind: ReferenceKind,
     // Association.
     /// Value that can match another node.
@@ -1027,11 +1121,7 @@ pub struct ImageReference {
 ///     ^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "footnoteDefinition")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FootnoteDefinition {
     // Parent.
     /// Content model.
@@ -1060,11 +1150,7 @@ pub struct FootnoteDefinition {
 ///     ^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "footnoteReference")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FootnoteReference {
     // Void.
     /// Positional info.
@@ -1093,11 +1179,7 @@ pub struct FootnoteReference {
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "table")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table {
     // Parent.
     /// Content model.
@@ -1116,11 +1198,7 @@ pub struct Table {
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "tableRow")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableRow {
     // Parent.
     /// Content model.
@@ -1136,11 +1214,7 @@ pub struct TableRow {
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "tableCell")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableCell {
     // Parent.
     /// Content model.
@@ -1156,11 +1230,7 @@ pub struct TableCell {
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "delete")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Delete {
     // Parent.
     /// Content model.
@@ -1180,11 +1250,7 @@ pub struct Delete {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "yaml")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Yaml {
     // Void.
     /// Content model.
@@ -1204,11 +1270,7 @@ pub struct Yaml {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "toml")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Toml {
     // Void.
     /// Content model.
@@ -1224,11 +1286,7 @@ pub struct Toml {
 ///     ^^^^^^^^^^^^^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxjsEsm")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MdxjsEsm {
     // Literal.
     /// Content model.
@@ -1247,11 +1305,7 @@ pub struct MdxjsEsm {
 ///     ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxFlowExpression")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MdxFlowExpression {
     // Literal.
     /// Content model.
@@ -1270,11 +1324,7 @@ pub struct MdxFlowExpression {
 ///       ^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxTextExpression")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MdxTextExpression {
     // Literal.
     /// Content model.
@@ -1293,11 +1343,7 @@ pub struct MdxTextExpression {
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxJsxFlowElement")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MdxJsxFlowElement {
     // Parent.
     /// Content model.
@@ -1320,11 +1366,7 @@ pub struct MdxJsxFlowElement {
 ///     ^^^^^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxJsxTextElement")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MdxJsxTextElement {
     // Parent.
     /// Content model.
@@ -1347,11 +1389,7 @@ pub struct MdxJsxTextElement {
 ///        ^
 /// ```
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(
-    feature = "json",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(tag = "type", rename = "mdxJsxAttribute")
-)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MdxJsxAttribute {
     // Void.
     /// Positional info.
@@ -2131,6 +2169,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn definition_list() {
+        let mut node = Node::DefinitionList(DefinitionList {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionList { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionList { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn definition_list_term() {
+        let mut node = Node::DefinitionListTerm(DefinitionListTerm {
+            position: None,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionListTerm { children: [], position: None }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionListTerm { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "should support `position_set`"
+        );
+    }
+
+    #[test]
+    fn definition_list_description() {
+        let mut node = Node::DefinitionListDescription(DefinitionListDescription {
+            position: None,
+            spread: false,
+            children: vec![],
+        });
+
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionListDescription { children: [], position: None, spread: false }",
+            "should support `Debug`"
+        );
+        assert_eq!(node.to_string(), "", "should support `ToString`");
+        assert_eq!(
+            node.children_mut(),
+            Some(&mut vec![]),
+            "should support `children_mut`"
+        );
+        assert_eq!(node.children(), Some(&vec![]), "should support `children`");
+        assert_eq!(node.position(), None, "should support `position`");
+        assert_eq!(node.position_mut(), None, "should support `position`");
+        node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
+        assert_eq!(
+            format!("{:?}", node),
+            "DefinitionListDescription { children: [], position: Some(1:1-1:2 (0-1)), spread: false }",
+            "should support `position_set`"
+        );
+    }
+
     #[test]
     fn list() {
         let mut node = Node::List(List {
@@ -2318,4 +2444,113 @@ mod tests {
             "should support `position_set`"
         );
     }
+    // Visitors.
+
+    #[test]
+    fn visit_preorder() {
+        let tree = Node::Root(Root {
+            children: vec![
+                Node::Heading(Heading {
+                    depth: 1,
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: None,
+                    })],
+                    position: None,
+                }),
+                Node::Paragraph(Paragraph {
+                    children: vec![Node::Emphasis(Emphasis {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: None,
+                        })],
+                        position: None,
+                    })],
+                    position: None,
+                }),
+            ],
+            position: None,
+        });
+        let mut names = vec![];
+
+        visit(&tree, &mut |node| {
+            names.push(match node {
+                Node::Root(_) => "root".to_string(),
+                Node::Heading(_) => "heading".to_string(),
+                Node::Paragraph(_) => "paragraph".to_string(),
+                Node::Emphasis(_) => "emphasis".to_string(),
+                Node::Text(x) => x.value.clone(),
+                _ => "other".to_string(),
+            });
+            Visit::Continue
+        });
+
+        assert_eq!(
+            names,
+            vec!["root", "heading", "a", "paragraph", "emphasis", "b"],
+            "should visit in preorder"
+        );
+
+        names.clear();
+
+        visit(&tree, &mut |node| {
+            if let Node::Text(x) = node {
+                names.push(x.value.clone());
+            }
+
+            if let Node::Heading(_) = node {
+                Visit::Skip
+            } else {
+                Visit::Continue
+            }
+        });
+
+        assert_eq!(names, vec!["b"], "should support skipping children");
+    }
+
+    #[test]
+    fn visit_mut_links() {
+        let mut tree = Node::Paragraph(Paragraph {
+            children: vec![
+                Node::Link(Link {
+                    url: "a".into(),
+                    title: None,
+                    children: vec![],
+                    position: None,
+                }),
+                Node::Emphasis(Emphasis {
+                    children: vec![Node::Link(Link {
+                        url: "b".into(),
+                        title: None,
+                        children: vec![],
+                        position: None,
+                    })],
+                    position: None,
+                }),
+            ],
+            position: None,
+        });
+
+        visit_mut(&mut tree, &mut |node| {
+            if let Node::Link(x) = node {
+                x.url = format!("/base/{}", x.url);
+            }
+            Visit::Continue
+        });
+
+        let mut urls = vec![];
+
+        visit(&tree, &mut |node| {
+            if let Node::Link(x) = node {
+                urls.push(x.url.clone());
+            }
+            Visit::Continue
+        });
+
+        assert_eq!(
+            urls,
+            vec!["/base/a", "/base/b"],
+            "should support changing nodes with `visit_mut`"
+        );
+    }
 }
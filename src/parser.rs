@@ -1,16 +1,17 @@
 //! Turn bytes of markdown into events.
 
-use crate::event::{Event, Point};
+use crate::event::{Event, Kind, Name, Point};
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::subtokenize;
 use crate::tokenizer::Tokenizer;
-use crate::util::location::Location;
-use crate::ParseOptions;
-use alloc::{string::String, vec, vec::Vec};
+use crate::util::{abbreviation::collect as collect_abbreviations, location::Location};
+use crate::{Constructs, ParseOptions};
+use alloc::{format, string::String, vec, vec::Vec};
+use core::cell::Cell;
 
 /// Info needed, in all content types, when parsing markdown.
 ///
-/// Importantly, this contains a set of known definitions.
+/// Importantly, this contains a set of known definitions and abbreviations.
 /// It also references the input value as bytes (`u8`).
 #[derive(Debug)]
 pub struct ParseState<'a> {
@@ -18,12 +19,37 @@ pub struct ParseState<'a> {
     pub location: Option<Location>,
     /// Configuration.
     pub options: &'a ParseOptions,
+    /// Constructs to use (typically `options.constructs`).
+    pub constructs: &'a Constructs,
     /// List of chars.
     pub bytes: &'a [u8],
     /// Set of defined definition identifiers.
     pub definitions: Vec<String>,
     /// Set of defined GFM footnote definition identifiers.
     pub gfm_footnote_definitions: Vec<String>,
+    /// List of defined abbreviations (labels and values).
+    pub abbreviations: Vec<(String, String)>,
+    /// Number of constructs attempted so far, if collecting stats.
+    pub constructs_attempted: Option<Cell<usize>>,
+}
+
+/// Info on how much work it took to parse a document.
+///
+/// This is returned in [`HtmlOutput`][crate::HtmlOutput] when
+/// [`collect_stats`][crate::CompileOptions::collect_stats] is on.
+/// It is meant to help with profiling: to understand why parsing some
+/// document is slow.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    /// Number of events (enters and exits) in the result.
+    pub events: usize,
+    /// Number of bytes in the input.
+    pub bytes: usize,
+    /// Number of constructs attempted, in all content types.
+    ///
+    /// Each attempt or check counts, including the ones that fail, and the
+    /// ones of partial constructs (such as whitespace).
+    pub constructs_attempted: usize,
 }
 
 /// Turn a string of markdown into events.
@@ -32,11 +58,51 @@ pub struct ParseState<'a> {
 pub fn parse<'a>(
     value: &'a str,
     options: &'a ParseOptions,
+) -> Result<(Vec<Event>, ParseState<'a>), String> {
+    parse_with_constructs(value, &options.constructs, options)
+}
+
+/// Turn a string of markdown into events, with other constructs than those in
+/// `options`.
+pub fn parse_with_constructs<'a>(
+    value: &'a str,
+    constructs: &'a Constructs,
+    options: &'a ParseOptions,
+) -> Result<(Vec<Event>, ParseState<'a>), String> {
+    parse_impl(value, constructs, options, false)
+}
+
+/// Turn a string of markdown into events, with other constructs than those in
+/// `options`, and collect stats on how much work that took.
+pub fn parse_with_stats<'a>(
+    value: &'a str,
+    constructs: &'a Constructs,
+    options: &'a ParseOptions,
+) -> Result<(Vec<Event>, ParseState<'a>, Stats), String> {
+    let (events, parse_state) = parse_impl(value, constructs, options, true)?;
+    let stats = Stats {
+        events: events.len(),
+        bytes: parse_state.bytes.len(),
+        constructs_attempted: parse_state
+            .constructs_attempted
+            .as_ref()
+            .map_or(0, Cell::get),
+    };
+    Ok((events, parse_state, stats))
+}
+
+/// Turn a string of markdown into events, optionally counting attempts.
+fn parse_impl<'a>(
+    value: &'a str,
+    constructs: &'a Constructs,
+    options: &'a ParseOptions,
+    collect_stats: bool,
 ) -> Result<(Vec<Event>, ParseState<'a>), String> {
     let bytes = value.as_bytes();
 
     let mut parse_state = ParseState {
         options,
+        constructs,
         bytes,
         location: if options.mdx_esm_parse.is_some() || options.mdx_expression_parse.is_some() {
             Some(Location::new(bytes))
@@ -45,6 +111,12 @@ pub fn parse<'a>(
         },
         definitions: vec![],
         gfm_footnote_definitions: vec![],
+        abbreviations: vec![],
+        constructs_attempted: if collect_stats {
+            Some(Cell::new(0))
+        } else {
+            None
+        },
     };
 
     let start = Point {
@@ -53,16 +125,36 @@ pub fn parse<'a>(
         index: 0,
         vs: 0,
     };
-    let mut tokenizer = Tokenizer::new(start, &parse_state);
+    let mut tokenizer = Tokenizer::new(start.clone(), &parse_state);
+
+    // Text is normally fed line by line, with where each line starts defined.
+    // Define the first line here, so that going back to an earlier line works.
+    if options.inline {
+        tokenizer.define_skip(start);
+    }
 
     let state = tokenizer.push(
         (0, 0),
         (parse_state.bytes.len(), 0),
-        State::Next(StateName::DocumentStart),
+        State::Next(if options.inline {
+            StateName::TextStart
+        } else {
+            StateName::DocumentStart
+        }),
     );
     let mut result = tokenizer.flush(state, true)?;
     let mut events = tokenizer.events;
 
+    if options.strict {
+        check_raw_flow_closed(&events)?;
+    }
+
+    // Abbreviations are found in text, which is parsed after all flow, so we
+    // can collect their definitions now.
+    if parse_state.constructs.abbreviation {
+        parse_state.abbreviations = collect_abbreviations(&events, parse_state.bytes);
+    }
+
     loop {
         let fn_defs = &mut parse_state.gfm_footnote_definitions;
         let defs = &mut parse_state.definitions;
@@ -76,3 +168,43 @@ pub fn parse<'a>(
         result = subtokenize(&mut events, &parse_state, &None)?;
     }
 }
+
+/// Check that code (fenced) and math (flow) have a closing fence, for strict
+/// mode.
+///
+/// Without one, they run to the end of their container, which is likely a
+/// mistake.
+fn check_raw_flow_closed(events: &[Event]) -> Result<(), String> {
+    let mut index = 0;
+    let mut open = None;
+    let mut fences = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        match event.name {
+            Name::CodeFenced | Name::MathFlow => {
+                if event.kind == Kind::Enter {
+                    open = Some(index);
+                    fences = 0;
+                } else if fences < 2 {
+                    let point = &events[open.expect("expected enter")].point;
+                    return Err(format!(
+                        "{}:{}: Unexpected end of {} without closing fence, expected a closing fence (strict:unclosed-fence)",
+                        point.line,
+                        point.column,
+                        if event.name == Name::CodeFenced { "code" } else { "math" },
+                    ));
+                }
+            }
+            Name::CodeFencedFence | Name::MathFlowFence if event.kind == Kind::Exit => {
+                fences += 1;
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    Ok(())
+}
@@ -37,6 +37,12 @@ pub enum Name {
     /// Heading (setext) is parsed as an underline that is preceded by content,
     /// both will form the whole construct.
     HeadingSetext,
+    /// Resolve definition list.
+    ///
+    /// Definition descriptions are parsed on their own.
+    /// The paragraphs before them are turned into terms, and terms and
+    /// descriptions that occur next to each other are wrapped into lists.
+    DefinitionList,
     /// Resolve list item.
     ///
     /// List items are parsed on their own.
@@ -65,11 +71,12 @@ pub enum Name {
 /// Call the corresponding resolver.
 pub fn call(tokenizer: &mut Tokenizer, name: Name) -> Result<Option<Subresult>, String> {
     let result = match name {
-        Name::Label => construct::label_end::resolve(tokenizer),
-        Name::Attention => construct::attention::resolve(tokenizer),
+        Name::Label => construct::label_end::resolve(tokenizer)?,
+        Name::Attention => construct::attention::resolve(tokenizer)?,
         Name::GfmTable => construct::gfm_table::resolve(tokenizer),
         Name::HeadingAtx => construct::heading_atx::resolve(tokenizer),
         Name::HeadingSetext => construct::heading_setext::resolve(tokenizer),
+        Name::DefinitionList => construct::definition_list::resolve(tokenizer),
         Name::ListItem => construct::list_item::resolve(tokenizer),
         Name::Content => construct::content::resolve(tokenizer)?,
         Name::Data => construct::partial_data::resolve(tokenizer),
@@ -43,6 +43,16 @@ impl State {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[allow(clippy::enum_variant_names)]
 pub enum Name {
+    AbbreviationStart,
+    AbbreviationBefore,
+    AbbreviationOpen,
+    AbbreviationLabelBefore,
+    AbbreviationLabel,
+    AbbreviationLabelAfter,
+    AbbreviationMarkerAfter,
+    AbbreviationValueBefore,
+    AbbreviationValue,
+
     AttentionStart,
     AttentionInside,
 
@@ -107,6 +117,19 @@ pub enum Name {
     DefinitionTitleAfter,
     DefinitionTitleAfterOptionalWhitespace,
 
+    DefinitionListStart,
+    DefinitionListBefore,
+    DefinitionListMarkerAfter,
+    DefinitionListTextBefore,
+    DefinitionListText,
+    DefinitionListFurtherStart,
+    DefinitionListFurtherBegin,
+    DefinitionListFurtherAfter,
+    DefinitionListFurtherMarker,
+    DefinitionListFurtherMarkerAfter,
+    DefinitionListContinuation,
+    DefinitionListAfter,
+
     DestinationStart,
     DestinationEnclosedBefore,
     DestinationEnclosed,
@@ -127,16 +150,12 @@ pub enum Name {
     DocumentFlowInside,
     DocumentFlowEnd,
 
+    EmojiStart,
+    EmojiOpen,
+    EmojiName,
+
     FlowStart,
-    FlowBeforeGfmTable,
-    FlowBeforeCodeIndented,
-    FlowBeforeRaw,
-    FlowBeforeHtml,
-    FlowBeforeMdxExpression,
-    FlowBeforeMdxJsx,
-    FlowBeforeHeadingAtx,
-    FlowBeforeHeadingSetext,
-    FlowBeforeThematicBreak,
+    FlowBeforeConstruct,
     FlowAfter,
     FlowBlankLineBefore,
     FlowBlankLineAfter,
@@ -407,6 +426,23 @@ pub enum Name {
     ParagraphLineStart,
     ParagraphInside,
 
+    PercentCommentFlowStart,
+    PercentCommentFlowBeforeSequenceOpen,
+    PercentCommentFlowSequenceOpen,
+    PercentCommentFlowBetween,
+    PercentCommentFlowValue,
+    PercentCommentFlowSequenceClose,
+    PercentCommentFlowAfterSequenceClose,
+    PercentCommentFlowAfter,
+    PercentCommentFlowNok,
+
+    PercentCommentTextStart,
+    PercentCommentTextSequenceOpen,
+    PercentCommentTextBetween,
+    PercentCommentTextLineEndingAfter,
+    PercentCommentTextValue,
+    PercentCommentTextSequenceClose,
+
     RawFlowStart,
     RawFlowBeforeSequenceOpen,
     RawFlowSequenceOpen,
@@ -428,6 +464,8 @@ pub enum Name {
     RawTextStart,
     RawTextSequenceOpen,
     RawTextBetween,
+    RawTextLineEndingAfter,
+    RawTextNok,
     RawTextData,
     RawTextSequenceClose,
 
@@ -471,6 +509,16 @@ pub enum Name {
 /// Call the corresponding state for a state name.
 pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
     let func = match name {
+        Name::AbbreviationStart => construct::abbreviation::start,
+        Name::AbbreviationBefore => construct::abbreviation::before,
+        Name::AbbreviationOpen => construct::abbreviation::open,
+        Name::AbbreviationLabelBefore => construct::abbreviation::label_before,
+        Name::AbbreviationLabel => construct::abbreviation::label,
+        Name::AbbreviationLabelAfter => construct::abbreviation::label_after,
+        Name::AbbreviationMarkerAfter => construct::abbreviation::marker_after,
+        Name::AbbreviationValueBefore => construct::abbreviation::value_before,
+        Name::AbbreviationValue => construct::abbreviation::value,
+
         Name::AttentionStart => construct::attention::start,
         Name::AttentionInside => construct::attention::inside,
 
@@ -537,6 +585,19 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
             construct::definition::title_after_optional_whitespace
         }
 
+        Name::DefinitionListStart => construct::definition_list::start,
+        Name::DefinitionListBefore => construct::definition_list::before,
+        Name::DefinitionListMarkerAfter => construct::definition_list::marker_after,
+        Name::DefinitionListTextBefore => construct::definition_list::text_before,
+        Name::DefinitionListText => construct::definition_list::text,
+        Name::DefinitionListFurtherStart => construct::definition_list::further_start,
+        Name::DefinitionListFurtherBegin => construct::definition_list::further_begin,
+        Name::DefinitionListFurtherAfter => construct::definition_list::further_after,
+        Name::DefinitionListFurtherMarker => construct::definition_list::further_marker,
+        Name::DefinitionListFurtherMarkerAfter => construct::definition_list::further_marker_after,
+        Name::DefinitionListContinuation => construct::definition_list::continuation,
+        Name::DefinitionListAfter => construct::definition_list::after,
+
         Name::DestinationStart => construct::partial_destination::start,
         Name::DestinationEnclosedBefore => construct::partial_destination::enclosed_before,
         Name::DestinationEnclosed => construct::partial_destination::enclosed,
@@ -563,16 +624,12 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::DocumentFlowEnd => construct::document::flow_end,
         Name::DocumentFlowInside => construct::document::flow_inside,
 
+        Name::EmojiStart => construct::emoji::start,
+        Name::EmojiOpen => construct::emoji::open,
+        Name::EmojiName => construct::emoji::name,
+
         Name::FlowStart => construct::flow::start,
-        Name::FlowBeforeGfmTable => construct::flow::before_gfm_table,
-        Name::FlowBeforeCodeIndented => construct::flow::before_code_indented,
-        Name::FlowBeforeRaw => construct::flow::before_raw,
-        Name::FlowBeforeHtml => construct::flow::before_html,
-        Name::FlowBeforeMdxExpression => construct::flow::before_mdx_expression,
-        Name::FlowBeforeMdxJsx => construct::flow::before_mdx_jsx,
-        Name::FlowBeforeHeadingAtx => construct::flow::before_heading_atx,
-        Name::FlowBeforeHeadingSetext => construct::flow::before_heading_setext,
-        Name::FlowBeforeThematicBreak => construct::flow::before_thematic_break,
+        Name::FlowBeforeConstruct => construct::flow::before_construct,
         Name::FlowAfter => construct::flow::after,
         Name::FlowBlankLineBefore => construct::flow::blank_line_before,
         Name::FlowBlankLineAfter => construct::flow::blank_line_after,
@@ -905,6 +962,29 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::ParagraphLineStart => construct::paragraph::line_start,
         Name::ParagraphInside => construct::paragraph::inside,
 
+        Name::PercentCommentFlowStart => construct::percent_comment_flow::start,
+        Name::PercentCommentFlowBeforeSequenceOpen => {
+            construct::percent_comment_flow::before_sequence_open
+        }
+        Name::PercentCommentFlowSequenceOpen => construct::percent_comment_flow::sequence_open,
+        Name::PercentCommentFlowBetween => construct::percent_comment_flow::between,
+        Name::PercentCommentFlowValue => construct::percent_comment_flow::value,
+        Name::PercentCommentFlowSequenceClose => construct::percent_comment_flow::sequence_close,
+        Name::PercentCommentFlowAfterSequenceClose => {
+            construct::percent_comment_flow::sequence_close_after
+        }
+        Name::PercentCommentFlowAfter => construct::percent_comment_flow::after,
+        Name::PercentCommentFlowNok => construct::percent_comment_flow::nok,
+
+        Name::PercentCommentTextStart => construct::percent_comment_text::start,
+        Name::PercentCommentTextSequenceOpen => construct::percent_comment_text::sequence_open,
+        Name::PercentCommentTextBetween => construct::percent_comment_text::between,
+        Name::PercentCommentTextLineEndingAfter => {
+            construct::percent_comment_text::line_ending_after
+        }
+        Name::PercentCommentTextValue => construct::percent_comment_text::value,
+        Name::PercentCommentTextSequenceClose => construct::percent_comment_text::sequence_close,
+
         Name::RawFlowStart => construct::raw_flow::start,
         Name::RawFlowBeforeSequenceOpen => construct::raw_flow::before_sequence_open,
         Name::RawFlowSequenceOpen => construct::raw_flow::sequence_open,
@@ -926,6 +1006,8 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::RawTextStart => construct::raw_text::start,
         Name::RawTextSequenceOpen => construct::raw_text::sequence_open,
         Name::RawTextBetween => construct::raw_text::between,
+        Name::RawTextLineEndingAfter => construct::raw_text::line_ending_after,
+        Name::RawTextNok => construct::raw_text::nok,
         Name::RawTextData => construct::raw_text::data,
         Name::RawTextSequenceClose => construct::raw_text::sequence_close,
 
@@ -117,7 +117,7 @@ pub fn subtokenize(
                 // Check if this is the first paragraph, after zero or more
                 // definitions (or a blank line), in a list item.
                 // Used for GFM task list items.
-                if tokenizer.parse_state.options.constructs.gfm_task_list_item
+                if tokenizer.parse_state.constructs.gfm_task_list_item
                     && index > 2
                     && events[index - 1].kind == Kind::Enter
                     && events[index - 1].name == Name::Paragraph
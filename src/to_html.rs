@@ -2,17 +2,22 @@
 use crate::event::{Event, Kind, Name};
 use crate::mdast::AlignKind;
 use crate::util::{
+    abbreviation::collect as collect_abbreviations,
     character_reference::decode as decode_character_reference,
     constant::{SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC},
+    emoji::decode as decode_emoji,
     encode::encode,
     gfm_tagfilter::gfm_tagfilter,
-    infer::{gfm_table_align, list_loose},
+    infer::{definition_list_description_loose, gfm_table_align, list_loose},
     normalize_identifier::normalize_identifier,
-    sanitize_uri::{sanitize, sanitize_with_protocols},
+    resolve_url::resolve_url,
+    sanitize_uri::{check_protocols, sanitize, sanitize_with_protocols},
     skip,
     slice::{Position, Slice},
+    slug::slug,
+    sort_attributes::sort_attributes,
 };
-use crate::{CompileOptions, LineEnding};
+use crate::{CompileOptions, DisallowedHtml, ImageEmptyAlt, LineEnding, Stats};
 use alloc::{
     format,
     string::{String, ToString},
@@ -54,6 +59,37 @@ struct Media {
     ///
     /// Interpreted string content.
     title: Option<String>,
+    /// Whether an image with an empty alt was dropped from the label, with
+    /// [`ImageEmptyAlt::Drop`][].
+    dropped_image: bool,
+}
+
+/// Result of compiling markdown to HTML.
+///
+/// Contains the HTML, and some extra info found while compiling.
+/// More info may be added in the future, so this can’t be constructed or
+/// matched exhaustively outside this crate.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct HtmlOutput {
+    /// HTML.
+    pub value: String,
+    /// Raw value of frontmatter, if any.
+    ///
+    /// This is the value between the fences, not including the line ending
+    /// right after the opening fence or right before the closing fence.
+    /// Line endings inside it are kept as-is.
+    /// It is not parsed (as YAML or TOML).
+    pub frontmatter: Option<String>,
+    /// HTML of a table of contents, if any.
+    ///
+    /// This is a nested list of links to headings, which is only generated
+    /// if [`toc`][CompileOptions::toc] is on, and if there are headings to
+    /// include.
+    pub toc: Option<String>,
+    /// Info on how much work it took to parse the document, if
+    /// [`collect_stats`][crate::CompileOptions::collect_stats] is on.
+    pub stats: Option<Stats>,
 }
 
 /// Representation of a definition.
@@ -80,6 +116,8 @@ struct CompileContext<'a> {
     events: &'a [Event],
     /// List of bytes.
     bytes: &'a [u8],
+    /// Extra emoji shortcodes.
+    emoji_shortcodes: &'a [(String, String)],
     /// Configuration.
     options: &'a CompileOptions,
     // Fields used by handlers to track the things they need to track to
@@ -92,10 +130,21 @@ struct CompileContext<'a> {
     raw_flow_seen_data: Option<bool>,
     /// Number of raw (flow) fences.
     raw_flow_fences_count: Option<usize>,
+    /// Tag names of the elements opened for raw (flow).
+    raw_flow_tag_names: Vec<String>,
+    /// Whether the last element of raw (flow) is open, and takes attributes.
+    raw_flow_attributes: bool,
     /// Whether we are in code (text).
     raw_text_inside: bool,
+    /// Whether the current definition list description is loose.
+    definition_list_description_loose: bool,
+    /// Whether we are in HTML that is removed.
+    html_remove_inside: bool,
     /// Whether we are in image text.
     image_alt_inside: bool,
+    /// Whether we are in text that can contain soft line endings (paragraph,
+    /// heading (setext) text).
+    text_inside: bool,
     /// Marker of character reference.
     character_reference_marker: Option<u8>,
     /// Whether we are expecting the first list item marker.
@@ -106,6 +155,8 @@ struct CompileContext<'a> {
     tight_stack: Vec<bool>,
     /// List of definitions.
     definitions: Vec<Definition>,
+    /// List of abbreviations (labels and titles).
+    abbreviations: Vec<(String, String)>,
     /// List of definitions.
     gfm_footnote_definitions: Vec<(String, String)>,
     gfm_footnote_definition_calls: Vec<(String, usize)>,
@@ -116,6 +167,10 @@ struct CompileContext<'a> {
     gfm_table_align: Option<Vec<AlignKind>>,
     /// Current GFM table column.
     gfm_table_column: usize,
+    /// Raw value of frontmatter.
+    frontmatter: Option<String>,
+    /// Headings in the table of contents (rank, id, and text as HTML).
+    toc: Vec<(usize, String, String)>,
     // Fields used to influance the current compilation.
     /// Ignore the next line ending.
     slurp_one_line_ending: bool,
@@ -136,33 +191,45 @@ impl<'a> CompileContext<'a> {
     fn new(
         events: &'a [Event],
         bytes: &'a [u8],
+        emoji_shortcodes: &'a [(String, String)],
         options: &'a CompileOptions,
         line_ending: LineEnding,
     ) -> CompileContext<'a> {
         CompileContext {
             events,
             bytes,
+            emoji_shortcodes,
             heading_atx_rank: None,
             heading_setext_buffer: None,
             raw_flow_seen_data: None,
             raw_flow_fences_count: None,
+            raw_flow_tag_names: vec![],
+            raw_flow_attributes: false,
             raw_text_inside: false,
+            definition_list_description_loose: false,
             character_reference_marker: None,
             list_expect_first_marker: None,
             media_stack: vec![],
             definitions: vec![],
+            abbreviations: collect_abbreviations(events, bytes),
             gfm_footnote_definitions: vec![],
             gfm_footnote_definition_calls: vec![],
             gfm_footnote_definition_stack: vec![],
             gfm_table_in_head: false,
             gfm_table_align: None,
             gfm_table_column: 0,
+            frontmatter: None,
+            toc: vec![],
             tight_stack: vec![],
             slurp_one_line_ending: false,
+            html_remove_inside: false,
             image_alt_inside: false,
+            text_inside: false,
             encode_html: true,
             line_ending_default: line_ending,
-            buffers: vec![String::new()],
+            // HTML is usually a bit longer than the markdown it comes from,
+            // so reserve some space to prevent reallocations.
+            buffers: vec![String::with_capacity(bytes.len() + bytes.len() / 2)],
             index: 0,
             options,
         }
@@ -185,6 +252,20 @@ impl<'a> CompileContext<'a> {
         last_buf.push_str(value);
     }
 
+    /// Check if the last character that was written is a line ending.
+    fn after_line_ending(&self) -> bool {
+        self.buffers.last().map_or(false, |buf| {
+            buf.ends_with(|char| matches!(char, '\n' | '\r'))
+        })
+    }
+
+    /// Check if the last character that was written is a space or tab.
+    fn after_whitespace(&self) -> bool {
+        self.buffers.last().map_or(false, |buf| {
+            buf.ends_with(|char| matches!(char, '\t' | ' '))
+        })
+    }
+
     /// Add a line ending.
     fn line_ending(&mut self) {
         let eol = self.line_ending_default.as_str().to_string();
@@ -201,10 +282,165 @@ impl<'a> CompileContext<'a> {
             self.line_ending();
         }
     }
+
+    /// Add a line ending between blocks if needed, unless `tight_output` is
+    /// on (and `canonical` is off).
+    fn block_line_ending_if_needed(&mut self) {
+        if self.options.canonical || !self.options.tight_output {
+            self.line_ending_if_needed();
+        }
+    }
+
+    /// Add an attribute (` name="value"`).
+    ///
+    /// The value is always encoded, also when `encode_html` is off, so that
+    /// it can’t break out of the attribute.
+    /// Pass values that are not encoded yet.
+    /// Invalid names (such as `a onclick`), which could inject markup, are
+    /// skipped.
+    fn attribute(&mut self, name: &str, value: &str) {
+        if !is_attribute_name(name) {
+            return;
+        }
+
+        self.push(" ");
+        self.push(name);
+        self.push("=\"");
+        self.push(&encode(value, true));
+        self.push("\"");
+    }
+
+    /// Add an attribute (` name="value"`), with a value that is already
+    /// encoded.
+    ///
+    /// Like [`attribute`][Self::attribute], but for values that are encoded
+    /// when they are compiled or sanitized (such as URLs and titles).
+    fn attribute_encoded(&mut self, name: &str, value: &str) {
+        if !is_attribute_name(name) {
+            return;
+        }
+
+        self.push(" ");
+        self.push(name);
+        self.push("=\"");
+        self.push(value);
+        self.push("\"");
+    }
+
+    /// Check if an element (such as `em`) can be generated.
+    fn allowed(&self, name: &str) -> bool {
+        match self.options.allowed_elements {
+            Some(ref names) => names.iter().any(|d| d == name),
+            None => true,
+        }
+    }
+
+    /// Get the rank of a heading of `depth`, shifted by `heading_offset`,
+    /// and clamped to `1..=6`.
+    fn heading_rank(&self, depth: usize) -> usize {
+        let offset = self.options.heading_offset;
+        let rank = if offset < 0 {
+            depth.saturating_sub(usize::from(offset.unsigned_abs()))
+        } else {
+            depth + usize::from(offset.unsigned_abs())
+        };
+        rank.clamp(1, 6)
+    }
+
+    /// Get the tag name of a heading of `rank`, which is `p` if that heading
+    /// is not allowed, or `None` if paragraphs are not allowed either.
+    fn heading_tag_name(&self, rank: usize) -> Option<String> {
+        let mut name = String::from("h");
+        name.push_str(&rank.to_string());
+
+        if self.allowed(&name) {
+            Some(name)
+        } else if self.allowed("p") {
+            Some("p".into())
+        } else {
+            None
+        }
+    }
+
+    /// Add the opening tag of a heading of `rank`, with `content` (HTML).
+    ///
+    /// Nothing is added if neither that heading nor a paragraph is allowed.
+    /// If a table of contents is generated, this also adds an `id`, and
+    /// stores the heading.
+    fn heading_start(&mut self, rank: usize, content: &str) {
+        let name = if let Some(name) = self.heading_tag_name(rank) {
+            name
+        } else {
+            return;
+        };
+
+        self.push("<");
+        self.push(&name);
+
+        if self.options.toc
+            && rank >= usize::from(self.options.toc_min_depth)
+            && rank <= usize::from(self.options.toc_max_depth)
+        {
+            let text = strip_tags(content);
+            let base = slug(&decode_html(&text));
+
+            // Empty slugs can’t be linked to.
+            if !base.is_empty() {
+                let mut id = base.clone();
+                let mut count = 0;
+
+                while self.toc.iter().any(|d| d.1 == id) {
+                    count += 1;
+                    id.clone_from(&base);
+                    id.push('-');
+                    id.push_str(&count.to_string());
+                }
+
+                self.attribute("id", &id);
+                self.toc.push((rank, id, text));
+            }
+        }
+
+        self.push(">");
+    }
+}
+
+/// Check that the tag names in `options` are valid.
+///
+/// They are used as they are, so invalid ones (such as `i onclick=x`) could
+/// inject markup.
+pub fn check_options(options: &CompileOptions) -> Result<(), String> {
+    let tag_names = [
+        ("emphasis_tag_name", &options.emphasis_tag_name),
+        (
+            "gfm_footnote_label_tag_name",
+            &options.gfm_footnote_label_tag_name,
+        ),
+        ("math_flow_tag_name", &options.math_flow_tag_name),
+        ("strong_tag_name", &options.strong_tag_name),
+    ];
+
+    for (option, value) in tag_names {
+        if let Some(name) = value {
+            if !is_tag_name(name) {
+                return Err(format!(
+                    "Cannot use `{}` as `{}`, expected an ASCII letter followed by ASCII alphanumerics or `-`",
+                    name, option
+                ));
+            }
+        }
+    }
+
+    Ok(())
 }
 
-/// Turn events and bytes into a string of HTML.
-pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> String {
+/// Turn events and bytes into a string of HTML, with some extra info.
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    emoji_shortcodes: &[(String, String)],
+    options: &CompileOptions,
+) -> HtmlOutput {
     let mut index = 0;
     let mut line_ending_inferred = None;
 
@@ -228,7 +464,13 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
     let line_ending_default =
         line_ending_inferred.unwrap_or_else(|| options.default_line_ending.clone());
 
-    let mut context = CompileContext::new(events, bytes, options, line_ending_default);
+    let mut context = CompileContext::new(
+        events,
+        bytes,
+        emoji_shortcodes,
+        options,
+        line_ending_default,
+    );
     let mut definition_indices = vec![];
     let mut index = 0;
     let mut definition_inside = false;
@@ -290,12 +532,26 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
         generate_footnote_section(&mut context);
     }
 
+    let mut toc = if context.toc.is_empty() {
+        None
+    } else {
+        Some(generate_toc(&context))
+    };
+
     debug_assert_eq!(context.buffers.len(), 1, "expected 1 final buffer");
-    context
-        .buffers
-        .get(0)
-        .expect("expected 1 final buffer")
-        .into()
+    let mut value = context.buffers.pop().expect("expected 1 final buffer");
+
+    if options.canonical {
+        value = sort_attributes(&value);
+        toc = toc.map(|d| sort_attributes(&d));
+    }
+
+    HtmlOutput {
+        value,
+        frontmatter: context.frontmatter,
+        toc,
+        stats: None,
+    }
 }
 
 /// Handle the event at `index`.
@@ -319,13 +575,14 @@ fn enter(context: &mut CompileContext) {
         | Name::DefinitionTitleString
         | Name::GfmFootnoteDefinitionPrefix
         | Name::HeadingAtxText
-        | Name::HeadingSetextText
         | Name::Label
         | Name::MdxEsm
         | Name::MdxFlowExpression
         | Name::MdxTextExpression
         | Name::MdxJsxFlowTag
         | Name::MdxJsxTextTag
+        | Name::PercentCommentFlow
+        | Name::PercentCommentText
         | Name::ReferenceString
         | Name::ResourceTitleString => on_enter_buffer(context),
 
@@ -335,6 +592,9 @@ fn enter(context: &mut CompileContext) {
         Name::CodeText | Name::MathText => on_enter_raw_text(context),
         Name::Definition => on_enter_definition(context),
         Name::DefinitionDestinationString => on_enter_definition_destination_string(context),
+        Name::DefinitionList => on_enter_definition_list(context),
+        Name::DefinitionListDescription => on_enter_definition_list_description(context),
+        Name::DefinitionListTerm => on_enter_definition_list_term(context),
         Name::Emphasis => on_enter_emphasis(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmFootnoteDefinition => on_enter_gfm_footnote_definition(context),
@@ -346,6 +606,7 @@ fn enter(context: &mut CompileContext) {
         Name::GfmTableHead => on_enter_gfm_table_head(context),
         Name::GfmTableRow => on_enter_gfm_table_row(context),
         Name::GfmTaskListItemCheck => on_enter_gfm_task_list_item_check(context),
+        Name::HeadingSetextText => on_enter_heading_setext_text(context),
         Name::HtmlFlow => on_enter_html_flow(context),
         Name::HtmlText => on_enter_html_text(context),
         Name::Image => on_enter_image(context),
@@ -363,17 +624,21 @@ fn enter(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit].
 fn exit(context: &mut CompileContext) {
     match context.events[context.index].name {
-        Name::CodeFencedFenceMeta
-        | Name::MathFlowFenceMeta
+        Name::CodeFencedFenceMeta => on_exit_code_fenced_fence_meta(context),
+        Name::MathFlowFenceMeta
         | Name::MdxJsxTextTag
         | Name::MdxTextExpression
+        | Name::PercentCommentText
         | Name::Resource => {
             on_exit_drop(context);
         }
-        Name::MdxEsm | Name::MdxFlowExpression | Name::MdxJsxFlowTag => on_exit_drop_slurp(context),
+        Name::MdxEsm | Name::MdxFlowExpression | Name::MdxJsxFlowTag | Name::PercentCommentFlow => {
+            on_exit_drop_slurp(context);
+        }
         Name::CharacterEscapeValue | Name::CodeTextData | Name::Data | Name::MathTextData => {
             on_exit_data(context);
         }
+        Name::Abbreviation => on_exit_abbreviation(context),
         Name::AutolinkEmail => on_exit_autolink_email(context),
         Name::AutolinkProtocol => on_exit_autolink_protocol(context),
         Name::BlankLineEnding => on_exit_blank_line_ending(context),
@@ -394,7 +659,11 @@ fn exit(context: &mut CompileContext) {
         Name::Definition => on_exit_definition(context),
         Name::DefinitionDestinationString => on_exit_definition_destination_string(context),
         Name::DefinitionLabelString => on_exit_definition_label_string(context),
+        Name::DefinitionList => on_exit_definition_list(context),
+        Name::DefinitionListDescription => on_exit_definition_list_description(context),
+        Name::DefinitionListTerm => on_exit_definition_list_term(context),
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::EmojiName => on_exit_emoji_name(context),
         Name::Emphasis => on_exit_emphasis(context),
         Name::Frontmatter => on_exit_frontmatter(context),
         Name::GfmAutolinkLiteralEmail => on_exit_gfm_autolink_literal_email(context),
@@ -451,37 +720,76 @@ fn on_enter_buffer(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`BlockQuote`][Name::BlockQuote].
 fn on_enter_block_quote(context: &mut CompileContext) {
     context.tight_stack.push(false);
-    context.line_ending_if_needed();
-    context.push("<blockquote>");
+
+    if context.allowed("blockquote") {
+        context.block_line_ending_if_needed();
+        context.push("<blockquote");
+
+        if context.options.block_quote_cite {
+            let url = block_quote_cite(context)
+                .map(|url| sanitize_url(context.options, &url, &SAFE_PROTOCOL_HREF));
+
+            if let Some(url) = url {
+                if !url.is_empty() {
+                    context.attribute_encoded("cite", &url);
+                }
+            }
+        }
+
+        context.push(">");
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`CodeIndented`][Name::CodeIndented].
 fn on_enter_code_indented(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
-    context.line_ending_if_needed();
-    context.push("<pre><code>");
+    context.block_line_ending_if_needed();
+    raw_flow_start(context, &["pre", "code"]);
+
+    if context.raw_flow_attributes {
+        context.push(">");
+    }
+
+    if context.options.code_line_numbers {
+        // Capture the code, to wrap its lines when done.
+        context.buffer();
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeFenced`][Name::CodeFenced],[`MathFlow`][Name::MathFlow]}.
 fn on_enter_raw_flow(context: &mut CompileContext) {
     context.raw_flow_seen_data = Some(false);
-    context.line_ending_if_needed();
-    // Note that no `>` is used, which is added later (due to info)
-    context.push("<pre><code");
+    context.block_line_ending_if_needed();
     context.raw_flow_fences_count = Some(0);
 
+    // Note that no `>` is used, which is added later (due to info)
     if context.events[context.index].name == Name::MathFlow {
-        context.push(" class=\"language-math math-display\"");
+        let options = context.options;
+        let class = if let Some(ref name) = options.math_flow_tag_name {
+            raw_flow_start(context, &[name]);
+            "math math-display"
+        } else {
+            raw_flow_start(context, &["pre", "code"]);
+            "language-math math-display"
+        };
+
+        if context.raw_flow_attributes {
+            context.attribute("class", options.math_flow_class.as_deref().unwrap_or(class));
+        }
+    } else {
+        raw_flow_start(context, &["pre", "code"]);
     }
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`CodeText`][Name::CodeText],[`MathText`][Name::MathText]}.
 fn on_enter_raw_text(context: &mut CompileContext) {
     context.raw_text_inside = true;
-    if !context.image_alt_inside {
+    if !context.image_alt_inside && context.allowed("code") {
         context.push("<code");
         if context.events[context.index].name == Name::MathText {
             context.push(" class=\"language-math math-inline\"");
+        } else if let Some(ref value) = context.options.default_inline_code_class {
+            context.attribute("class", value);
         }
         context.push(">");
     }
@@ -498,6 +806,7 @@ fn on_enter_definition(context: &mut CompileContext) {
         reference_id: None,
         destination: None,
         title: None,
+        dropped_image: false,
     });
 }
 
@@ -507,10 +816,52 @@ fn on_enter_definition_destination_string(context: &mut CompileContext) {
     context.encode_html = false;
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionList`][Name::DefinitionList].
+fn on_enter_definition_list(context: &mut CompileContext) {
+    context.block_line_ending_if_needed();
+
+    if context.allowed("dl") {
+        context.push("<dl>");
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionListDescription`][Name::DefinitionListDescription].
+fn on_enter_definition_list_description(context: &mut CompileContext) {
+    context.definition_list_description_loose =
+        definition_list_description_loose(context.events, context.index);
+    context.block_line_ending_if_needed();
+
+    if context.allowed("dd") {
+        context.push("<dd>");
+    }
+
+    if context.definition_list_description_loose && context.allowed("p") {
+        context.push("<p>");
+    }
+
+    context.text_inside = true;
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionListTerm`][Name::DefinitionListTerm].
+fn on_enter_definition_list_term(context: &mut CompileContext) {
+    context.block_line_ending_if_needed();
+
+    if context.allowed("dt") {
+        context.push("<dt>");
+    }
+
+    context.text_inside = true;
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("<em>");
+    let options = context.options;
+    let name = options.emphasis_tag_name.as_deref().unwrap_or("em");
+
+    if !context.image_alt_inside && context.allowed(name) {
+        context.push("<");
+        context.push(name);
+        context.push(">");
     }
 }
 
@@ -533,12 +884,13 @@ fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
         reference_id: None,
         destination: None,
         title: None,
+        dropped_image: false,
     });
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_enter_gfm_strikethrough(context: &mut CompileContext) {
-    if !context.image_alt_inside {
+    if !context.image_alt_inside && context.allowed("del") {
         context.push("<del>");
     }
 }
@@ -547,13 +899,18 @@ fn on_enter_gfm_strikethrough(context: &mut CompileContext) {
 fn on_enter_gfm_table(context: &mut CompileContext) {
     let align = gfm_table_align(context.events, context.index);
     context.gfm_table_align = Some(align);
-    context.line_ending_if_needed();
-    context.push("<table>");
+    context.block_line_ending_if_needed();
+
+    if context.allowed("table") {
+        context.push("<table>");
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableBody`][Name::GfmTableBody].
 fn on_enter_gfm_table_body(context: &mut CompileContext) {
-    context.push("<tbody>");
+    if context.allowed("tbody") {
+        context.push("<tbody>");
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableCell`][Name::GfmTableCell].
@@ -566,57 +923,105 @@ fn on_enter_gfm_table_cell(context: &mut CompileContext) {
         context.buffer();
     } else {
         let value = align[column];
-        context.line_ending_if_needed();
-
-        if context.gfm_table_in_head {
-            context.push("<th");
+        let name = if context.gfm_table_in_head {
+            "th"
         } else {
-            context.push("<td");
-        }
+            "td"
+        };
+        context.block_line_ending_if_needed();
 
-        match value {
-            AlignKind::Left => context.push(" align=\"left\""),
-            AlignKind::Right => context.push(" align=\"right\""),
-            AlignKind::Center => context.push(" align=\"center\""),
-            AlignKind::None => {}
-        }
+        if context.allowed(name) {
+            context.push("<");
+            context.push(name);
 
-        context.push(">");
+            match value {
+                AlignKind::Left => context.push(" align=\"left\""),
+                AlignKind::Right => context.push(" align=\"right\""),
+                AlignKind::Center => context.push(" align=\"center\""),
+                AlignKind::None => {}
+            }
+
+            context.push(">");
+        }
     }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableHead`][Name::GfmTableHead].
 fn on_enter_gfm_table_head(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    context.push("<thead>");
+    context.block_line_ending_if_needed();
+
+    if context.allowed("thead") {
+        context.push("<thead>");
+    }
+
     context.gfm_table_in_head = true;
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTableRow`][Name::GfmTableRow].
 fn on_enter_gfm_table_row(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    context.push("<tr>");
+    context.block_line_ending_if_needed();
+
+    if context.allowed("tr") {
+        context.push("<tr>");
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
 fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("<input type=\"checkbox\" disabled=\"\" ");
+    if !context.image_alt_inside && context.allowed("input") {
+        context.push("<input type=\"checkbox\" disabled=\"\"");
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`HeadingSetextText`][Name::HeadingSetextText].
+fn on_enter_heading_setext_text(context: &mut CompileContext) {
+    context.buffer();
+    context.text_inside = true;
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`HtmlFlow`][Name::HtmlFlow].
 fn on_enter_html_flow(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    if context.options.allow_dangerous_html {
+    if context.options.allow_dangerous_html || context.options.allow_html_flow {
+        context.block_line_ending_if_needed();
         context.encode_html = false;
+    } else {
+        on_enter_html_disallowed(context, true);
     }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`HtmlText`][Name::HtmlText].
 fn on_enter_html_text(context: &mut CompileContext) {
-    if context.options.allow_dangerous_html {
-        context.encode_html = false;
+    // HTML in image text ends up in the `alt` attribute, so it’s always
+    // encoded.
+    if context.options.allow_dangerous_html || context.options.allow_html_text {
+        if !context.image_alt_inside {
+            context.encode_html = false;
+        }
+    } else {
+        on_enter_html_disallowed(context, false);
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]},
+/// when not allowed.
+fn on_enter_html_disallowed(context: &mut CompileContext, flow: bool) {
+    match context.options.on_disallowed_html {
+        DisallowedHtml::Remove => {
+            context.html_remove_inside = true;
+            // Buffer the content, so that it can be dropped.
+            context.buffer();
+        }
+        DisallowedHtml::Escape | DisallowedHtml::Keep => {
+            if flow {
+                context.block_line_ending_if_needed();
+            }
+
+            if context.options.on_disallowed_html == DisallowedHtml::Keep
+                && !context.image_alt_inside
+            {
+                context.encode_html = false;
+            }
+        }
     }
 }
 
@@ -629,6 +1034,7 @@ fn on_enter_image(context: &mut CompileContext) {
         reference_id: None,
         destination: None,
         title: None,
+        dropped_image: false,
     });
     context.image_alt_inside = true; // Disallow tags.
 }
@@ -642,22 +1048,28 @@ fn on_enter_link(context: &mut CompileContext) {
         reference_id: None,
         destination: None,
         title: None,
+        dropped_image: false,
     });
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
 fn on_enter_list(context: &mut CompileContext) {
     let loose = list_loose(context.events, context.index, true);
-    context.tight_stack.push(!loose);
-    context.line_ending_if_needed();
+    context
+        .tight_stack
+        .push(context.options.force_tight_lists || !loose);
+    context.block_line_ending_if_needed();
 
-    // Note: no `>`.
-    context.push(if context.events[context.index].name == Name::ListOrdered {
-        "<ol"
-    } else {
-        "<ul"
-    });
-    context.list_expect_first_marker = Some(true);
+    let allowed = context.allowed(list_tag_name(context));
+
+    if allowed {
+        // Note: no `>`.
+        context.push("<");
+        context.push(list_tag_name(context));
+    }
+
+    // Expect the first marker to finish the opening tag, if there is one.
+    context.list_expect_first_marker = Some(allowed);
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`ListItemMarker`][Name::ListItemMarker].
@@ -666,9 +1078,12 @@ fn on_enter_list_item_marker(context: &mut CompileContext) {
         context.push(">");
     }
 
-    context.line_ending_if_needed();
+    context.block_line_ending_if_needed();
+
+    if context.allowed("li") {
+        context.push("<li>");
+    }
 
-    context.push("<li>");
     context.list_expect_first_marker = Some(false);
 }
 
@@ -677,9 +1092,34 @@ fn on_enter_paragraph(context: &mut CompileContext) {
     let tight = context.tight_stack.last().unwrap_or(&false);
 
     if !tight {
-        context.line_ending_if_needed();
-        context.push("<p>");
+        context.block_line_ending_if_needed();
+
+        if context.allowed("p") {
+            context.push("<p>");
+        }
+    } else if context.options.force_tight_lists {
+        // Lists that are only tight because of `force_tight_lists` can have
+        // several paragraphs in an item: keep them apart.
+        let mut index = context.index;
+
+        while index > 0
+            && matches!(
+                context.events[index - 1].name,
+                Name::BlankLineEnding | Name::LineEnding | Name::SpaceOrTab
+            )
+        {
+            index -= 1;
+        }
+
+        if index > 0
+            && context.events[index - 1].kind == Kind::Exit
+            && context.events[index - 1].name == Name::Paragraph
+        {
+            context.line_ending_if_needed();
+        }
     }
+
+    context.text_inside = true;
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`Resource`][Name::Resource].
@@ -698,8 +1138,47 @@ fn on_enter_resource_destination_string(context: &mut CompileContext) {
 
 /// Handle [`Enter`][Kind::Enter]:[`Strong`][Name::Strong].
 fn on_enter_strong(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("<strong>");
+    let options = context.options;
+    let name = options.strong_tag_name.as_deref().unwrap_or("strong");
+
+    if !context.image_alt_inside && context.allowed(name) {
+        context.push("<");
+        context.push(name);
+        context.push(">");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Abbreviation`][Name::Abbreviation].
+fn on_exit_abbreviation(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    );
+    let value = slice.as_str();
+    let element = !context.image_alt_inside && context.allowed("abbr");
+
+    if element {
+        let title = &context
+            .abbreviations
+            .iter()
+            .find(|d| d.0 == value)
+            .expect("expected abbreviation to be defined")
+            .1;
+
+        if title.is_empty() {
+            context.push("<abbr>");
+        } else {
+            let title = title.clone();
+            context.push("<abbr");
+            context.attribute("title", &title);
+            context.push(">");
+        }
+    }
+
+    context.push(&encode(value, context.encode_html));
+
+    if element {
+        context.push("</abbr>");
     }
 }
 
@@ -733,24 +1212,31 @@ fn on_exit_autolink_protocol(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:{[`HardBreakEscape`][Name::HardBreakEscape],[`HardBreakTrailing`][Name::HardBreakTrailing]}.
 fn on_exit_break(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("<br />");
+    if !context.image_alt_inside && context.allowed("br") {
+        context.push(if context.options.xhtml {
+            "<br />"
+        } else {
+            "<br>"
+        });
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`BlankLineEnding`][Name::BlankLineEnding].
 fn on_exit_blank_line_ending(context: &mut CompileContext) {
     if context.index == context.events.len() - 1 {
-        context.line_ending_if_needed();
+        context.block_line_ending_if_needed();
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`BlockQuote`][Name::BlockQuote].
 fn on_exit_block_quote(context: &mut CompileContext) {
     context.tight_stack.pop();
-    context.line_ending_if_needed();
     context.slurp_one_line_ending = false;
-    context.push("</blockquote>");
+
+    if context.allowed("blockquote") {
+        context.block_line_ending_if_needed();
+        context.push("</blockquote>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`CharacterReferenceMarker`][Name::CharacterReferenceMarker].
@@ -778,9 +1264,13 @@ fn on_exit_character_reference_value(context: &mut CompileContext) {
         context.bytes,
         &Position::from_exit_event(context.events, context.index),
     );
-    let value = decode_character_reference(slice.as_str(), marker, true)
+    let mut value = decode_character_reference(slice.as_str(), marker, true)
         .expect("expected to parse only valid named references");
 
+    if context.options.normalize_whitespace {
+        value = collapse_whitespace(&value, context.after_whitespace());
+    }
+
     context.push(&encode(&value, context.encode_html));
 }
 
@@ -805,21 +1295,41 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
         .expect("expected `raw_flow_fences_count`");
 
     if count == 0 {
-        context.push(">");
+        if context.raw_flow_attributes {
+            context.push(">");
+        }
+
         context.slurp_one_line_ending = true;
+
+        if context.options.code_line_numbers
+            && context.events[context.index].name == Name::CodeFencedFence
+        {
+            // Capture the code, to wrap its lines when done.
+            context.buffer();
+        }
     }
 
     context.raw_flow_fences_count = Some(count + 1);
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta].
+fn on_exit_code_fenced_fence_meta(context: &mut CompileContext) {
+    let value = context.resume();
+
+    if context.options.code_meta_attribute && context.raw_flow_attributes {
+        context.attribute_encoded("data-meta", &value);
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceInfo`][Name::CodeFencedFenceInfo].
 ///
 /// Note: math (flow) does not support `info`.
 fn on_exit_raw_flow_fence_info(context: &mut CompileContext) {
     let value = context.resume();
-    context.push(" class=\"language-");
-    context.push(&value);
-    context.push("\"");
+
+    if context.raw_flow_attributes {
+        context.attribute_encoded("class", &format!("language-{}", value));
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFenced`][Name::CodeFenced],[`CodeIndented`][Name::CodeIndented],[`MathFlow`][Name::MathFlow]}.
@@ -851,11 +1361,22 @@ fn on_exit_raw_flow(context: &mut CompileContext) {
         context.line_ending_if_needed();
     }
 
-    context.push("</code></pre>");
+    if context.options.code_line_numbers && context.events[context.index].name != Name::MathFlow {
+        let value = context.resume();
+        generate_code_lines(context, &value);
+    }
+
+    while let Some(name) = context.raw_flow_tag_names.pop() {
+        context.push("</");
+        context.push(&name);
+        context.push(">");
+    }
+
+    context.raw_flow_attributes = false;
 
     if let Some(count) = context.raw_flow_fences_count.take() {
         if count < 2 {
-            context.line_ending_if_needed();
+            context.block_line_ending_if_needed();
         }
     }
 
@@ -908,7 +1429,7 @@ fn on_exit_raw_text(context: &mut CompileContext) {
     context.raw_text_inside = false;
     context.push(str::from_utf8(&bytes).unwrap());
 
-    if !context.image_alt_inside {
+    if !context.image_alt_inside && context.allowed("code") {
         context.push("</code>");
     }
 }
@@ -930,14 +1451,19 @@ fn on_exit_drop_slurp(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeTextData`][Name::CodeTextData],[`Data`][Name::Data],[`CharacterEscapeValue`][Name::CharacterEscapeValue]}.
 fn on_exit_data(context: &mut CompileContext) {
-    context.push(&encode(
-        Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        .as_str(),
-        context.encode_html,
-    ));
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    );
+    let value = slice.as_str();
+
+    // Collapse whitespace in text, but not in code.
+    if context.options.normalize_whitespace && context.events[context.index].name == Name::Data {
+        let value = collapse_whitespace(value, context.after_whitespace());
+        context.push(&encode(&value, context.encode_html));
+    } else {
+        context.push(&encode(value, context.encode_html));
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Definition`][Name::Definition].
@@ -970,16 +1496,64 @@ fn on_exit_definition_label_string(context: &mut CompileContext) {
         Some(Position::from_exit_event(context.events, context.index).to_indices());
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`DefinitionList`][Name::DefinitionList].
+fn on_exit_definition_list(context: &mut CompileContext) {
+    if context.allowed("dl") {
+        context.block_line_ending_if_needed();
+        context.push("</dl>");
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DefinitionListDescription`][Name::DefinitionListDescription].
+fn on_exit_definition_list_description(context: &mut CompileContext) {
+    if context.definition_list_description_loose && context.allowed("p") {
+        context.push("</p>");
+    }
+
+    if context.allowed("dd") {
+        context.push("</dd>");
+    }
+
+    context.definition_list_description_loose = false;
+    context.text_inside = false;
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`DefinitionListTerm`][Name::DefinitionListTerm].
+fn on_exit_definition_list_term(context: &mut CompileContext) {
+    if context.allowed("dt") {
+        context.push("</dt>");
+    }
+
+    context.text_inside = false;
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`DefinitionTitleString`][Name::DefinitionTitleString].
 fn on_exit_definition_title_string(context: &mut CompileContext) {
     let buf = context.resume();
     context.media_stack.last_mut().unwrap().title = Some(buf);
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`EmojiName`][Name::EmojiName].
+fn on_exit_emoji_name(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &Position::from_exit_event(context.events, context.index),
+    );
+    let value = decode_emoji(slice.as_str(), context.emoji_shortcodes)
+        .expect("expected to parse only known emoji");
+
+    context.push(&encode(value, context.encode_html));
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Emphasis`][Name::Emphasis].
 fn on_exit_emphasis(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("</em>");
+    let options = context.options;
+    let name = options.emphasis_tag_name.as_deref().unwrap_or("em");
+
+    if !context.image_alt_inside && context.allowed(name) {
+        context.push("</");
+        context.push(name);
+        context.push(">");
     }
 }
 
@@ -987,21 +1561,64 @@ fn on_exit_emphasis(context: &mut CompileContext) {
 fn on_exit_frontmatter(context: &mut CompileContext) {
     context.resume();
     context.slurp_one_line_ending = true;
-}
 
-/// Handle [`Exit`][Kind::Exit]:[`GfmAutolinkLiteralEmail`][Name::GfmAutolinkLiteralEmail].
-fn on_exit_gfm_autolink_literal_email(context: &mut CompileContext) {
-    generate_autolink(
-        context,
-        Some("mailto:"),
-        Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        .as_str(),
-        true,
-    );
-}
+    // Find the fences: after the opening one, before the closing one.
+    let mut index = context.index - 1;
+    let mut end = None;
+    let mut start = None;
+
+    while context.events[index].name != Name::Frontmatter {
+        if context.events[index].name == Name::FrontmatterFence {
+            if context.events[index].kind == Kind::Enter {
+                end.get_or_insert(context.events[index].point.index);
+            } else {
+                start = Some(context.events[index].point.index);
+            }
+        }
+
+        index -= 1;
+    }
+
+    let mut start = start.expect("expected opening fence");
+    let mut end = end.expect("expected closing fence");
+
+    // Ignore the line endings next to the fences.
+    if context.bytes[start] == b'\r' && context.bytes.get(start + 1) == Some(&b'\n') {
+        start += 2;
+    } else {
+        start += 1;
+    }
+
+    if end > start {
+        end -= 1;
+
+        if end > start && context.bytes[end] == b'\n' && context.bytes[end - 1] == b'\r' {
+            end -= 1;
+        }
+    } else {
+        end = start;
+    }
+
+    context.frontmatter = Some(
+        Slice::from_indices(context.bytes, start, end)
+            .as_str()
+            .into(),
+    );
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`GfmAutolinkLiteralEmail`][Name::GfmAutolinkLiteralEmail].
+fn on_exit_gfm_autolink_literal_email(context: &mut CompileContext) {
+    generate_autolink(
+        context,
+        Some("mailto:"),
+        Slice::from_position(
+            context.bytes,
+            &Position::from_exit_event(context.events, context.index),
+        )
+        .as_str(),
+        true,
+    );
+}
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmAutolinkLiteralMailto`][Name::GfmAutolinkLiteralMailto].
 fn on_exit_gfm_autolink_literal_mailto(context: &mut CompileContext) {
@@ -1089,34 +1706,74 @@ fn on_exit_gfm_footnote_call(context: &mut CompileContext) {
         return;
     }
 
-    context.push("<sup><a href=\"#");
-    if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
-    } else {
-        context.push("user-content-");
-    }
-    context.push("fn-");
-    context.push(&safe_id);
-    context.push("\" id=\"");
-    if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
-    } else {
-        context.push("user-content-");
-    }
-    context.push("fnref-");
-    context.push(&safe_id);
-    if context.gfm_footnote_definition_calls[call_index].1 > 1 {
-        context.push("-");
-        context.push(
-            &context.gfm_footnote_definition_calls[call_index]
-                .1
-                .to_string(),
+    if let Some(ref template) = context.options.gfm_footnote_call_template {
+        let prefix = context
+            .options
+            .gfm_footnote_clobber_prefix
+            .as_deref()
+            .unwrap_or("user-content-");
+        let mut ref_id = format!("{}fnref-{}", prefix, safe_id);
+        let count = context.gfm_footnote_definition_calls[call_index].1;
+        if count > 1 {
+            ref_id.push('-');
+            ref_id.push_str(&count.to_string());
+        }
+        let value = fill_template(
+            template,
+            &[
+                ("index", &(call_index + 1).to_string()),
+                ("id", &format!("{}fn-{}", prefix, safe_id)),
+                ("ref_id", &ref_id),
+            ],
         );
+        context.push(&value);
+        return;
+    }
+
+    let sup = context.allowed("sup");
+    let link = context.allowed("a");
+
+    if sup {
+        context.push("<sup>");
+    }
+
+    if link {
+        context.push("<a href=\"#");
+        if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
+            context.push(&encode(value, context.encode_html));
+        } else {
+            context.push("user-content-");
+        }
+        context.push("fn-");
+        context.push(&safe_id);
+        context.push("\" id=\"");
+        if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
+            context.push(&encode(value, context.encode_html));
+        } else {
+            context.push("user-content-");
+        }
+        context.push("fnref-");
+        context.push(&safe_id);
+        if context.gfm_footnote_definition_calls[call_index].1 > 1 {
+            context.push("-");
+            context.push(
+                &context.gfm_footnote_definition_calls[call_index]
+                    .1
+                    .to_string(),
+            );
+        }
+        context.push("\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">");
     }
-    context.push("\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">");
 
     context.push(&(call_index + 1).to_string());
-    context.push("</a></sup>");
+
+    if link {
+        context.push("</a>");
+    }
+
+    if sup {
+        context.push("</sup>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmFootnoteDefinitionLabelString`][Name::GfmFootnoteDefinitionLabelString].
@@ -1147,7 +1804,7 @@ fn on_exit_gfm_footnote_definition(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_exit_gfm_strikethrough(context: &mut CompileContext) {
-    if !context.image_alt_inside {
+    if !context.image_alt_inside && context.allowed("del") {
         context.push("</del>");
     }
 }
@@ -1155,14 +1812,19 @@ fn on_exit_gfm_strikethrough(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`GfmTable`][Name::GfmTable].
 fn on_exit_gfm_table(context: &mut CompileContext) {
     context.gfm_table_align = None;
-    context.line_ending_if_needed();
-    context.push("</table>");
+
+    if context.allowed("table") {
+        context.block_line_ending_if_needed();
+        context.push("</table>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableBody`][Name::GfmTableBody].
 fn on_exit_gfm_table_body(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    context.push("</tbody>");
+    if context.allowed("tbody") {
+        context.block_line_ending_if_needed();
+        context.push("</tbody>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableCell`][Name::GfmTableCell].
@@ -1170,10 +1832,16 @@ fn on_exit_gfm_table_cell(context: &mut CompileContext) {
     let align = context.gfm_table_align.as_ref().unwrap();
 
     if context.gfm_table_column < align.len() {
-        if context.gfm_table_in_head {
-            context.push("</th>");
+        let name = if context.gfm_table_in_head {
+            "th"
         } else {
-            context.push("</td>");
+            "td"
+        };
+
+        if context.allowed(name) {
+            context.push("</");
+            context.push(name);
+            context.push(">");
         }
     } else {
         // Stop capturing.
@@ -1186,8 +1854,11 @@ fn on_exit_gfm_table_cell(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableHead`][Name::GfmTableHead].
 fn on_exit_gfm_table_head(context: &mut CompileContext) {
     context.gfm_table_in_head = false;
-    context.line_ending_if_needed();
-    context.push("</thead>");
+
+    if context.allowed("thead") {
+        context.block_line_ending_if_needed();
+        context.push("</thead>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTableRow`][Name::GfmTableRow].
@@ -1204,21 +1875,24 @@ fn on_exit_gfm_table_row(context: &mut CompileContext) {
     }
 
     context.gfm_table_column = 0;
-    context.line_ending_if_needed();
-    context.push("</tr>");
+
+    if context.allowed("tr") {
+        context.block_line_ending_if_needed();
+        context.push("</tr>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
 fn on_exit_gfm_task_list_item_check(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("/>");
+    if !context.image_alt_inside && context.allowed("input") {
+        context.push(if context.options.xhtml { " />" } else { ">" });
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTaskListItemValueChecked`][Name::GfmTaskListItemValueChecked].
 fn on_exit_gfm_task_list_item_value_checked(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("checked=\"\" ");
+    if !context.image_alt_inside && context.allowed("input") {
+        context.push(" checked=\"\"");
     }
 }
 
@@ -1228,10 +1902,17 @@ fn on_exit_heading_atx(context: &mut CompileContext) {
         .heading_atx_rank
         .take()
         .expect("`heading_atx_rank` must be set in headings");
+    let name = context.heading_tag_name(rank);
+    let value = context.resume();
 
-    context.push("</h");
-    context.push(&rank.to_string());
-    context.push(">");
+    context.heading_start(rank, &value);
+    context.push(&value);
+
+    if let Some(name) = name {
+        context.push("</");
+        context.push(&name);
+        context.push(">");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`HeadingAtxSequence`][Name::HeadingAtxSequence].
@@ -1243,11 +1924,10 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
             &Position::from_exit_event(context.events, context.index),
         )
         .len();
-        context.line_ending_if_needed();
-        context.heading_atx_rank = Some(rank);
-        context.push("<h");
-        context.push(&rank.to_string());
-        context.push(">");
+        context.block_line_ending_if_needed();
+        context.heading_atx_rank = Some(context.heading_rank(rank));
+        // Buffer the content, so that it’s known when adding the opening tag.
+        context.buffer();
     }
 }
 
@@ -1262,6 +1942,7 @@ fn on_exit_heading_setext_text(context: &mut CompileContext) {
     let buf = context.resume();
     context.heading_setext_buffer = Some(buf);
     context.slurp_one_line_ending = true;
+    context.text_inside = false;
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`HeadingSetextUnderlineSequence`][Name::HeadingSetextUnderlineSequence].
@@ -1272,20 +1953,32 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
         .expect("`heading_atx_rank` must be set in headings");
     let position = Position::from_exit_event(context.events, context.index);
     let head = context.bytes[position.start.index];
-    let rank = if head == b'-' { "2" } else { "1" };
+    let rank = context.heading_rank(if head == b'-' { 2 } else { 1 });
+    let name = context.heading_tag_name(rank);
 
-    context.line_ending_if_needed();
-    context.push("<h");
-    context.push(rank);
-    context.push(">");
+    context.block_line_ending_if_needed();
+    context.heading_start(rank, &text);
     context.push(&text);
-    context.push("</h");
-    context.push(rank);
-    context.push(">");
+
+    if let Some(name) = name {
+        context.push("</");
+        context.push(&name);
+        context.push(">");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
 fn on_exit_html(context: &mut CompileContext) {
+    if context.html_remove_inside {
+        context.resume();
+        context.html_remove_inside = false;
+
+        // Drop the line ending after removed HTML (flow) too.
+        if context.events[context.index].name == Name::HtmlFlow {
+            context.slurp_one_line_ending = true;
+        }
+    }
+
     context.encode_html = true;
 }
 
@@ -1297,7 +1990,8 @@ fn on_exit_html_data(context: &mut CompileContext) {
     );
     let value = slice.as_str();
 
-    let encoded = if context.options.gfm_tagfilter && context.options.allow_dangerous_html {
+    // HTML is allowed if we are not encoding it.
+    let encoded = if context.options.gfm_tagfilter && !context.encode_html {
         encode(&gfm_tagfilter(value), context.encode_html)
     } else {
         encode(value, context.encode_html)
@@ -1325,11 +2019,63 @@ fn on_exit_line_ending(context: &mut CompileContext) {
     } else if context.slurp_one_line_ending
         // Ignore line endings after definitions.
         || (context.index > 1
-            && (context.events[context.index - 2].name == Name::Definition
+            && (context.events[context.index - 2].name == Name::AbbreviationDefinition
+                || context.events[context.index - 2].name == Name::Definition
                 || context.events[context.index - 2].name == Name::GfmFootnoteDefinition))
     {
         context.slurp_one_line_ending = false;
+    } else if context.index > 1
+        && context.events[context.index - 2].kind == Kind::Exit
+        && matches!(
+            context.events[context.index - 2].name,
+            Name::BlockQuote
+                | Name::CodeFenced
+                | Name::CodeIndented
+                | Name::DefinitionList
+                | Name::DefinitionListDescription
+                | Name::DefinitionListTerm
+                | Name::GfmTable
+                | Name::GfmTableBody
+                | Name::GfmTableDelimiterRow
+                | Name::GfmTableHead
+                | Name::GfmTableRow
+                | Name::HeadingAtx
+                | Name::HeadingSetext
+                | Name::HtmlFlow
+                | Name::ListItem
+                | Name::ListOrdered
+                | Name::ListUnordered
+                | Name::MathFlow
+                | Name::MdxEsm
+                | Name::MdxFlowExpression
+                | Name::MdxJsxFlowTag
+                | Name::Paragraph
+                | Name::PercentCommentFlow
+                | Name::ThematicBreak
+        )
+        // When the closing tag of a block is not allowed, there can already
+        // be a line ending.
+        && (context.options.tight_output || context.after_line_ending())
+    {
+        // Ignore line endings between blocks.
     } else {
+        // Turn soft line endings into hard breaks, if needed.
+        if context.options.hard_break_all
+            && context.text_inside
+            && !context.image_alt_inside
+            && context.allowed("br")
+            && !matches!(
+                context.events[context.index - 2].name,
+                Name::HardBreakEscape | Name::HardBreakTrailing | Name::HtmlTextData
+            )
+        {
+            context.push(if context.options.xhtml {
+                "<br />"
+            } else {
+                "<br>"
+            });
+        }
+
         context.push(&encode(
             Slice::from_position(
                 context.bytes,
@@ -1344,12 +2090,13 @@ fn on_exit_line_ending(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
 fn on_exit_list(context: &mut CompileContext) {
     context.tight_stack.pop();
-    context.line_ending();
-    context.push(if context.events[context.index].name == Name::ListOrdered {
-        "</ol>"
-    } else {
-        "</ul>"
-    });
+
+    if context.allowed(list_tag_name(context)) {
+        context.block_line_ending_if_needed();
+        context.push("</");
+        context.push(list_tag_name(context));
+        context.push(">");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ListItem`][Name::ListItem].
@@ -1364,8 +2111,10 @@ fn on_exit_list_item(context: &mut CompileContext) {
             Name::LineEnding,
             Name::SpaceOrTab,
             // Also ignore things that don’t contribute to the document.
+            Name::AbbreviationDefinition,
             Name::Definition,
             Name::GfmFootnoteDefinition,
+            Name::PercentCommentFlow,
         ],
     );
     let previous = &context.events[before_item];
@@ -1374,11 +2123,13 @@ fn on_exit_list_item(context: &mut CompileContext) {
 
     context.slurp_one_line_ending = false;
 
-    if !tight_paragraph && !empty_item {
-        context.line_ending_if_needed();
-    }
+    if context.allowed("li") {
+        if !tight_paragraph && !empty_item {
+            context.block_line_ending_if_needed();
+        }
 
-    context.push("</li>");
+        context.push("</li>");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ListItemValue`][Name::ListItemValue].
@@ -1391,9 +2142,7 @@ fn on_exit_list_item_value(context: &mut CompileContext) {
         let value = slice.as_str().parse::<u32>().ok().unwrap();
 
         if value != 1 {
-            context.push(" start=\"");
-            context.push(&value.to_string());
-            context.push("\"");
+            context.attribute("start", &value.to_string());
         }
     }
 }
@@ -1417,6 +2166,24 @@ fn on_exit_media(context: &mut CompileContext) {
 
     let media = context.media_stack.pop().unwrap();
     let label = media.label.unwrap();
+    let empty_alt = media.image && !is_in_image && label.is_empty();
+
+    if context.options.image_empty_alt == ImageEmptyAlt::Drop {
+        if empty_alt {
+            if let Some(parent) = context.media_stack.last_mut() {
+                parent.dropped_image = true;
+            }
+
+            return;
+        }
+
+        // Drop links that are empty because of dropped images too, as they
+        // would not be accessible.
+        if !media.image && media.dropped_image && label.trim().is_empty() {
+            return;
+        }
+    }
+
     let id = media.reference_id.or(media.label_id).map(|indices| {
         normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str())
     });
@@ -1439,46 +2206,51 @@ fn on_exit_media(context: &mut CompileContext) {
         None
     };
 
-    if !is_in_image {
-        if media.image {
-            context.push("<img src=\"");
-        } else {
-            context.push("<a href=\"");
-        };
+    let destination = if let Some(index) = definition_index {
+        context.definitions[index].destination.clone()
+    } else {
+        media.destination
+    };
 
-        let destination = if let Some(index) = definition_index {
-            context.definitions[index].destination.as_ref()
-        } else {
-            media.destination.as_ref()
-        };
+    // Drop too long destinations, such as giant data URIs, by not generating
+    // the element.
+    let too_long = matches!(
+        (context.options.max_url_length, &destination),
+        (Some(max), Some(destination)) if destination.len() > max
+    );
 
+    let element =
+        !is_in_image && !too_long && context.allowed(if media.image { "img" } else { "a" });
+    let mut url = None;
+
+    if element {
         if let Some(destination) = destination {
-            let url = if context.options.allow_dangerous_protocol {
-                sanitize(destination)
-            } else {
-                sanitize_with_protocols(
-                    destination,
-                    if media.image {
-                        &SAFE_PROTOCOL_SRC
-                    } else {
-                        &SAFE_PROTOCOL_HREF
-                    },
-                )
+            let destination = match context.options.base_url {
+                Some(ref base) => resolve_url(base, &destination),
+                None => destination,
             };
-            context.push(&url);
+            let value = sanitize_url(
+                context.options,
+                &destination,
+                if media.image {
+                    &SAFE_PROTOCOL_SRC
+                } else {
+                    &SAFE_PROTOCOL_HREF
+                },
+            );
+            url = Some(value);
         }
 
-        if media.image {
-            context.push("\" alt=\"");
-        };
-    }
-
-    if media.image {
-        context.push(&label);
-    }
+        let value = url.as_deref().unwrap_or("");
 
-    if !is_in_image {
-        context.push("\"");
+        if media.image {
+            context.push("<img");
+            context.attribute_encoded("src", value);
+            context.attribute_encoded("alt", &label);
+        } else {
+            context.push("<a");
+            context.attribute_encoded("href", value);
+        }
 
         let title = if let Some(index) = definition_index {
             context.definitions[index].title.clone()
@@ -1487,22 +2259,34 @@ fn on_exit_media(context: &mut CompileContext) {
         };
 
         if let Some(title) = title {
-            context.push(" title=\"");
-            context.push(&title);
-            context.push("\"");
+            context.attribute_encoded("title", &title);
         };
 
-        if media.image {
+        if !media.image {
+            if let Some(ref url) = url {
+                generate_external_link_attributes(context, url);
+            }
+        }
+
+        if empty_alt && context.options.image_empty_alt == ImageEmptyAlt::Presentation {
+            context.attribute("role", "presentation");
+        }
+
+        if media.image && context.options.xhtml {
             context.push(" /");
         }
 
         context.push(">");
     }
 
-    if !media.image {
+    if media.image {
+        if !element {
+            context.push(&label);
+        }
+    } else {
         context.push(&label);
 
-        if !is_in_image {
+        if element {
             context.push("</a>");
         }
     }
@@ -1514,9 +2298,11 @@ fn on_exit_paragraph(context: &mut CompileContext) {
 
     if *tight {
         context.slurp_one_line_ending = true;
-    } else {
+    } else if context.allowed("p") {
         context.push("</p>");
     }
+
+    context.text_inside = false;
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ReferenceString`][Name::ReferenceString].
@@ -1543,47 +2329,221 @@ fn on_exit_resource_title_string(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:[`Strong`][Name::Strong].
 fn on_exit_strong(context: &mut CompileContext) {
-    if !context.image_alt_inside {
-        context.push("</strong>");
+    let options = context.options;
+    let name = options.strong_tag_name.as_deref().unwrap_or("strong");
+
+    if !context.image_alt_inside && context.allowed(name) {
+        context.push("</");
+        context.push(name);
+        context.push(">");
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_exit_thematic_break(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    context.push("<hr />");
+    if !context.allowed("hr") {
+        context.slurp_one_line_ending = true;
+        return;
+    }
+
+    context.block_line_ending_if_needed();
+    context.push("<hr");
+
+    for (name, value) in &context.options.thematic_break_attributes {
+        context.attribute(name, value);
+    }
+
+    context.push(if context.options.xhtml { " />" } else { ">" });
+}
+
+/// Get the tag name of the list at the current event (`ol` or `ul`).
+fn list_tag_name(context: &CompileContext) -> &'static str {
+    if context.events[context.index].name == Name::ListOrdered {
+        "ol"
+    } else {
+        "ul"
+    }
+}
+
+/// Add the opening tags of raw (flow), such as `<pre><code`.
+///
+/// Elements that are not allowed are not generated, except for the outer
+/// element, which turns into a paragraph.
+/// The last element is left open (no `>`), so that attributes can be added
+/// to it, which is stored in `raw_flow_attributes`.
+fn raw_flow_start(context: &mut CompileContext, names: &[&str]) {
+    let mut index = 0;
+
+    while index < names.len() {
+        let name = names[index];
+        let last = index == names.len() - 1;
+
+        if context.allowed(name) {
+            context.push("<");
+            context.push(name);
+
+            if !last {
+                context.push(">");
+            }
+
+            context.raw_flow_tag_names.push(name.into());
+            context.raw_flow_attributes = last;
+        } else if index == 0 && context.allowed("p") {
+            context.push("<p>");
+            context.raw_flow_tag_names.push("p".into());
+        }
+
+        index += 1;
+    }
+}
+
+/// Generate a table of contents.
+fn generate_toc(context: &CompileContext) -> String {
+    let eol = context.line_ending_default.as_str();
+    let ul = context.allowed("ul");
+    let li = context.allowed("li");
+    let a = context.allowed("a");
+    let mut result = String::new();
+    // Ranks of open lists.
+    let mut stack: Vec<usize> = vec![];
+
+    // Add `value` on its own line, if there is a value.
+    let line = |result: &mut String, value: &str| {
+        if !value.is_empty() {
+            if !result.is_empty() {
+                result.push_str(eol);
+            }
+
+            result.push_str(value);
+        }
+    };
+
+    for (rank, id, text) in &context.toc {
+        let rank = *rank;
+
+        if let Some(current) = stack.last_mut() {
+            if rank > *current {
+                // Open a list in the current item.
+                line(&mut result, if ul { "<ul>" } else { "" });
+                stack.push(rank);
+            } else {
+                if li {
+                    result.push_str("</li>");
+                }
+
+                // Close lists whose parent list is of this rank or deeper,
+                // but never the outer list.
+                while stack.len() > 1 && stack[stack.len() - 2] >= rank {
+                    stack.pop();
+                    line(&mut result, if ul { "</ul>" } else { "" });
+                    line(&mut result, if li { "</li>" } else { "" });
+                }
+
+                let current = stack.last_mut().unwrap();
+                *current = rank.min(*current);
+            }
+        } else {
+            line(&mut result, if ul { "<ul>" } else { "" });
+            stack.push(rank);
+        }
+
+        let mut entry = String::new();
+
+        if li {
+            entry.push_str("<li>");
+        }
+
+        if a {
+            entry.push_str("<a href=\"#");
+            entry.push_str(&encode(id, true));
+            entry.push_str("\">");
+        }
+
+        entry.push_str(text);
+
+        if a {
+            entry.push_str("</a>");
+        }
+
+        line(&mut result, &entry);
+    }
+
+    if li {
+        result.push_str("</li>");
+    }
+
+    while stack.pop().is_some() {
+        line(&mut result, if ul { "</ul>" } else { "" });
+
+        if !stack.is_empty() {
+            line(&mut result, if li { "</li>" } else { "" });
+        }
+    }
+
+    result
 }
 
 /// Generate a footnote section.
 fn generate_footnote_section(context: &mut CompileContext) {
-    context.line_ending_if_needed();
-    context.push("<section data-footnotes=\"\" class=\"footnotes\"><");
-    if let Some(ref value) = context.options.gfm_footnote_label_tag_name {
-        context.push(&encode(value, context.encode_html));
+    let section = context.allowed("section");
+    let list = context.allowed("ol");
+    let label_tag_name = context
+        .options
+        .gfm_footnote_label_tag_name
+        .as_deref()
+        .unwrap_or("h2");
+    // Labels that are not allowed turn into paragraphs, without attributes.
+    let label_element = context.allowed(label_tag_name);
+    let label_tag_name = if label_element {
+        Some(label_tag_name)
+    } else if context.allowed("p") {
+        Some("p")
     } else {
-        context.push("h2");
+        None
+    };
+
+    context.block_line_ending_if_needed();
+
+    if section {
+        context.push("<section data-footnotes=\"\" class=\"footnotes\">");
+        // Put the label on its own line, as it’s a block.
+        if context.options.canonical {
+            context.line_ending();
+        }
     }
-    context.push(" id=\"footnote-label\" ");
-    if let Some(ref value) = context.options.gfm_footnote_label_attributes {
-        context.push(value);
-    } else {
-        context.push("class=\"sr-only\"");
+
+    if let Some(ref name) = label_tag_name {
+        context.push("<");
+        context.push(name);
+
+        if label_element {
+            context.push(" id=\"footnote-label\" ");
+            if let Some(ref value) = context.options.gfm_footnote_label_attributes {
+                context.push(value);
+            } else {
+                context.push("class=\"sr-only\"");
+            }
+        }
+
+        context.push(">");
     }
-    context.push(">");
+
     if let Some(ref value) = context.options.gfm_footnote_label {
         context.push(&encode(value, context.encode_html));
     } else {
         context.push("Footnotes");
     }
-    context.push("</");
-    if let Some(ref value) = context.options.gfm_footnote_label_tag_name {
-        context.push(&encode(value, context.encode_html));
-    } else {
-        context.push("h2");
+
+    if let Some(ref name) = label_tag_name {
+        context.push("</");
+        context.push(name);
+        context.push(">");
+    }
+
+    if list {
+        context.block_line_ending_if_needed();
+        context.push("<ol>");
     }
-    context.push(">");
-    context.line_ending();
-    context.push("<ol>");
 
     let mut index = 0;
     while index < context.gfm_footnote_definition_calls.len() {
@@ -1591,11 +2551,17 @@ fn generate_footnote_section(context: &mut CompileContext) {
         index += 1;
     }
 
-    context.line_ending();
-    context.push("</ol>");
-    context.line_ending();
-    context.push("</section>");
-    context.line_ending();
+    if list {
+        context.block_line_ending_if_needed();
+        context.push("</ol>");
+    }
+
+    if section {
+        context.block_line_ending_if_needed();
+        context.push("</section>");
+    }
+
+    context.block_line_ending_if_needed();
 }
 
 /// Generate a footnote item from a call.
@@ -1618,22 +2584,28 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
         "expected definition"
     );
 
-    context.line_ending();
-    context.push("<li id=\"");
-    if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
-    } else {
-        context.push("user-content-");
+    let item = context.allowed("li");
+    let sup = context.allowed("sup");
+
+    context.block_line_ending_if_needed();
+
+    if item {
+        context.push("<li id=\"");
+        if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
+            context.push(&encode(value, context.encode_html));
+        } else {
+            context.push("user-content-");
+        }
+        context.push("fn-");
+        context.push(&safe_id);
+        context.push("\">");
+        context.block_line_ending_if_needed();
     }
-    context.push("fn-");
-    context.push(&safe_id);
-    context.push("\">");
-    context.line_ending();
 
-    // Create one or more backreferences.
+    // Create one or more backreferences, if links are allowed.
     let mut reference_index = 0;
     let mut backreferences = String::new();
-    while reference_index < context.gfm_footnote_definition_calls[index].1 {
+    while context.allowed("a") && reference_index < context.gfm_footnote_definition_calls[index].1 {
         if reference_index != 0 {
             backreferences.push(' ');
         }
@@ -1657,9 +2629,13 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
         }
         backreferences.push_str("\" class=\"data-footnote-backref\">↩");
         if reference_index != 0 {
-            backreferences.push_str("<sup>");
+            if sup {
+                backreferences.push_str("<sup>");
+            }
             backreferences.push_str(&(reference_index + 1).to_string());
-            backreferences.push_str("</sup>");
+            if sup {
+                backreferences.push_str("</sup>");
+            }
         }
         backreferences.push_str("</a>");
 
@@ -1678,7 +2654,9 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
     // there.
     // But in all other cases, `<` and `>` would be encoded, so we can be
     // sure that this is generated by our compiler.
-    if byte_index > 3
+    if backreferences.is_empty() {
+        context.push(&value);
+    } else if byte_index > 3
         && bytes[byte_index - 4] == b'<'
         && bytes[byte_index - 3] == b'/'
         && bytes[byte_index - 2] == b'p'
@@ -1693,11 +2671,14 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
         context.push(&result);
     } else {
         context.push(&value);
-        context.line_ending_if_needed();
+        context.block_line_ending_if_needed();
         context.push(&backreferences);
     }
-    context.line_ending_if_needed();
-    context.push("</li>");
+    context.block_line_ending_if_needed();
+
+    if item {
+        context.push("</li>");
+    }
 }
 
 /// Generate an autolink (used by unicode autolinks and GFM autolink literals).
@@ -1718,7 +2699,10 @@ fn generate_autolink(
         index += 1;
     }
 
-    if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
+    let element =
+        !context.image_alt_inside && (!is_in_link || !is_gfm_literal) && context.allowed("a");
+
+    if element {
         context.push("<a href=\"");
         let url = if let Some(protocol) = protocol {
             format!("{}{}", protocol, value)
@@ -1726,19 +2710,260 @@ fn generate_autolink(
             value.into()
         };
 
-        let url = if context.options.allow_dangerous_protocol {
-            sanitize(&url)
-        } else {
-            sanitize_with_protocols(&url, &SAFE_PROTOCOL_HREF)
-        };
+        let url = sanitize_url(context.options, &url, &SAFE_PROTOCOL_HREF);
 
         context.push(&url);
-        context.push("\">");
+        context.push("\"");
+        generate_external_link_attributes(context, &url);
+        context.push(">");
     }
 
     context.push(&encode(value, context.encode_html));
 
-    if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
+    if element {
         context.push("</a>");
     }
 }
+
+/// Find the URL of a citation at the start of a block quote.
+///
+/// That’s a first line of only `Source:` and an autolink:
+///
+/// ```markdown
+/// > | > Source: <https://example.com>
+///       ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+///   | > a
+/// ```
+fn block_quote_cite(context: &CompileContext) -> Option<String> {
+    let events = context.events;
+    let mut index = skip::opt(events, context.index + 1, &[Name::BlockQuotePrefix]);
+
+    if events.get(index)?.name != Name::Paragraph || events[index].kind != Kind::Enter {
+        return None;
+    }
+
+    index = skip::opt(events, index + 1, &[Name::SpaceOrTab]);
+
+    if events.get(index)?.name != Name::Data {
+        return None;
+    }
+
+    let label = Slice::from_position(context.bytes, &Position::from_exit_event(events, index + 1));
+
+    // Enter:Autolink, Enter:AutolinkMarker, Exit:AutolinkMarker,
+    // Enter:AutolinkProtocol.
+    if label.as_str().trim_end() != "Source:"
+        || events.get(index + 2)?.name != Name::Autolink
+        || events.get(index + 5)?.name != Name::AutolinkProtocol
+    {
+        return None;
+    }
+
+    // Exit:AutolinkProtocol, Enter:AutolinkMarker, Exit:AutolinkMarker,
+    // Exit:Autolink.
+    let after = skip::opt(events, index + 10, &[Name::SpaceOrTab]);
+
+    if !matches!(events.get(after)?.name, Name::LineEnding | Name::Paragraph) {
+        return None;
+    }
+
+    Some(
+        Slice::from_position(context.bytes, &Position::from_exit_event(events, index + 6))
+            .as_str()
+            .into(),
+    )
+}
+
+/// Make a URL of a link, image, or autolink safe.
+///
+/// Percent-encodes it if `encode_urls` is on, and drops it if it has a
+/// protocol not in `protocols`, unless `allow_dangerous_protocol` is on.
+fn sanitize_url(options: &CompileOptions, url: &str, protocols: &[&str]) -> String {
+    match (options.encode_urls, options.allow_dangerous_protocol) {
+        (true, true) => sanitize(url),
+        (true, false) => sanitize_with_protocols(url, protocols),
+        (false, true) => encode(url, true),
+        (false, false) => check_protocols(encode(url, true), protocols),
+    }
+}
+
+/// Fill the `{name}` placeholders in `template` with encoded values.
+///
+/// Unknown placeholders, and braces that are not placeholders, are kept.
+fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let value = rest.find('}').and_then(|end| {
+            values
+                .iter()
+                .find(|d| d.0 == &rest[1..end])
+                .map(|d| (end, d.1))
+        });
+
+        if let Some((end, value)) = value {
+            result.push_str(&encode(value, true));
+            rest = &rest[end + 1..];
+        } else {
+            result.push('{');
+            rest = &rest[1..];
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Generate `rel` and `target` attributes for a link, if it is external.
+///
+/// A link is external if its URL has a host: either it has a protocol
+/// followed by `//` (such as `https://example.com`), or it is
+/// protocol-relative (such as `//example.com`).
+fn generate_external_link_attributes(context: &mut CompileContext, url: &str) {
+    if context.options.external_link_rel.is_none() && context.options.external_link_target.is_none()
+    {
+        return;
+    }
+
+    let bytes = url.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len()
+        && (bytes[index].is_ascii_alphanumeric() || matches!(bytes[index], b'+' | b'-' | b'.'))
+    {
+        index += 1;
+    }
+
+    // Protocol-relative, or a protocol (starting with a letter) followed by
+    // `//`.
+    let external = if index == 0 {
+        url.starts_with("//")
+    } else {
+        bytes[0].is_ascii_alphabetic() && url[index..].starts_with("://")
+    };
+
+    if external {
+        if let Some(ref value) = context.options.external_link_rel {
+            context.attribute("rel", value);
+        }
+
+        if let Some(ref value) = context.options.external_link_target {
+            context.attribute("target", value);
+        }
+    }
+}
+
+/// Generate lines of code, each wrapped in a `span` with its line number.
+///
+/// Line endings are kept between the elements.
+fn generate_code_lines(context: &mut CompileContext, value: &str) {
+    if !context.allowed("span") {
+        context.push(value);
+        return;
+    }
+
+    let bytes = value.as_bytes();
+    let mut line = 1;
+    let mut start = 0;
+    let mut index = 0;
+
+    while index <= bytes.len() {
+        let end = index;
+
+        if index == bytes.len() {
+            index += 1;
+        } else if bytes[index] == b'\r' && index + 1 < bytes.len() && bytes[index + 1] == b'\n' {
+            index += 2;
+        } else if matches!(bytes[index], b'\n' | b'\r') {
+            index += 1;
+        } else {
+            index += 1;
+            continue;
+        }
+
+        // Ignore the empty “line” after a final line ending.
+        if end < bytes.len() || start < end {
+            context.push("<span class=\"line\" data-line=\"");
+            context.push(&line.to_string());
+            context.push("\">");
+            context.push(&value[start..end]);
+            context.push("</span>");
+            context.push(&value[end..index.min(bytes.len())]);
+            line += 1;
+        }
+
+        start = index;
+    }
+}
+
+/// Check if `name` is a valid HTML tag name.
+fn is_tag_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(char) if char.is_ascii_alphabetic())
+        && chars.all(|char| char.is_ascii_alphanumeric() || char == '-')
+}
+
+/// Check if `name` is a valid HTML attribute name.
+///
+/// See: <https://html.spec.whatwg.org/multipage/syntax.html#attributes-2>.
+fn is_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.chars().any(|char| {
+            char.is_control()
+                || char.is_whitespace()
+                || matches!(char, '"' | '\'' | '>' | '/' | '=' | '<')
+        })
+}
+
+/// Collapse runs of spaces and tabs into one space.
+///
+/// Pass whether the value comes right after a space or tab, so that runs
+/// across events (such as `a&#32; b`) are collapsed too.
+fn collapse_whitespace(value: &str, after_whitespace: bool) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut in_whitespace = after_whitespace;
+
+    for char in value.chars() {
+        if matches!(char, '\t' | ' ') {
+            if !in_whitespace {
+                result.push(' ');
+                in_whitespace = true;
+            }
+        } else {
+            result.push(char);
+            in_whitespace = false;
+        }
+    }
+
+    result
+}
+
+/// Remove tags from HTML, keeping the text.
+fn strip_tags(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut in_tag = false;
+
+    for char in value.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(char),
+            _ => {}
+        }
+    }
+
+    result
+}
+
+/// Decode the character references that [`encode`][] generates.
+fn decode_html(value: &str) -> String {
+    value
+        .replace("&quot;", "\"")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
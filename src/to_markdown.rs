@@ -0,0 +1,974 @@
+//! Turn a syntax tree into markdown.
+
+use crate::configuration::SerializeOptions;
+use crate::mdast::{
+    AlignKind, AttributeContent, AttributeValue, Code, DefinitionList, Node, ReferenceKind, Table,
+};
+use crate::util::normalize_identifier::normalize_identifier;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Info needed, in all content types, when serializing.
+#[derive(Debug)]
+struct State<'a> {
+    /// Configuration.
+    options: &'a SerializeOptions,
+    /// Whether we are in a table cell, where `|` must be escaped.
+    in_table: bool,
+}
+
+/// Turn an mdast syntax tree into markdown.
+pub fn serialize(node: &Node, options: &SerializeOptions) -> Result<String, String> {
+    if !matches!(options.bullet, '*' | '+' | '-') {
+        return Err(format!(
+            "Cannot serialize bullets with `{}`, expected `*`, `+`, or `-`",
+            options.bullet
+        ));
+    }
+
+    if !matches!(options.emphasis, '*' | '_') {
+        return Err(format!(
+            "Cannot serialize emphasis with `{}`, expected `*` or `_`",
+            options.emphasis
+        ));
+    }
+
+    if !matches!(options.strong, '*' | '_') {
+        return Err(format!(
+            "Cannot serialize strong with `{}`, expected `*` or `_`",
+            options.strong
+        ));
+    }
+
+    let mut state = State {
+        options,
+        in_table: false,
+    };
+    let mut value = if is_phrasing(node) {
+        let mut buf = String::new();
+        inline(&mut state, node, None, &mut buf);
+        buf
+    } else {
+        block(&mut state, node)
+    };
+
+    if !value.is_empty() && !value.ends_with('\n') {
+        value.push('\n');
+    }
+
+    Ok(value)
+}
+
+/// Serialize flow content, joined by `separator`.
+fn flow(state: &mut State, children: &[Node], separator: &str) -> String {
+    let mut results = vec![];
+    // Ordered-ness and marker of the previous list, if the previous node is
+    // a list.
+    let mut previous_list: Option<(bool, char)> = None;
+    let mut index = 0;
+
+    while index < children.len() {
+        let child = &children[index];
+
+        match child {
+            Node::List(list) => {
+                let default = if list.ordered {
+                    '.'
+                } else {
+                    state.options.bullet
+                };
+                let marker = match previous_list {
+                    // Two adjacent lists of the same kind would form one list.
+                    Some((ordered, marker)) if ordered == list.ordered && marker == default => {
+                        other_marker(default)
+                    }
+                    _ => default,
+                };
+                results.push(list_value(state, child, marker));
+                previous_list = Some((list.ordered, marker));
+            }
+            Node::Code(code) => {
+                // Indented code cannot interrupt a paragraph, and would
+                // continue a list item or footnote definition.
+                let indent = index == 0
+                    || (separator == "\n\n"
+                        && !matches!(
+                            children[index - 1],
+                            Node::List(_) | Node::ListItem(_) | Node::FootnoteDefinition(_)
+                        ));
+                results.push(code_value(state, code, indent));
+                previous_list = None;
+            }
+            _ => {
+                results.push(block(state, child));
+                previous_list = None;
+            }
+        }
+
+        index += 1;
+    }
+
+    results.join(separator)
+}
+
+/// Serialize a node as flow content.
+fn block(state: &mut State, node: &Node) -> String {
+    match node {
+        Node::Root(x) => flow(state, &x.children, "\n\n"),
+        Node::BlockQuote(x) => prefix(&flow(state, &x.children, "\n\n"), "> ", ">"),
+        Node::List(_) => {
+            let bullet = state.options.bullet;
+            list_value(state, node, bullet)
+        }
+        Node::ListItem(_) => {
+            let bullet = state.options.bullet.to_string();
+            list_item(state, node, &bullet)
+        }
+        Node::FootnoteDefinition(x) => {
+            let label = label(x.label.as_ref(), &x.identifier);
+            let value = flow(state, &x.children, "\n\n");
+
+            if value.is_empty() {
+                format!("[^{}]:", label)
+            } else {
+                format!("[^{}]: {}", label, indent(&value, "    "))
+            }
+        }
+        Node::DefinitionList(x) => definition_list(state, x),
+        Node::DefinitionListTerm(x) => phrasing(state, &x.children),
+        Node::DefinitionListDescription(x) => {
+            format!(": {}", indent(&phrasing(state, &x.children), "  "))
+        }
+        Node::Paragraph(x) => phrasing(state, &x.children),
+        Node::Heading(x) => heading(state, &x.children, x.depth),
+        Node::ThematicBreak(_) => "***".into(),
+        Node::Code(x) => code_value(state, x, true),
+        Node::Math(x) => fenced(&x.value, '$', 2, x.meta.as_ref().map_or("", |d| d)),
+        Node::Html(x) => x.value.clone(),
+        Node::Definition(x) => {
+            let mut value = format!(
+                "[{}]: {}",
+                label(x.label.as_ref(), &x.identifier),
+                destination(&x.url)
+            );
+
+            if let Some(title) = &x.title {
+                value.push(' ');
+                value.push_str(&title_value(title));
+            }
+
+            value
+        }
+        Node::Table(x) => table(state, x),
+        Node::TableRow(_) | Node::TableCell(_) => {
+            let mut row = vec![];
+            let was_in_table = state.in_table;
+            state.in_table = true;
+            cells(state, node, &mut row);
+            state.in_table = was_in_table;
+            format!("| {} |", row.join(" | "))
+        }
+        Node::Yaml(x) => format!("---\n{}\n---", x.value),
+        Node::Toml(x) => format!("+++\n{}\n+++", x.value),
+        Node::MdxjsEsm(x) => x.value.clone(),
+        Node::MdxFlowExpression(x) => format!("{{{}}}", x.value),
+        Node::MdxJsxFlowElement(x) => {
+            let open = jsx_open(x.name.as_ref(), &x.attributes, x.children.is_empty());
+
+            if x.children.is_empty() {
+                open
+            } else {
+                format!(
+                    "{}\n{}\n</{}>",
+                    open,
+                    flow(state, &x.children, "\n\n"),
+                    x.name.as_ref().map_or("", |d| d)
+                )
+            }
+        }
+        _ => {
+            let mut buf = String::new();
+            inline(state, node, None, &mut buf);
+            buf
+        }
+    }
+}
+
+/// Serialize a definition list.
+fn definition_list(state: &mut State, node: &DefinitionList) -> String {
+    let mut value = String::new();
+
+    for (index, child) in node.children.iter().enumerate() {
+        if index > 0 {
+            // Tight descriptions directly follow what is before them, terms
+            // and spread descriptions come after a blank line.
+            let tight = matches!(child, Node::DefinitionListDescription(x) if !x.spread);
+            value.push_str(if tight { "\n" } else { "\n\n" });
+        }
+
+        value.push_str(&block(state, child));
+    }
+
+    value
+}
+
+/// Serialize code (flow), as indented code if `indent` is allowed and
+/// possible, otherwise fenced.
+fn code_value(state: &State, node: &Code, indent: bool) -> String {
+    let indented = indent
+        && !state.options.fences
+        && node.lang.is_none()
+        && node.meta.is_none()
+        && !node.value.trim().is_empty()
+        && !node.value.starts_with('\n')
+        && !node.value.ends_with('\n');
+
+    if indented {
+        prefix(&node.value, "    ", "")
+    } else {
+        let mut info = node.lang.clone().unwrap_or_default();
+
+        if let Some(meta) = &node.meta {
+            info.push(' ');
+            info.push_str(meta);
+        }
+
+        let marker = if info.contains('`') { '~' } else { '`' };
+        fenced(&node.value, marker, 3, &info)
+    }
+}
+
+/// Serialize a list, using `marker` as its bullet (unordered) or delimiter
+/// (ordered).
+fn list_value(state: &mut State, node: &Node, marker: char) -> String {
+    if let Node::List(list) = node {
+        let mut results = vec![];
+
+        for (number, child) in (list.start.unwrap_or(1)..).zip(list.children.iter()) {
+            let bullet = if list.ordered {
+                format!("{}{}", number, marker)
+            } else {
+                marker.to_string()
+            };
+            results.push(list_item(state, child, &bullet));
+        }
+
+        results.join(if list.spread { "\n\n" } else { "\n" })
+    } else {
+        block(state, node)
+    }
+}
+
+/// Serialize a list item, with `bullet` as its marker.
+fn list_item(state: &mut State, node: &Node, bullet: &str) -> String {
+    if let Node::ListItem(item) = node {
+        let mut value = flow(
+            state,
+            &item.children,
+            if item.spread { "\n\n" } else { "\n" },
+        );
+
+        // A thematic break made with asterisks, directly after a `*`, would
+        // form one thematic break.
+        if bullet == "*" && matches!(item.children.first(), Some(Node::ThematicBreak(_))) {
+            value.replace_range(0..3, "___");
+        }
+
+        if let Some(checked) = item.checked {
+            let check = if checked { "[x]" } else { "[ ]" };
+            value = if value.is_empty() {
+                check.into()
+            } else {
+                format!("{} {}", check, value)
+            };
+        }
+
+        if value.is_empty() {
+            bullet.into()
+        } else {
+            let size = bullet.len() + 1;
+            format!("{} {}", bullet, indent(&value, &" ".repeat(size)))
+        }
+    } else {
+        block(state, node)
+    }
+}
+
+/// Serialize a heading.
+fn heading(state: &mut State, children: &[Node], depth: u8) -> String {
+    let mut value = phrasing(state, children);
+
+    // Line endings are only possible in setext headings.
+    if value.contains('\n') {
+        if depth < 3 {
+            let underline = if depth == 1 { "===" } else { "---" };
+            return format!("{}\n{}", value, underline);
+        }
+
+        value = value.replace('\n', " ");
+    }
+
+    if value.is_empty() {
+        return "#".repeat(depth.into());
+    }
+
+    // Escape a final sequence, as it would otherwise be seen as a closing
+    // sequence.
+    let bytes = value.as_bytes();
+    let mut start = bytes.len();
+
+    while start > 0 && bytes[start - 1] == b'#' {
+        start -= 1;
+    }
+
+    if start < bytes.len() && (start == 0 || matches!(bytes[start - 1], b' ' | b'\t')) {
+        value.insert(start, '\\');
+    }
+
+    format!("{} {}", "#".repeat(depth.into()), value)
+}
+
+/// Serialize a GFM table.
+fn table(state: &mut State, node: &Table) -> String {
+    let was_in_table = state.in_table;
+    state.in_table = true;
+    let mut rows = vec![];
+    // Cells past the delimiter row are dropped when parsing, so that row
+    // defines the number of columns, if known.
+    let mut size = node.align.len();
+
+    for child in &node.children {
+        let mut row = vec![];
+        cells(state, child, &mut row);
+        if node.align.is_empty() {
+            size = size.max(row.len());
+        }
+        rows.push(row);
+    }
+
+    state.in_table = was_in_table;
+
+    let mut lines = vec![];
+    let mut index = 0;
+
+    while index < rows.len() {
+        let row = &mut rows[index];
+
+        row.resize(size, String::new());
+
+        lines.push(format!("| {} |", row.join(" | ")));
+
+        if index == 0 {
+            let mut delimiters = vec![];
+
+            while delimiters.len() < size {
+                delimiters.push(match node.align.get(delimiters.len()) {
+                    Some(AlignKind::Left) => ":--",
+                    Some(AlignKind::Right) => "--:",
+                    Some(AlignKind::Center) => ":-:",
+                    _ => "---",
+                });
+            }
+
+            lines.push(format!("| {} |", delimiters.join(" | ")));
+        }
+
+        index += 1;
+    }
+
+    lines.join("\n")
+}
+
+/// Serialize the cells of a table row (or a cell) into `row`.
+fn cells(state: &mut State, node: &Node, row: &mut Vec<String>) {
+    match node {
+        Node::TableRow(x) => {
+            for child in &x.children {
+                cells(state, child, row);
+            }
+        }
+        Node::TableCell(x) => row.push(phrasing(state, &x.children)),
+        _ => row.push(block(state, node)),
+    }
+}
+
+/// Serialize phrasing content.
+fn phrasing(state: &mut State, children: &[Node]) -> String {
+    let mut buf = String::new();
+    inlines(state, children, &mut buf);
+    buf
+}
+
+/// Serialize phrasing content, into `buf`.
+fn inlines(state: &mut State, children: &[Node], buf: &mut String) {
+    let mut index = 0;
+
+    while index < children.len() {
+        inline(state, &children[index], children.get(index + 1), buf);
+        index += 1;
+    }
+}
+
+/// Serialize a node as phrasing content, into `buf`.
+///
+/// Whether text is at the start of a line is checked in `buf`, so it must
+/// start at the start of a line.
+fn inline(state: &mut State, node: &Node, next: Option<&Node>, buf: &mut String) {
+    match node {
+        Node::Text(x) => text(state, &x.value, buf),
+        Node::Emphasis(x) => {
+            let marker = attention_marker(state.options.emphasis, buf, next);
+            buf.push(marker);
+            inlines(state, &x.children, buf);
+            buf.push(marker);
+        }
+        Node::Strong(x) => {
+            // `_` does not work in words, and `*` after `*` is fine.
+            let marker = if intraword(buf, next) || buf.ends_with('*') {
+                '*'
+            } else {
+                state.options.strong
+            };
+            buf.push(marker);
+            buf.push(marker);
+            inlines(state, &x.children, buf);
+            buf.push(marker);
+            buf.push(marker);
+        }
+        Node::Delete(x) => {
+            buf.push_str("~~");
+            inlines(state, &x.children, buf);
+            buf.push_str("~~");
+        }
+        Node::InlineCode(x) => {
+            let value = raw_text(&x.value, '`');
+            // Pipes in code must be escaped in tables too.
+            if state.in_table {
+                buf.push_str(&value.replace('|', "\\|"));
+            } else {
+                buf.push_str(&value);
+            }
+        }
+        Node::InlineMath(x) => buf.push_str(&raw_text(&x.value, '$')),
+        Node::Break(_) => buf.push_str("\\\n"),
+        Node::Html(x) => buf.push_str(&x.value),
+        Node::Link(x) => {
+            if is_autolink(node) {
+                buf.push('<');
+                buf.push_str(&node.to_string());
+                buf.push('>');
+            } else {
+                escape_exclamation(buf);
+                buf.push('[');
+                inlines(state, &x.children, buf);
+                buf.push_str("](");
+                buf.push_str(&resource(&x.url, x.title.as_ref()));
+                buf.push(')');
+            }
+        }
+        Node::LinkReference(x) => {
+            escape_exclamation(buf);
+            buf.push('[');
+            let start = buf.len();
+            inlines(state, &x.children, buf);
+            let text = buf[start..].to_string();
+            buf.push(']');
+            reference(
+                x.reference_kind,
+                x.label.as_ref(),
+                &x.identifier,
+                &text,
+                buf,
+            );
+        }
+        Node::Image(x) => {
+            buf.push_str("![");
+            text(state, &x.alt, buf);
+            buf.push_str("](");
+            buf.push_str(&resource(&x.url, x.title.as_ref()));
+            buf.push(')');
+        }
+        Node::ImageReference(x) => {
+            buf.push_str("![");
+            let start = buf.len();
+            text(state, &x.alt, buf);
+            let text = buf[start..].to_string();
+            buf.push(']');
+            reference(
+                x.reference_kind,
+                x.label.as_ref(),
+                &x.identifier,
+                &text,
+                buf,
+            );
+        }
+        Node::FootnoteReference(x) => {
+            buf.push_str("[^");
+            buf.push_str(&label(x.label.as_ref(), &x.identifier));
+            buf.push(']');
+        }
+        Node::MdxTextExpression(x) => {
+            buf.push('{');
+            buf.push_str(&x.value);
+            buf.push('}');
+        }
+        Node::MdxJsxTextElement(x) => {
+            buf.push_str(&jsx_open(
+                x.name.as_ref(),
+                &x.attributes,
+                x.children.is_empty(),
+            ));
+
+            if !x.children.is_empty() {
+                inlines(state, &x.children, buf);
+                buf.push_str("</");
+                buf.push_str(x.name.as_ref().map_or("", |d| d));
+                buf.push('>');
+            }
+        }
+        _ => buf.push_str(&block(state, node)),
+    }
+}
+
+/// Serialize text, escaping characters that would otherwise be seen as
+/// markdown syntax, into `buf`.
+fn text(state: &State, value: &str, buf: &mut String) {
+    let chars = value.chars().collect::<Vec<_>>();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let char = chars[index];
+        let at_line_start = buf.is_empty() || buf.ends_with('\n');
+
+        if at_line_start {
+            match char {
+                // Blank lines end paragraphs.
+                '\n' => {
+                    buf.push_str("&#xA;");
+                    index += 1;
+                    continue;
+                }
+                '\r' => {
+                    buf.push_str("&#xD;");
+                    index += 1;
+                    continue;
+                }
+                // Whitespace at the start of a line is ignored.
+                ' ' => {
+                    buf.push_str("&#x20;");
+                    index += 1;
+                    continue;
+                }
+                '\t' => {
+                    buf.push_str("&#x9;");
+                    index += 1;
+                    continue;
+                }
+                // Headings (atx), block quotes, list items, thematic breaks,
+                // and setext heading underlines.
+                '#' | '>' | '-' | '+' | '=' => {
+                    buf.push('\\');
+                    buf.push(char);
+                    index += 1;
+                    continue;
+                }
+                // Ordered list items.
+                '0'..='9' => {
+                    let start = index;
+
+                    while index < chars.len() && chars[index].is_ascii_digit() {
+                        index += 1;
+                    }
+
+                    buf.extend(&chars[start..index]);
+
+                    if index < chars.len() && matches!(chars[index], '.' | ')') {
+                        buf.push('\\');
+                        buf.push(chars[index]);
+                        index += 1;
+                    }
+
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        match char {
+            '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '~' => buf.push('\\'),
+            '|' if state.in_table => buf.push('\\'),
+            // Character references.
+            '&' if matches!(chars.get(index + 1), Some(c) if c.is_ascii_alphanumeric() || *c == '#') =>
+            {
+                buf.push('\\');
+            }
+            _ => {}
+        }
+
+        buf.push(char);
+        index += 1;
+    }
+}
+
+/// Escape a final `!` in `buf`, as it would otherwise turn a following link
+/// into an image.
+fn escape_exclamation(buf: &mut String) {
+    if buf.ends_with('!') && !buf.ends_with("\\!") {
+        buf.pop();
+        buf.push_str("\\!");
+    }
+}
+
+/// Serialize the reference part of a link or image reference, into `buf`.
+///
+/// `text` is the serialized text of the reference, which is used as the
+/// label of collapsed and shortcut references.
+/// When that text does not match `identifier`, a full reference is used
+/// instead.
+fn reference(
+    kind: ReferenceKind,
+    label_value: Option<&String>,
+    identifier: &str,
+    text: &str,
+    buf: &mut String,
+) {
+    let matches = normalize_identifier(text).to_lowercase() == identifier;
+
+    match kind {
+        ReferenceKind::Collapsed if matches => buf.push_str("[]"),
+        ReferenceKind::Shortcut if matches => {}
+        _ => {
+            buf.push('[');
+            buf.push_str(&label(label_value, identifier));
+            buf.push(']');
+        }
+    }
+}
+
+/// Serialize a label.
+///
+/// Labels in mdast are the text content of the label, so formatting and
+/// escapes are lost, while identifiers are normalized (and lowercased)
+/// source.
+/// The label is used if it matches the identifier, otherwise the identifier
+/// is used.
+fn label(label: Option<&String>, identifier: &str) -> String {
+    if let Some(label) = label {
+        let value = escape(label, &['\\', '[', ']']);
+
+        if normalize_identifier(&value).to_lowercase() == identifier {
+            return value;
+        }
+    }
+
+    identifier.into()
+}
+
+/// Get the marker to use for emphasis.
+///
+/// That’s `marker`, unless the emphasis is in a word, as `_` does not work
+/// there, or unless it directly follows that marker, as two markers would
+/// otherwise form strong.
+fn attention_marker(marker: char, buf: &str, next: Option<&Node>) -> char {
+    let marker = if intraword(buf, next) { '*' } else { marker };
+
+    if buf.ends_with(marker) {
+        if marker == '*' {
+            '_'
+        } else {
+            '*'
+        }
+    } else {
+        marker
+    }
+}
+
+/// Check if attention is in a word: whether `buf` ends in, or `next` starts
+/// with, an alphanumeric.
+fn intraword(buf: &str, next: Option<&Node>) -> bool {
+    matches!(buf.chars().last(), Some(char) if char.is_alphanumeric())
+        || matches!(next, Some(Node::Text(text)) if matches!(text.value.chars().next(), Some(char) if char.is_alphanumeric()))
+}
+
+/// Serialize the resource (destination and title) of a link or image.
+fn resource(url: &str, title: Option<&String>) -> String {
+    let mut value = if url.is_empty() && title.is_some() {
+        "<>".into()
+    } else if url.is_empty() {
+        String::new()
+    } else {
+        destination(url)
+    };
+
+    if let Some(title) = title {
+        value.push(' ');
+        value.push_str(&title_value(title));
+    }
+
+    value
+}
+
+/// Serialize a destination.
+fn destination(url: &str) -> String {
+    let mut depth = 0;
+    let mut balanced = true;
+
+    for char in url.chars() {
+        if char == '(' {
+            depth += 1;
+        } else if char == ')' {
+            if depth == 0 {
+                balanced = false;
+            } else {
+                depth -= 1;
+            }
+        }
+    }
+
+    let raw = url.is_empty()
+        || !balanced
+        || depth > 0
+        || url.starts_with('<')
+        || url
+            .chars()
+            .any(|char| char.is_ascii_control() || char == ' ');
+
+    if raw {
+        format!("<{}>", escape(url, &['\\', '<', '>']))
+    } else {
+        escape(url, &['\\'])
+    }
+}
+
+/// Serialize a title.
+fn title_value(title: &str) -> String {
+    format!("\"{}\"", escape(title, &['\\', '"']))
+}
+
+/// Escape `chars` (and character references) in `value` with backslashes.
+fn escape(value: &str, chars: &[char]) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut iter = value.chars().peekable();
+
+    while let Some(char) = iter.next() {
+        if chars.contains(&char)
+            || (char == '&'
+                && matches!(iter.peek(), Some(c) if c.is_ascii_alphanumeric() || *c == '#'))
+        {
+            result.push('\\');
+        }
+
+        result.push(char);
+    }
+
+    result
+}
+
+/// Serialize code (text) or math (text), with `marker`.
+fn raw_text(value: &str, marker: char) -> String {
+    let mut size = 1;
+
+    while has_sequence(value, marker, size) {
+        size += 1;
+    }
+
+    let fence = marker.to_string().repeat(size);
+    let pad = value.starts_with(marker) || value.ends_with(marker);
+
+    if pad {
+        format!("{} {} {}", fence, value, fence)
+    } else {
+        format!("{}{}{}", fence, value, fence)
+    }
+}
+
+/// Serialize code (flow) or math (flow), with fences made of `marker`.
+fn fenced(value: &str, marker: char, min: usize, info: &str) -> String {
+    let mut size = min;
+
+    for line in value.lines() {
+        let count = line
+            .trim_start()
+            .chars()
+            .take_while(|d| *d == marker)
+            .count();
+
+        if count >= size {
+            size = count + 1;
+        }
+    }
+
+    let fence = marker.to_string().repeat(size);
+
+    if value.is_empty() {
+        format!("{}{}\n{}", fence, info, fence)
+    } else {
+        format!("{}{}\n{}\n{}", fence, info, value, fence)
+    }
+}
+
+/// Serialize the opening tag of an MDX JSX element.
+fn jsx_open(name: Option<&String>, attributes: &[AttributeContent], empty: bool) -> String {
+    let mut value = String::from("<");
+
+    if let Some(name) = name {
+        value.push_str(name);
+    }
+
+    for attribute in attributes {
+        value.push(' ');
+
+        match attribute {
+            AttributeContent::Expression { value: inner, .. } => {
+                value.push('{');
+                value.push_str(inner);
+                value.push('}');
+            }
+            AttributeContent::Property(property) => {
+                value.push_str(&property.name);
+
+                match &property.value {
+                    Some(AttributeValue::Literal(inner)) => {
+                        value.push_str("=\"");
+                        value.push_str(&inner.replace('"', "&quot;"));
+                        value.push('"');
+                    }
+                    Some(AttributeValue::Expression(inner)) => {
+                        value.push_str("={");
+                        value.push_str(&inner.value);
+                        value.push('}');
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+
+    if empty {
+        value.push_str(if name.is_some() { " />" } else { "></>" });
+    } else {
+        value.push('>');
+    }
+
+    value
+}
+
+/// Check if `value` contains a sequence of exactly `size` `marker`s.
+fn has_sequence(value: &str, marker: char, size: usize) -> bool {
+    let mut count = 0;
+
+    for char in value.chars() {
+        if char == marker {
+            count += 1;
+        } else {
+            if count == size {
+                return true;
+            }
+
+            count = 0;
+        }
+    }
+
+    count == size
+}
+
+/// Check if a link can be serialized as an autolink.
+fn is_autolink(node: &Node) -> bool {
+    if let Node::Link(link) = node {
+        if link.title.is_some() || link.children.len() != 1 {
+            return false;
+        }
+
+        let value = node.to_string();
+
+        if value != link.url && format!("mailto:{}", value) != link.url {
+            return false;
+        }
+
+        let bytes = value.as_bytes();
+
+        if value.contains(|d: char| d.is_ascii_control() || matches!(d, ' ' | '<' | '>')) {
+            return false;
+        }
+
+        // Email.
+        if value != link.url {
+            return value.contains('@');
+        }
+
+        // Scheme.
+        let mut index = 0;
+
+        while index < bytes.len()
+            && (bytes[index].is_ascii_alphanumeric() || matches!(bytes[index], b'+' | b'-' | b'.'))
+        {
+            index += 1;
+        }
+
+        (2..=32).contains(&index)
+            && bytes[0].is_ascii_alphabetic()
+            && index < bytes.len()
+            && bytes[index] == b':'
+    } else {
+        false
+    }
+}
+
+/// Check if a node is phrasing content.
+fn is_phrasing(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Text(_)
+            | Node::Emphasis(_)
+            | Node::Strong(_)
+            | Node::Delete(_)
+            | Node::InlineCode(_)
+            | Node::InlineMath(_)
+            | Node::Break(_)
+            | Node::Link(_)
+            | Node::LinkReference(_)
+            | Node::Image(_)
+            | Node::ImageReference(_)
+            | Node::FootnoteReference(_)
+            | Node::MdxTextExpression(_)
+            | Node::MdxJsxTextElement(_)
+    )
+}
+
+/// Get the marker to use for a list next to a list using `marker`.
+fn other_marker(marker: char) -> char {
+    match marker {
+        '.' => ')',
+        ')' => '.',
+        '*' => '-',
+        _ => '*',
+    }
+}
+
+/// Prefix each line in `value` with `start`, or `empty` for empty lines.
+fn prefix(value: &str, start: &str, empty: &str) -> String {
+    value
+        .split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                empty.to_string()
+            } else {
+                format!("{}{}", start, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Indent each line in `value` with `start`, except for the first line and
+/// empty lines.
+fn indent(value: &str, start: &str) -> String {
+    match value.split_once('\n') {
+        Some((first, rest)) => format!("{}\n{}", first, prefix(rest, start, "")),
+        None => value.into(),
+    }
+}
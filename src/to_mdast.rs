@@ -3,18 +3,19 @@
 use crate::event::{Event, Kind, Name, Point as EventPoint};
 use crate::mdast::{
     AttributeContent, AttributeValue, AttributeValueExpression, BlockQuote, Break, Code,
-    Definition, Delete, Emphasis, FootnoteDefinition, FootnoteReference, Heading, Html, Image,
-    ImageReference, InlineCode, InlineMath, Link, LinkReference, List, ListItem, Math,
-    MdxFlowExpression, MdxJsxAttribute, MdxJsxFlowElement, MdxJsxTextElement, MdxTextExpression,
-    MdxjsEsm, Node, Paragraph, ReferenceKind, Root, Strong, Table, TableCell, TableRow, Text,
-    ThematicBreak, Toml, Yaml,
+    Definition, DefinitionList, DefinitionListDescription, DefinitionListTerm, Delete, Emphasis,
+    FootnoteDefinition, FootnoteReference, Heading, Html, Image, ImageReference, InlineCode,
+    InlineMath, Link, LinkReference, List, ListItem, Math, MdxFlowExpression, MdxJsxAttribute,
+    MdxJsxFlowElement, MdxJsxTextElement, MdxTextExpression, MdxjsEsm, Node, Paragraph,
+    ReferenceKind, Root, Strong, Table, TableCell, TableRow, Text, ThematicBreak, Toml, Yaml,
 };
 use crate::unist::{Point, Position};
 use crate::util::{
     character_reference::{
         decode as decode_character_reference, parse as parse_character_reference,
     },
-    infer::{gfm_table_align, list_item_loose, list_loose},
+    emoji::decode as decode_emoji,
+    infer::{definition_list_description_loose, gfm_table_align, list_item_loose, list_loose},
     mdx_collect::{collect, Result as CollectResult},
     normalize_identifier::normalize_identifier,
     slice::{Position as SlicePosition, Slice},
@@ -87,6 +88,8 @@ struct CompileContext<'a> {
     events: &'a [Event],
     /// List of bytes.
     bytes: &'a [u8],
+    /// Extra emoji shortcodes.
+    emoji_shortcodes: &'a [(String, String)],
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     character_reference_marker: u8,
@@ -106,7 +109,11 @@ struct CompileContext<'a> {
 
 impl<'a> CompileContext<'a> {
     /// Create a new compile context.
-    fn new(events: &'a [Event], bytes: &'a [u8]) -> CompileContext<'a> {
+    fn new(
+        events: &'a [Event],
+        bytes: &'a [u8],
+        emoji_shortcodes: &'a [(String, String)],
+    ) -> CompileContext<'a> {
         let tree = Node::Root(Root {
             children: vec![],
             position: Some(Position {
@@ -126,6 +133,7 @@ impl<'a> CompileContext<'a> {
         CompileContext {
             events,
             bytes,
+            emoji_shortcodes,
             character_reference_marker: 0,
             gfm_table_inside: false,
             hard_break_after: false,
@@ -222,8 +230,12 @@ impl<'a> CompileContext<'a> {
 }
 
 /// Turn events and bytes into a syntax tree.
-pub fn compile(events: &[Event], bytes: &[u8]) -> Result<Node, String> {
-    let mut context = CompileContext::new(events, bytes);
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    emoji_shortcodes: &[(String, String)],
+) -> Result<Node, String> {
+    let mut context = CompileContext::new(events, bytes, emoji_shortcodes);
 
     let mut index = 0;
     while index < events.len() {
@@ -258,13 +270,15 @@ fn handle(context: &mut CompileContext, index: usize) -> Result<(), String> {
 /// Handle [`Enter`][Kind::Enter].
 fn enter(context: &mut CompileContext) -> Result<(), String> {
     match context.events[context.index].name {
-        Name::AutolinkEmail
+        Name::Abbreviation
+        | Name::AutolinkEmail
         | Name::AutolinkProtocol
         | Name::CharacterEscapeValue
         | Name::CharacterReference
         | Name::CodeFlowChunk
         | Name::CodeTextData
         | Name::Data
+        | Name::Emoji
         | Name::FrontmatterChunk
         | Name::HtmlFlowData
         | Name::HtmlTextData
@@ -280,6 +294,8 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
         | Name::LabelText
         | Name::MathFlowFenceMeta
         | Name::MdxJsxTagAttributeValueLiteral
+        | Name::PercentCommentFlow
+        | Name::PercentCommentText
         | Name::ReferenceString
         | Name::ResourceDestinationString
         | Name::ResourceTitleString => on_enter_buffer(context),
@@ -289,6 +305,9 @@ fn enter(context: &mut CompileContext) -> Result<(), String> {
         Name::CodeIndented => on_enter_code_indented(context),
         Name::CodeText => on_enter_code_text(context),
         Name::Definition => on_enter_definition(context),
+        Name::DefinitionList => on_enter_definition_list(context),
+        Name::DefinitionListDescription => on_enter_definition_list_description(context),
+        Name::DefinitionListTerm => on_enter_definition_list_term(context),
         Name::Emphasis => on_enter_emphasis(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmAutolinkLiteralEmail
@@ -340,6 +359,10 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
         | Name::BlockQuote
         | Name::CharacterReference
         | Name::Definition
+        | Name::DefinitionList
+        | Name::DefinitionListDescription
+        | Name::DefinitionListTerm
+        | Name::Emoji
         | Name::Emphasis
         | Name::GfmFootnoteDefinition
         | Name::GfmStrikethrough
@@ -353,7 +376,8 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
         | Name::ThematicBreak => {
             on_exit(context)?;
         }
-        Name::CharacterEscapeValue
+        Name::Abbreviation
+        | Name::CharacterEscapeValue
         | Name::CodeFlowChunk
         | Name::CodeTextData
         | Name::Data
@@ -365,7 +389,10 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
         | Name::MdxJsxTagAttributeValueLiteralValue => {
             on_exit_data(context)?;
         }
-        Name::MdxJsxTagAttributeExpression | Name::MdxJsxTagAttributeValueExpression => {
+        Name::MdxJsxTagAttributeExpression
+        | Name::MdxJsxTagAttributeValueExpression
+        | Name::PercentCommentFlow
+        | Name::PercentCommentText => {
             on_exit_drop(context);
         }
         Name::AutolinkProtocol => on_exit_autolink_protocol(context)?,
@@ -389,6 +416,7 @@ fn exit(context: &mut CompileContext) -> Result<(), String> {
             on_exit_definition_id(context);
         }
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::EmojiName => on_exit_emoji_name(context),
         Name::Frontmatter => on_exit_frontmatter(context)?,
         Name::GfmAutolinkLiteralEmail
         | Name::GfmAutolinkLiteralMailto
@@ -569,6 +597,33 @@ fn on_enter_definition(context: &mut CompileContext) {
     }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionList`][Name::DefinitionList].
+fn on_enter_definition_list(context: &mut CompileContext) {
+    context.tail_push(Node::DefinitionList(DefinitionList {
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionListDescription`][Name::DefinitionListDescription].
+fn on_enter_definition_list_description(context: &mut CompileContext) {
+    let spread = definition_list_description_loose(context.events, context.index);
+
+    context.tail_push(Node::DefinitionListDescription(DefinitionListDescription {
+        spread,
+        children: vec![],
+        position: None,
+    }));
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`DefinitionListTerm`][Name::DefinitionListTerm].
+fn on_enter_definition_list_term(context: &mut CompileContext) {
+    context.tail_push(Node::DefinitionListTerm(DefinitionListTerm {
+        children: vec![],
+        position: None,
+    }));
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     context.tail_push(Node::Emphasis(Emphasis {
@@ -1132,6 +1187,22 @@ fn on_exit_definition_title_string(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`EmojiName`][Name::EmojiName].
+fn on_exit_emoji_name(context: &mut CompileContext) {
+    let slice = Slice::from_position(
+        context.bytes,
+        &SlicePosition::from_exit_event(context.events, context.index),
+    );
+    let value = decode_emoji(slice.as_str(), context.emoji_shortcodes)
+        .expect("expected to parse only known emoji");
+
+    if let Node::Text(node) = context.tail_mut() {
+        node.value.push_str(value);
+    } else {
+        unreachable!("expected text on stack");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:*, by dropping the current buffer.
 fn on_exit_drop(context: &mut CompileContext) {
     context.resume();
@@ -1296,7 +1367,9 @@ fn on_exit_line_ending(context: &mut CompileContext) -> Result<(), String> {
     // Line ending is a part of nodes that accept phrasing.
     else if matches!(
         context.tail_mut(),
-        Node::Emphasis(_)
+        Node::DefinitionListDescription(_)
+            | Node::DefinitionListTerm(_)
+            | Node::Emphasis(_)
             | Node::Heading(_)
             | Node::Paragraph(_)
             | Node::Strong(_)
@@ -0,0 +1,156 @@
+//! Turn a syntax tree into speech segments.
+//!
+//! Speech segments are a flat list of plain-text utterances, meant for
+//! text-to-speech engines.
+//! Each segment has a hint about what kind of content it came from, so that
+//! an engine can adjust prosody.
+
+use crate::configuration::SpeechOptions;
+use crate::mdast::Node;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Kind of content a [`Segment`][] came from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SegmentKind {
+    /// Heading.
+    ///
+    /// ```markdown
+    /// > | # a
+    ///       ^
+    /// ```
+    Heading,
+    /// Paragraph.
+    ///
+    /// ```markdown
+    /// > | a
+    ///     ^
+    /// ```
+    Paragraph,
+    /// Paragraph in a list item.
+    ///
+    /// ```markdown
+    /// > | * a
+    ///       ^
+    /// ```
+    ListItem,
+    /// Code (flow).
+    ///
+    /// ```markdown
+    /// > | ~~~
+    ///     ^^^
+    /// > | a
+    ///     ^
+    /// > | ~~~
+    ///     ^^^
+    /// ```
+    Code,
+}
+
+/// Plain-text utterance.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Segment {
+    /// Kind of content this came from.
+    pub kind: SegmentKind,
+    /// Plain text to speak.
+    pub value: String,
+}
+
+/// Turn an mdast syntax tree into speech segments.
+pub fn compile(tree: &Node, options: &SpeechOptions) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    visit(tree, options, false, &mut segments);
+    segments
+}
+
+/// Add segments for `node` (and its descendants) to `segments`.
+fn visit(node: &Node, options: &SpeechOptions, in_list_item: bool, segments: &mut Vec<Segment>) {
+    match node {
+        Node::Heading(x) => push(segments, SegmentKind::Heading, phrasing(&x.children)),
+        Node::Paragraph(x) => push(
+            segments,
+            if in_list_item {
+                SegmentKind::ListItem
+            } else {
+                SegmentKind::Paragraph
+            },
+            phrasing(&x.children),
+        ),
+        Node::DefinitionListTerm(x) => {
+            push(segments, SegmentKind::Paragraph, phrasing(&x.children));
+        }
+        Node::DefinitionListDescription(x) => {
+            push(segments, SegmentKind::Paragraph, phrasing(&x.children));
+        }
+        Node::Code(x) => {
+            let value = if options.summarize_code {
+                let lines = x.value.lines().count();
+                format!(
+                    "code block, {} line{}",
+                    lines,
+                    if lines == 1 { "" } else { "s" }
+                )
+            } else {
+                x.value.clone()
+            };
+            push(segments, SegmentKind::Code, value);
+        }
+        Node::TableRow(x) => {
+            let cells = x
+                .children
+                .iter()
+                .map(|cell| cell.children().map_or(String::new(), |x| phrasing(x)))
+                .filter(|cell| !cell.is_empty())
+                .collect::<Vec<_>>();
+            push(segments, SegmentKind::Paragraph, cells.join(", "));
+        }
+        Node::ListItem(x) => {
+            for child in &x.children {
+                visit(child, options, true, segments);
+            }
+        }
+        _ => {
+            if let Some(children) = node.children() {
+                for child in children {
+                    visit(child, options, in_list_item, segments);
+                }
+            }
+        }
+    }
+}
+
+/// Add a segment, if it has text.
+fn push(segments: &mut Vec<Segment>, kind: SegmentKind, value: String) {
+    if !value.is_empty() {
+        segments.push(Segment { kind, value });
+    }
+}
+
+/// Get the plain text of phrasing content, with whitespace collapsed.
+fn phrasing(children: &[Node]) -> String {
+    let mut value = String::new();
+    phrasing_into(children, &mut value);
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Add the plain text of phrasing content to `value`.
+///
+/// Markup (HTML, and the tags of JSX) and expressions are not spoken.
+fn phrasing_into(children: &[Node], value: &mut String) {
+    for child in children {
+        match child {
+            Node::Break(_) => value.push(' '),
+            Node::Html(_) | Node::MdxTextExpression(_) => {}
+            _ => {
+                if let Some(children) = child.children() {
+                    phrasing_into(children, value);
+                } else {
+                    value.push_str(&child.to_string());
+                }
+            }
+        }
+    }
+}
@@ -197,6 +197,11 @@ pub struct TokenizeState<'a> {
     /// Used for GFM task list items.
     pub document_at_first_paragraph_of_list_item: bool,
 
+    /// Index of the next construct to try in `flow_construct_order`.
+    ///
+    /// Used when tokenizing [flow content][crate::construct::flow].
+    pub flow_construct_index: usize,
+
     // Couple of very frequent settings for parsing whitespace.
     pub space_or_tab_eol_content: Option<Content>,
     pub space_or_tab_eol_connect: bool,
@@ -344,6 +349,7 @@ impl<'a> Tokenizer<'a> {
                 document_child_state: None,
                 document_child: None,
                 document_at_first_paragraph_of_list_item: false,
+                flow_construct_index: 0,
                 definitions: vec![],
                 gfm_footnote_definitions: vec![],
                 mdx_last_parse_error: None,
@@ -571,9 +577,17 @@ impl<'a> Tokenizer<'a> {
         self.stack.truncate(previous.stack_len);
     }
 
+    /// Count an attempt or check, if collecting stats.
+    fn count_attempt(&self) {
+        if let Some(count) = &self.parse_state.constructs_attempted {
+            count.set(count.get() + 1);
+        }
+    }
+
     /// Stack an attempt, moving to `ok` on [`State::Ok`][] and `nok` on
     /// [`State::Nok`][], reverting in both cases.
     pub fn check(&mut self, ok: State, nok: State) {
+        self.count_attempt();
         // Always capture (and restore) when checking.
         // No need to capture (and restore) when `nok` is `State::Nok`, because the
         // parent attempt will do it.
@@ -590,6 +604,7 @@ impl<'a> Tokenizer<'a> {
     /// Stack an attempt, moving to `ok` on [`State::Ok`][] and `nok` on
     /// [`State::Nok`][], reverting in the latter case.
     pub fn attempt(&mut self, ok: State, nok: State) {
+        self.count_attempt();
         // Always capture (and restore) when checking.
         // No need to capture (and restore) when `nok` is `State::Nok`, because the
         // parent attempt will do it.
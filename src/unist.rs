@@ -5,8 +5,10 @@
 use alloc::fmt;
 
 /// One place in a source file.
-#[derive(Clone, Eq, PartialEq)]
-#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+///
+/// Points are ordered by where they occur in a source file.
+#[derive(Clone, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     /// 1-indexed integer representing a line in a source file.
     pub line: usize,
@@ -35,7 +37,7 @@ impl fmt::Debug for Point {
 
 /// Location of a node in a source file.
 #[derive(Clone, Eq, PartialEq)]
-#[cfg_attr(feature = "json", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     /// Represents the place of the first character of the parsed source region.
     pub start: Point,
@@ -59,6 +61,29 @@ impl Position {
             end: Point::new(end_line, end_column, end_offset),
         }
     }
+
+    /// Get the source of this position in `value`.
+    ///
+    /// `value` must be the source file that this position is from.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_mdast, unist::Position, ParseOptions};
+    /// # fn main() -> Result<(), String> {
+    ///
+    /// let value = "a *b* c";
+    /// let tree = to_mdast(value, &ParseOptions::default())?;
+    /// let emphasis = &tree.children().unwrap()[0].children().unwrap()[1];
+    ///
+    /// assert_eq!(emphasis.position().unwrap().slice(value), "*b*");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn slice<'a>(&self, value: &'a str) -> &'a str {
+        &value[self.start.offset..self.end.offset]
+    }
 }
 
 impl fmt::Debug for Position {
@@ -89,6 +114,11 @@ mod tests {
             "1:1 (0)",
             "should support `Debug` on unist points"
         );
+
+        assert!(
+            Point::new(1, 2, 1) < Point::new(2, 1, 3),
+            "should support `Ord` on unist points"
+        );
     }
 
     #[test]
@@ -99,5 +129,11 @@ mod tests {
             "1:1-1:3 (0-2)",
             "should support `Debug` on unist positions"
         );
+
+        assert_eq!(
+            Position::new(1, 3, 3, 1, 5, 5).slice("a\u{a0}bc"),
+            "bc",
+            "should support slicing a source file w/ unist positions"
+        );
     }
 }
@@ -0,0 +1,122 @@
+//! Helpers for abbreviations.
+
+use crate::event::{Event, Kind, Name};
+use crate::util::slice::{Position, Slice};
+use alloc::{string::String, vec::Vec};
+
+/// Collect abbreviation definitions.
+///
+/// Turns events into a list of labels and values.
+/// When a label is defined several times, only the first one is kept.
+pub fn collect(events: &[Event], bytes: &[u8]) -> Vec<(String, String)> {
+    let mut abbreviations: Vec<(String, String)> = Vec::new();
+    let mut label = None;
+    let mut value = String::new();
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            match event.name {
+                Name::AbbreviationDefinitionLabel => {
+                    let slice =
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index));
+                    label = Some(String::from(slice.as_str().trim()));
+                }
+                Name::AbbreviationDefinitionValue => {
+                    let slice =
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index));
+                    value = String::from(slice.as_str().trim());
+                }
+                Name::AbbreviationDefinition => {
+                    if let Some(label) = label.take() {
+                        if !label.is_empty() && !abbreviations.iter().any(|d| d.0 == label) {
+                            abbreviations.push((label, core::mem::take(&mut value)));
+                        }
+                    }
+
+                    value.clear();
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    abbreviations
+}
+
+/// Find an abbreviation at the start of `value`.
+///
+/// `after` is the character after `value`, used when an abbreviation ends
+/// where `value` ends.
+/// Returns the size (in bytes) of the longest matching label that is not
+/// followed by a word character.
+pub fn find(value: &str, after: Option<char>, abbreviations: &[(String, String)]) -> Option<usize> {
+    let mut size = 0;
+
+    for (label, _) in abbreviations {
+        if value.starts_with(label.as_str())
+            && !matches!(value[label.len()..].chars().next().or(after), Some(char) if word_char(char))
+            && label.len() > size
+        {
+            size = label.len();
+        }
+    }
+
+    if size == 0 {
+        None
+    } else {
+        Some(size)
+    }
+}
+
+/// Check if a character is a word character, which cannot occur directly
+/// around abbreviations.
+///
+/// That’s alphanumerics and `_`.
+pub fn word_char(char: char) -> bool {
+    char == '_' || char.is_alphanumeric()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn find_abbreviation() {
+        let abbreviations = vec![
+            ("HTML".into(), "Hyper Text Markup Language".into()),
+            ("HTML5".into(), "HTML, version 5".into()),
+        ];
+
+        assert_eq!(
+            find("HTML a", None, &abbreviations),
+            Some(4),
+            "should find an abbreviation"
+        );
+        assert_eq!(
+            find("HTMLs", None, &abbreviations),
+            None,
+            "should not find an abbreviation followed by a word character"
+        );
+        assert_eq!(
+            find("HTML", Some('s'), &abbreviations),
+            None,
+            "should not find an abbreviation followed by a word character after `value`"
+        );
+        assert_eq!(
+            find("HTML5.", None, &abbreviations),
+            Some(5),
+            "should prefer the longest abbreviation"
+        );
+        assert_eq!(
+            find("html", None, &abbreviations),
+            None,
+            "should be case-sensitive"
+        );
+    }
+}
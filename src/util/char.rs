@@ -102,6 +102,48 @@ pub fn classify(char: char) -> Kind {
     }
 }
 
+/// Check whether a `char` is a CJK (Chinese, Japanese, or Korean)
+/// character.
+///
+/// Used for attention (emphasis, strong), which can be configured to be more
+/// lenient next to such characters, as they are not separated by whitespace.
+///
+/// ## References
+///
+/// *   [`markdown-cjk-friendly`](https://github.com/tats-u/markdown-cjk-friendly)
+pub fn is_cjk(char: char) -> bool {
+    matches!(
+        char as u32,
+        // Hangul Jamo.
+        0x1100..=0x11FF
+            // CJK Radicals Supplement, Kangxi Radicals.
+            | 0x2E80..=0x2FDF
+            // Ideographic Description Characters, CJK Symbols and Punctuation,
+            // Hiragana, Katakana, Bopomofo, Hangul Compatibility Jamo, Kanbun,
+            // CJK Strokes, Enclosed CJK Letters and Months, CJK Compatibility,
+            // CJK Unified Ideographs (and Extension A), Yi.
+            | 0x2FF0..=0xA4CF
+            // Hangul Jamo Extended-A.
+            | 0xA960..=0xA97F
+            // Hangul Syllables, Hangul Jamo Extended-B.
+            | 0xAC00..=0xD7FF
+            // CJK Compatibility Ideographs.
+            | 0xF900..=0xFAFF
+            // Vertical Forms.
+            | 0xFE10..=0xFE1F
+            // CJK Compatibility Forms, Small Form Variants.
+            | 0xFE30..=0xFE6F
+            // Halfwidth and Fullwidth Forms.
+            | 0xFF00..=0xFFEF
+            // Kana Extended-B, Kana Supplement, Kana Extended-A, Small Kana
+            // Extension.
+            | 0x1AFF0..=0x1B16F
+            // CJK Unified Ideographs Extension B and later, CJK Compatibility
+            // Ideographs Supplement.
+            | 0x20000..=0x3FFFD
+    )
+}
+
 /// Like [`classify`], but supports eof as whitespace.
 pub fn classify_opt(char_opt: Option<char>) -> Kind {
     char_opt.map_or(Kind::Whitespace, classify)
@@ -172,9 +214,37 @@ mod tests {
             "should classify punctuation"
         );
 
+        assert_eq!(
+            classify('\u{A0}'),
+            Kind::Whitespace,
+            "should classify unicode whitespace (no-break space)"
+        );
+
+        assert_eq!(
+            classify('\u{2003}'),
+            Kind::Whitespace,
+            "should classify unicode whitespace (em space)"
+        );
+
+        assert_eq!(
+            classify('、'),
+            Kind::Punctuation,
+            "should classify unicode punctuation"
+        );
+
         assert_eq!(classify('a'), Kind::Other, "should classify other");
     }
 
+    #[test]
+    fn test_is_cjk() {
+        assert!(is_cjk('中'), "should support han");
+        assert!(is_cjk('こ'), "should support hiragana");
+        assert!(is_cjk('한'), "should support hangul");
+        assert!(is_cjk('：'), "should support fullwidth punctuation");
+        assert!(!is_cjk('a'), "should not support latin");
+        assert!(!is_cjk('😀'), "should not support emoji");
+    }
+
     #[test]
     fn test_format_opt() {
         assert_eq!(
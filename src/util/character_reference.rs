@@ -54,8 +54,7 @@ pub fn decode_named(value: &str, html5: bool) -> Option<String> {
 ///
 /// This returns a `String` form of the associated character or a replacement
 /// character for C0 control characters (except for ASCII whitespace), C1
-/// control characters, lone surrogates, noncharacters, and out of range
-/// characters.
+/// control characters, lone surrogates, and out of range characters.
 ///
 /// ## Examples
 ///
@@ -67,27 +66,25 @@ pub fn decode_named(value: &str, html5: bool) -> Option<String> {
 /// assert_eq!(decode_numeric("0", 10), "�"); // Not allowed.
 /// ```
 ///
-/// ## Panics
-///
-/// This function panics if a invalid string or an out of bounds valid string
-/// is given.
-/// It is expected that figuring out whether a number is allowed is handled in
-/// the parser.
-/// When `markdown-rs` is used, this function never panics.
+/// Numbers that are invalid or too big (such as `110000` in hexadecimal),
+/// lone surrogates, and disallowed control characters all result in the
+/// replacement character.
 ///
 /// ## References
 ///
 /// *   [`micromark-util-decode-numeric-character-reference` in `micromark`](https://github.com/micromark/micromark/tree/main/packages/micromark-util-decode-numeric-character-reference)
 /// *   [*§ 2.5 Entity and numeric character references* in `CommonMark`](https://spec.commonmark.org/0.30/#entity-and-numeric-character-references)
 pub fn decode_numeric(value: &str, radix: u32) -> String {
-    if let Some(char) = char::from_u32(u32::from_str_radix(value, radix).unwrap()) {
+    if let Some(char) = u32::from_str_radix(value, radix)
+        .ok()
+        .and_then(char::from_u32)
+    {
         if !matches!(char,
             // C0 except for HT, LF, FF, CR, space
             '\0'..='\u{08}' | '\u{0B}' | '\u{0E}'..='\u{1F}' |
             // Control character (DEL) of c0, and C1 controls.
             '\u{7F}'..='\u{9F}'
-            // Lone surrogates, noncharacters, and out of range are handled by
-            // Rust.
+            // Lone surrogates and out of range are handled by Rust.
         ) {
             return char.into();
         }
@@ -8,8 +8,9 @@
 //!
 //! Some constants are instead lists of things, such as the list of tag names
 //! considered in the **raw** production of [HTML (flow)][html_flow]
-//! ([`HTML_RAW_NAMES`][]), or the list of named character references
-//! ([`CHARACTER_REFERENCES`][]).
+//! ([`HTML_RAW_NAMES`][]), the list of named character references
+//! ([`CHARACTER_REFERENCES`][]), or the list of emoji shortcodes
+//! ([`EMOJI`][]).
 //!
 //! [raw_flow]: crate::construct::raw_flow
 //! [heading_atx]: crate::construct::heading_atx
@@ -124,16 +125,16 @@ pub const HEADING_ATX_OPENING_FENCE_SIZE_MAX: usize = 6;
 ///
 /// Tag names not on this list result in the **complete** production.
 ///
-/// > 👉 **Note**: `source` was removed on `main` of the `CommonMark` spec and
-/// > is slated to be released in `CommonMark@0.31`.
+/// > 👉 **Note**: `source` was removed and `search` was added in
+/// > `CommonMark@0.31`.
 ///
 /// ## References
 ///
-/// *   [*§ 4.6 HTML blocks* in `CommonMark`](https://spec.commonmark.org/0.30/#html-blocks)
+/// *   [*§ 4.6 HTML blocks* in `CommonMark`](https://spec.commonmark.org/0.31.2/#html-blocks)
 /// *   [*Remove source element as HTML block start condition* as `commonmark/commonmark-spec#710`](https://github.com/commonmark/commonmark-spec/pull/710)
 ///
 /// [html_flow]: crate::construct::html_flow
-pub const HTML_BLOCK_NAMES: [&str; 61] = [
+pub const HTML_BLOCK_NAMES: [&str; 62] = [
     "address",
     "article",
     "aside",
@@ -183,6 +184,7 @@ pub const HTML_BLOCK_NAMES: [&str; 61] = [
     "option",
     "p",
     "param",
+    "search",
     "section",
     "summary",
     "table",
@@ -2701,6 +2703,149 @@ pub const CHARACTER_REFERENCES_HTML_4: [(&str, &str); 252] = [
     ("zwnj", "‌"),
 ];
 
+/// List of shortcodes and values that form [emoji][].
+///
+/// This list is sensitive to casing.
+/// It is a small subset of the shortcodes supported on GitHub, for the most
+/// common emoji.
+/// Pass [`emoji_shortcodes`][emoji_shortcodes] to support more.
+///
+/// ## References
+///
+/// *   [`github/gemoji`](https://github.com/github/gemoji)
+///
+/// [emoji]: crate::construct::emoji
+/// [emoji_shortcodes]: crate::ParseOptions::emoji_shortcodes
+pub const EMOJI: [(&str, &str); 127] = [
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("1234", "🔢"),
+    ("angry", "😠"),
+    ("apple", "🍎"),
+    ("art", "🎨"),
+    ("astonished", "😲"),
+    ("baby", "👶"),
+    ("balloon", "🎈"),
+    ("bang", "❗"),
+    ("beer", "🍺"),
+    ("beers", "🍻"),
+    ("bell", "🔔"),
+    ("birthday", "🎂"),
+    ("blush", "😊"),
+    ("bomb", "💣"),
+    ("book", "📖"),
+    ("books", "📚"),
+    ("boom", "💥"),
+    ("broken_heart", "💔"),
+    ("bug", "🐛"),
+    ("bulb", "💡"),
+    ("cake", "🍰"),
+    ("calendar", "📆"),
+    ("camera", "📷"),
+    ("cat", "🐱"),
+    ("chart_with_upwards_trend", "📈"),
+    ("clap", "👏"),
+    ("clock1", "🕐"),
+    ("cloud", "☁\u{fe0f}"),
+    ("coffee", "☕"),
+    ("computer", "💻"),
+    ("confused", "😕"),
+    ("construction", "🚧"),
+    ("cool", "🆒"),
+    ("cry", "😢"),
+    ("dart", "🎯"),
+    ("dizzy", "💫"),
+    ("dog", "🐶"),
+    ("eyes", "👀"),
+    ("fire", "🔥"),
+    ("flushed", "😳"),
+    ("frowning", "😦"),
+    ("gem", "💎"),
+    ("ghost", "👻"),
+    ("gift", "🎁"),
+    ("grey_question", "❔"),
+    ("grimacing", "😬"),
+    ("grin", "😁"),
+    ("grinning", "😀"),
+    ("hammer", "🔨"),
+    ("heart", "❤\u{fe0f}"),
+    ("heart_eyes", "😍"),
+    ("heavy_check_mark", "✔\u{fe0f}"),
+    ("hourglass", "⌛"),
+    ("hugs", "🤗"),
+    ("hushed", "😯"),
+    ("innocent", "😇"),
+    ("joy", "😂"),
+    ("key", "🔑"),
+    ("kiss", "💋"),
+    ("kissing", "😗"),
+    ("laughing", "😆"),
+    ("link", "🔗"),
+    ("lock", "🔒"),
+    ("mag", "🔍"),
+    ("memo", "📝"),
+    ("moon", "🌔"),
+    ("muscle", "💪"),
+    ("neutral_face", "😐"),
+    ("no_entry", "⛔"),
+    ("ok", "🆗"),
+    ("ok_hand", "👌"),
+    ("open_mouth", "😮"),
+    ("package", "📦"),
+    ("pencil2", "✏\u{fe0f}"),
+    ("pensive", "😔"),
+    ("point_down", "👇"),
+    ("point_left", "👈"),
+    ("point_right", "👉"),
+    ("point_up_2", "👆"),
+    ("pray", "🙏"),
+    ("pushpin", "📌"),
+    ("question", "❓"),
+    ("rage", "😡"),
+    ("raised_hands", "🙌"),
+    ("recycle", "♻\u{fe0f}"),
+    ("relieved", "😌"),
+    ("rocket", "🚀"),
+    ("rofl", "🤣"),
+    ("rose", "🌹"),
+    ("scream", "😱"),
+    ("see_no_evil", "🙈"),
+    ("sleeping", "😴"),
+    ("sleepy", "😪"),
+    ("slightly_smiling_face", "🙂"),
+    ("smile", "😄"),
+    ("smiley", "😃"),
+    ("smirk", "😏"),
+    ("sob", "😭"),
+    ("sparkles", "✨"),
+    ("star", "⭐"),
+    ("stuck_out_tongue", "😛"),
+    ("stuck_out_tongue_winking_eye", "😜"),
+    ("sunglasses", "😎"),
+    ("sunny", "☀\u{fe0f}"),
+    ("sweat", "😓"),
+    ("sweat_smile", "😅"),
+    ("tada", "🎉"),
+    ("thinking", "🤔"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("tired_face", "😫"),
+    ("trophy", "🏆"),
+    ("unamused", "😒"),
+    ("upside_down_face", "🙃"),
+    ("warning", "⚠\u{fe0f}"),
+    ("wave", "👋"),
+    ("white_check_mark", "✅"),
+    ("wink", "😉"),
+    ("worried", "😟"),
+    ("wrench", "🔧"),
+    ("x", "❌"),
+    ("yum", "😋"),
+    ("zap", "⚡"),
+    ("zzz", "💤"),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
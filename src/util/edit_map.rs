@@ -57,8 +57,10 @@ fn shift_links(events: &mut [Event], jumps: &[(usize, usize, usize)]) {
 /// Tracks a bunch of edits.
 #[derive(Debug)]
 pub struct EditMap {
-    /// Record of changes.
-    map: Vec<(usize, usize, Vec<Event>)>,
+    /// Record of changes, in the order they were made: where, how many events
+    /// to remove, which events to add, and whether to add them before
+    /// existing additions.
+    map: Vec<(usize, usize, Vec<Event>, bool)>,
 }
 
 impl EditMap {
@@ -76,20 +78,40 @@ impl EditMap {
     }
     /// Done, change the events.
     pub fn consume(&mut self, events: &mut Vec<Event>) {
-        self.map
-            .sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-
         if self.map.is_empty() {
             return;
         }
 
+        // Sort by where changes happen, keeping the order they were made in,
+        // and merge changes at the same place.
+        // Adding them cheaply and sorting once here prevents quadratic
+        // behavior when there are many changes.
+        self.map.sort_by_key(|d| d.0);
+        let mut edits: Vec<(usize, usize, Vec<Event>)> = Vec::with_capacity(self.map.len());
+
+        for (at, remove, mut add, before) in self.map.split_off(0) {
+            match edits.last_mut() {
+                Some(last) if last.0 == at => {
+                    last.1 += remove;
+
+                    if before {
+                        add.append(&mut last.2);
+                        last.2 = add;
+                    } else {
+                        last.2.append(&mut add);
+                    }
+                }
+                _ => edits.push((at, remove, add)),
+            }
+        }
+
         // Calculate jumps: where items in the current list move to.
-        let mut jumps = Vec::with_capacity(self.map.len());
+        let mut jumps = Vec::with_capacity(edits.len());
         let mut index = 0;
         let mut add_acc = 0;
         let mut remove_acc = 0;
-        while index < self.map.len() {
-            let (at, remove, add) = &self.map[index];
+        while index < edits.len() {
+            let (at, remove, add) = &edits[index];
             remove_acc += remove;
             add_acc += add.len();
             jumps.push((*at, remove_acc, add_acc));
@@ -99,13 +121,13 @@ impl EditMap {
         shift_links(events, &jumps);
 
         let len_before = events.len();
-        let mut index = self.map.len();
+        let mut index = edits.len();
         let mut vecs = Vec::with_capacity(index * 2 + 1);
         while index > 0 {
             index -= 1;
-            vecs.push(events.split_off(self.map[index].0 + self.map[index].1));
-            vecs.push(self.map[index].2.split_off(0));
-            events.truncate(self.map[index].0);
+            vecs.push(events.split_off(edits[index].0 + edits[index].1));
+            vecs.push(edits[index].2.split_off(0));
+            events.truncate(edits[index].0);
         }
         vecs.push(events.split_off(0));
 
@@ -114,35 +136,14 @@ impl EditMap {
         while let Some(mut slice) = vecs.pop() {
             events.append(&mut slice);
         }
-
-        self.map.truncate(0);
     }
 }
 
 /// Create an edit.
-fn add_impl(edit_map: &mut EditMap, at: usize, remove: usize, mut add: Vec<Event>, before: bool) {
-    let mut index = 0;
-
+fn add_impl(edit_map: &mut EditMap, at: usize, remove: usize, add: Vec<Event>, before: bool) {
     if remove == 0 && add.is_empty() {
         return;
     }
 
-    while index < edit_map.map.len() {
-        if edit_map.map[index].0 == at {
-            edit_map.map[index].1 += remove;
-
-            if before {
-                add.append(&mut edit_map.map[index].2);
-                edit_map.map[index].2 = add;
-            } else {
-                edit_map.map[index].2.append(&mut add);
-            }
-
-            return;
-        }
-
-        index += 1;
-    }
-
-    edit_map.map.push((at, remove, add));
+    edit_map.map.push((at, remove, add, before));
 }
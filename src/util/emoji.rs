@@ -0,0 +1,90 @@
+//! Helpers for emoji.
+
+use crate::util::constant::EMOJI;
+use alloc::string::String;
+
+/// Decode an emoji shortcode.
+///
+/// Turn the name coming from an emoji shortcode (without the `:`s) into a
+/// string.
+/// This looks the given string up in `custom` first, and then at `0` in the
+/// tuples of [`EMOJI`][], and takes the corresponding value from `1`.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::emoji::decode;
+///
+/// assert_eq!(decode("smile", &[]), Some("😄"));
+/// assert_eq!(decode("shipit", &[]), None);
+/// assert_eq!(decode("shipit", &[("shipit".into(), "🐿️".into())]), Some("🐿️"));
+/// ```
+///
+/// ## References
+///
+/// *   [`github/gemoji`](https://github.com/github/gemoji)
+pub fn decode<'a>(name: &str, custom: &'a [(String, String)]) -> Option<&'a str> {
+    if let Some(d) = custom.iter().find(|d| d.0 == name) {
+        return Some(&d.1);
+    }
+
+    EMOJI.iter().find(|d| d.0 == name).map(|d| d.1)
+}
+
+/// Check if a byte is allowed in the name of an emoji shortcode.
+///
+/// That’s ASCII alphanumerics, `+`, `-`, and `_`.
+pub fn name_byte(byte: u8) -> bool {
+    matches!(byte, b'+' | b'-' | b'_') || byte.is_ascii_alphanumeric()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn decode_emoji() {
+        assert_eq!(
+            decode("smile", &[]),
+            Some("😄"),
+            "should decode known names"
+        );
+        assert_eq!(decode("+1", &[]), Some("👍"), "should decode `+1`");
+        assert_eq!(decode("Smile", &[]), None, "should be case-sensitive");
+        assert_eq!(
+            decode("shipit", &[]),
+            None,
+            "should not decode unknown names"
+        );
+
+        let custom = vec![
+            ("shipit".into(), "🐿️".into()),
+            ("smile".into(), ":)".into()),
+        ];
+        assert_eq!(
+            decode("shipit", &custom),
+            Some("🐿️"),
+            "should decode custom names"
+        );
+        assert_eq!(
+            decode("smile", &custom),
+            Some(":)"),
+            "should prefer custom names over built-in ones"
+        );
+    }
+
+    #[test]
+    fn emoji_sorted() {
+        let mut index = 1;
+        while index < EMOJI.len() {
+            assert!(
+                EMOJI[index - 1].0 < EMOJI[index].0,
+                "expected `{}` before `{}`",
+                EMOJI[index - 1].0,
+                EMOJI[index].0
+            );
+            index += 1;
+        }
+    }
+}
@@ -24,10 +24,23 @@ use alloc::string::String;
 ///
 /// *   [`micromark-util-encode` in `micromark`](https://github.com/micromark/micromark/tree/main/packages/micromark-util-encode)
 pub fn encode(value: &str, encode_html: bool) -> String {
+    let bytes = value.as_bytes();
+
+    // Fast path: find the first dangerous character, or copy as-is if there
+    // is none (common for large code blocks).
+    let mut index = bytes
+        .iter()
+        .position(|byte| {
+            matches!(byte, b'\0') || (encode_html && matches!(byte, b'&' | b'"' | b'<' | b'>'))
+        })
+        .unwrap_or(bytes.len());
+
+    if index == bytes.len() {
+        return value.into();
+    }
+
     // It’ll grow a bit bigger for each dangerous character.
     let mut result = String::with_capacity(value.len());
-    let bytes = value.as_bytes();
-    let mut index = 0;
     let mut start = 0;
 
     while index < bytes.len() {
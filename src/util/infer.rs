@@ -4,6 +4,7 @@
 
 use crate::event::{Event, Kind, Name};
 use crate::mdast::AlignKind;
+use crate::util::skip;
 use alloc::{vec, vec::Vec};
 
 /// Figure out if a list is spread or not.
@@ -125,7 +126,28 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
                     at_prefix = true;
                 }
 
-                if !at_prefix {
+                // Blank line at the end of the item, with nothing after it:
+                //
+                // ```markdown
+                //   | - a
+                // > | ␊
+                //     ^
+                //   | ␊
+                // ```
+                let after = skip::opt(
+                    events,
+                    index + 1,
+                    &[
+                        Name::BlankLineEnding,
+                        Name::BlockQuotePrefix,
+                        Name::LineEnding,
+                        Name::SpaceOrTab,
+                    ],
+                );
+                let at_end =
+                    events[after].kind == Kind::Exit && events[after].name == Name::ListItem;
+
+                if !at_prefix && !at_end {
                     return true;
                 }
             }
@@ -142,6 +164,22 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
     false
 }
 
+/// Figure out if a definition list description is spread or not.
+///
+/// Descriptions after blank lines are spread (“loose”).
+pub fn definition_list_description_loose(events: &[Event], index: usize) -> bool {
+    debug_assert!(
+        matches!(events[index].name, Name::DefinitionListDescription),
+        "expected definition list description"
+    );
+    let before = skip::opt_back(
+        events,
+        index - 1,
+        &[Name::BlockQuotePrefix, Name::LineEnding, Name::SpaceOrTab],
+    );
+    events[before].name == Name::BlankLineEnding
+}
+
 /// Figure out the alignment of a GFM table.
 pub fn gfm_table_align(events: &[Event], mut index: usize) -> Vec<AlignKind> {
     debug_assert!(
@@ -1,9 +1,11 @@
 //! Utilities used when processing markdown.
 
+pub mod abbreviation;
 pub mod char;
 pub mod character_reference;
 pub mod constant;
 pub mod edit_map;
+pub mod emoji;
 pub mod encode;
 pub mod gfm_tagfilter;
 pub mod identifier;
@@ -13,7 +15,10 @@ pub mod location;
 pub mod mdx;
 pub mod mdx_collect;
 pub mod normalize_identifier;
+pub mod resolve_url;
 pub mod sanitize_uri;
 pub mod skip;
 pub mod slice;
+pub mod slug;
+pub mod sort_attributes;
 pub mod unicode;
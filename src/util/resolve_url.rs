@@ -0,0 +1,169 @@
+//! Resolve relative URLs against a base.
+
+use alloc::string::String;
+
+/// Resolve a URL against a base URL.
+///
+/// URLs that are empty, that start with `#` or `/` (which includes
+/// protocol-relative `//`), or that have a protocol (such as `https:`) are
+/// returned as they are.
+/// Other URLs are relative: leading `./` and `../` segments are resolved
+/// against the directory of `base`, and the rest is appended to it.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::resolve_url::resolve_url;
+///
+/// assert_eq!(resolve_url("https://a.b/c/", "./d.png"), "https://a.b/c/d.png");
+/// assert_eq!(resolve_url("https://a.b/c/", "../d"), "https://a.b/d");
+/// assert_eq!(resolve_url("https://a.b/c/", "/d"), "/d");
+/// assert_eq!(resolve_url("https://a.b/c/", "#d"), "#d");
+/// ```
+pub fn resolve_url(base: &str, url: &str) -> String {
+    if url.is_empty() || url.starts_with('#') || url.starts_with('/') || has_protocol(url) {
+        return url.into();
+    }
+
+    // Drop the search and hash of the base.
+    let base = &base[..base.find(&['?', '#'][..]).unwrap_or(base.len())];
+    // Where the path of the base starts, after the protocol and host.
+    let path_start = base.find("://").map_or(0, |index| {
+        base[index + 3..]
+            .find('/')
+            .map_or(base.len(), |slash| index + 3 + slash)
+    });
+    // Drop the final segment (file name) of the base.
+    let mut directory = if let Some(index) = base[path_start..].rfind('/') {
+        String::from(&base[..=path_start + index])
+    } else {
+        let mut value = String::from(&base[..path_start]);
+        if path_start > 0 {
+            value.push('/');
+        }
+        value
+    };
+    let mut rest = url;
+
+    loop {
+        if let Some(after) = rest.strip_prefix("./") {
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("../") {
+            rest = after;
+
+            // Move up a directory, but not beyond the root.
+            if directory.len() > path_start + 1 {
+                directory.pop();
+                let size = directory
+                    .rfind('/')
+                    .map_or(0, |index| index.max(path_start) + 1);
+                directory.truncate(size);
+            }
+        } else {
+            break;
+        }
+    }
+
+    directory.push_str(rest);
+    directory
+}
+
+/// Check whether a URL starts with a protocol (such as `https:`).
+///
+/// That’s an ASCII letter, followed by ASCII alphanumerics, `+`, `-`, or
+/// `.`, and then a `:`.
+fn has_protocol(url: &str) -> bool {
+    let bytes = url.as_bytes();
+    let mut index = 0;
+
+    if bytes.is_empty() || !bytes[0].is_ascii_alphabetic() {
+        return false;
+    }
+
+    while index < bytes.len()
+        && (bytes[index].is_ascii_alphanumeric() || matches!(bytes[index], b'+' | b'-' | b'.'))
+    {
+        index += 1;
+    }
+
+    index < bytes.len() && bytes[index] == b':'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve() {
+        assert_eq!(
+            resolve_url("https://a.b/c/", "d.png"),
+            "https://a.b/c/d.png",
+            "should resolve relative URLs"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/", "./d.png"),
+            "https://a.b/c/d.png",
+            "should resolve `./`"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/d/", "../../e"),
+            "https://a.b/e",
+            "should resolve `../`"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/", "../../../d"),
+            "https://a.b/d",
+            "should not resolve `../` beyond the root"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/d.html?e#f", "g"),
+            "https://a.b/c/g",
+            "should drop the file name, search, and hash of the base"
+        );
+        assert_eq!(
+            resolve_url("https://a.b", "c"),
+            "https://a.b/c",
+            "should support a base w/o path"
+        );
+        assert_eq!(
+            resolve_url("https://a.b?c", "d"),
+            "https://a.b/d",
+            "should support a base w/o path, w/ search"
+        );
+        assert_eq!(
+            resolve_url("/a/b/", "../c"),
+            "/a/c",
+            "should support a base w/o protocol"
+        );
+        assert_eq!(
+            resolve_url("a/", "../b"),
+            "b",
+            "should support a relative base"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/", "/d"),
+            "/d",
+            "should not resolve absolute paths"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/", "//d.e"),
+            "//d.e",
+            "should not resolve protocol-relative URLs"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/", "mailto:d@e.f"),
+            "mailto:d@e.f",
+            "should not resolve URLs w/ a protocol"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/", "#d"),
+            "#d",
+            "should not resolve fragments"
+        );
+        assert_eq!(
+            resolve_url("https://a.b/c/", ""),
+            "",
+            "should not resolve empty URLs"
+        );
+    }
+}
@@ -52,8 +52,25 @@ pub fn sanitize(value: &str) -> String {
 ///
 /// *   [`micromark-util-sanitize-uri` in `micromark`](https://github.com/micromark/micromark/tree/main/packages/micromark-util-sanitize-uri)
 pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
-    let value = sanitize(value);
+    check_protocols(sanitize(value), protocols)
+}
 
+/// Check the protocol of an already sanitized URL.
+///
+/// This is what [`sanitize_with_protocols`][sanitize_with_protocols] does
+/// after [`sanitize`][sanitize], exposed for URLs that are made safe in some
+/// other way (such as without percent-encoding).
+/// If the URL includes an unknown protocol, an empty string is returned.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::sanitize_uri::check_protocols;
+///
+/// assert_eq!(check_protocols("javascript:alert(1)".into(), &["http", "https"]), "");
+/// assert_eq!(check_protocols("a b".into(), &["http", "https"]), "a b");
+/// ```
+pub fn check_protocols(value: String, protocols: &[&str]) -> String {
     let end = value.find(|c| matches!(c, '?' | '#' | '/'));
     let mut colon = value.find(|c| matches!(c, ':'));
 
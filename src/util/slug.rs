@@ -0,0 +1,48 @@
+//! Turn text into slugs, for use as `id`s.
+
+use alloc::string::String;
+
+/// Turn plain text into a slug, like GitHub does for headings.
+///
+/// Letters are lowercased, whitespace turns into `-`, and other characters
+/// that are not alphanumeric, `-`, or `_` (such as punctuation and symbols)
+/// are removed.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::slug::slug;
+///
+/// assert_eq!(slug("Hello, World!"), "hello-world");
+/// assert_eq!(slug("a_b-c"), "a_b-c");
+/// ```
+pub fn slug(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+
+    for char in value.chars() {
+        if char.is_whitespace() {
+            result.push('-');
+        } else if char.is_alphanumeric() || char == '-' || char == '_' {
+            result.extend(char.to_lowercase());
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugs() {
+        assert_eq!(slug("abc"), "abc", "should keep lowercase letters");
+        assert_eq!(slug("ABC"), "abc", "should lowercase letters");
+        assert_eq!(slug("a b\tc"), "a-b-c", "should turn whitespace into `-`");
+        assert_eq!(slug("a  b"), "a--b", "should not collapse whitespace");
+        assert_eq!(slug("a, b! (c)?"), "a-b-c", "should remove punctuation");
+        assert_eq!(slug("a-b_c"), "a-b_c", "should keep `-` and `_`");
+        assert_eq!(slug("Ünïcödé 1"), "ünïcödé-1", "should support unicode");
+        assert_eq!(slug(""), "", "should support empty text");
+    }
+}
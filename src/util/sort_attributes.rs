@@ -0,0 +1,170 @@
+//! Sort attributes in HTML tags, for canonical output.
+
+use alloc::{string::String, vec::Vec};
+
+/// Sort the attributes of each tag in `value` by name.
+///
+/// Only tags where all attributes are either empty or double quoted are
+/// changed, which includes all tags generated by this crate.
+/// Other tags (such as raw HTML with single quoted attributes), comments,
+/// and text, are kept as-is.
+/// Attributes with the same name keep their order.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::sort_attributes::sort_attributes;
+///
+/// assert_eq!(sort_attributes("<a id=\"b\" href=\"c\">d</a>"), "<a href=\"c\" id=\"b\">d</a>");
+/// ```
+pub fn sort_attributes(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut result = String::with_capacity(bytes.len());
+    let mut index = 0;
+    let mut start = 0;
+
+    while index < bytes.len() {
+        if bytes[index] == b'<' {
+            // Skip over comments, as they can contain anything.
+            if bytes[index..].starts_with(b"<!--") {
+                index = match value[index + 4..].find("-->") {
+                    Some(end) => index + 4 + end + 3,
+                    None => bytes.len(),
+                };
+                continue;
+            }
+
+            if let Some((end, tag)) = tag(bytes, index) {
+                result.push_str(&value[start..index]);
+                result.push_str(&tag);
+                index = end;
+                start = end;
+                continue;
+            }
+        }
+
+        index += 1;
+    }
+
+    result.push_str(&value[start..]);
+    result
+}
+
+/// Parse an opening tag at `index`, and serialize it with sorted attributes.
+///
+/// Returns the index after the tag, and the new tag.
+fn tag(bytes: &[u8], index: usize) -> Option<(usize, String)> {
+    let name_start = index + 1;
+    let mut index = name_start;
+
+    while index < bytes.len() && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'-') {
+        index += 1;
+    }
+
+    if index == name_start || !bytes[name_start].is_ascii_alphabetic() {
+        return None;
+    }
+
+    let name_end = index;
+    let mut attributes: Vec<(&[u8], &[u8])> = Vec::new();
+
+    loop {
+        let whitespace_start = index;
+
+        while index < bytes.len() && matches!(bytes[index], b'\t' | b'\n' | b'\r' | b' ') {
+            index += 1;
+        }
+
+        match bytes.get(index) {
+            Some(b'>' | b'/') => break,
+            // Attributes must be separated by whitespace.
+            Some(_) if index > whitespace_start => {}
+            _ => return None,
+        }
+
+        let attribute_start = index;
+
+        while index < bytes.len()
+            && !matches!(
+                bytes[index],
+                b'\t' | b'\n' | b'\r' | b' ' | b'"' | b'\'' | b'/' | b'<' | b'=' | b'>'
+            )
+        {
+            index += 1;
+        }
+
+        if index == attribute_start {
+            return None;
+        }
+
+        let attribute_name_end = index;
+
+        if bytes.get(index) == Some(&b'=') {
+            index += 1;
+
+            if bytes.get(index) != Some(&b'"') {
+                return None;
+            }
+
+            index += 1;
+
+            while index < bytes.len() && bytes[index] != b'"' {
+                index += 1;
+            }
+
+            if index == bytes.len() {
+                return None;
+            }
+
+            index += 1;
+        }
+
+        attributes.push((
+            &bytes[attribute_start..attribute_name_end],
+            &bytes[attribute_name_end..index],
+        ));
+    }
+
+    let tail_start = index;
+
+    if bytes[index] == b'/' {
+        index += 1;
+    }
+
+    if bytes.get(index) != Some(&b'>') {
+        return None;
+    }
+
+    index += 1;
+
+    attributes.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut result = String::new();
+    result.push('<');
+    result.push_str(str(&bytes[name_start..name_end]));
+
+    for (name, value) in attributes {
+        result.push(' ');
+        result.push_str(str(name));
+        result.push_str(str(value));
+    }
+
+    if bytes[tail_start] == b'/' {
+        // Keep whether there is a space before a closing slash (as in
+        // `<br />`).
+        if matches!(bytes[tail_start - 1], b'\t' | b'\n' | b'\r' | b' ') {
+            result.push(' ');
+        }
+
+        result.push('/');
+    }
+
+    result.push('>');
+
+    Some((index, result))
+}
+
+/// Turn bytes, which are known to be UTF-8 at these boundaries, into a str.
+fn str(bytes: &[u8]) -> &str {
+    core::str::from_utf8(bytes).expect("expected utf8 at ascii boundaries")
+}
@@ -0,0 +1,191 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn abbreviation() -> Result<(), String> {
+    let abbreviation = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                abbreviation: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("*[HTML]: Hyper Text Markup Language\n\nHTML"),
+        "<p>*[HTML]: Hyper Text Markup Language</p>\n<p>HTML</p>",
+        "should not support abbreviations by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: Hyper Text Markup Language\n\na HTML b",
+            &abbreviation
+        )?,
+        "<p>a <abbr title=\"Hyper Text Markup Language\">HTML</abbr> b</p>",
+        "should support abbreviations w/ `abbreviation: true`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a HTML b\n\n*[HTML]: Hyper Text Markup Language",
+            &abbreviation
+        )?,
+        "<p>a <abbr title=\"Hyper Text Markup Language\">HTML</abbr> b</p>\n",
+        "should support abbreviations defined after their use"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: Hyper Text Markup Language\nHTML", &abbreviation)?,
+        "<p><abbr title=\"Hyper Text Markup Language\">HTML</abbr></p>",
+        "should support a definition directly before a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n*[HTML]: Hyper Text Markup Language", &abbreviation)?,
+        "<p>a\n*[HTML]: Hyper Text Markup Language</p>",
+        "should not support a definition interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: Hyper Text Markup Language\n\nHTMLs xHTML HTML_ HTML5 (HTML), HTML.", &abbreviation)?,
+        "<p>HTMLs xHTML HTML_ HTML5 (<abbr title=\"Hyper Text Markup Language\">HTML</abbr>), <abbr title=\"Hyper Text Markup Language\">HTML</abbr>.</p>",
+        "should respect word boundaries"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: Hyper Text Markup Language\n\nhtml Html",
+            &abbreviation
+        )?,
+        "<p>html Html</p>",
+        "should be case-sensitive"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: Hyper Text Markup Language\n*[W3C]: World Wide Web Consortium\n\nThe HTML spec is maintained by the W3C.",
+            &abbreviation
+        )?,
+        "<p>The <abbr title=\"Hyper Text Markup Language\">HTML</abbr> spec is maintained by the <abbr title=\"World Wide Web Consortium\">W3C</abbr>.</p>",
+        "should support multiple abbreviations"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: Hyper Text Markup Language\n*[HTML 5]: Hyper Text Markup Language, version 5\n\nHTML 5 and HTML",
+            &abbreviation
+        )?,
+        "<p><abbr title=\"Hyper Text Markup Language, version 5\">HTML 5</abbr> and <abbr title=\"Hyper Text Markup Language\">HTML</abbr></p>",
+        "should prefer the longest abbreviation"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: a\n*[HTML]: b\n\nHTML", &abbreviation)?,
+        "<p><abbr title=\"a\">HTML</abbr></p>",
+        "should use the first definition if there are several"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: Hyper Text Markup Language\n\n*HTML* and **a HTML**", &abbreviation)?,
+        "<p><em><abbr title=\"Hyper Text Markup Language\">HTML</abbr></em> and <strong>a <abbr title=\"Hyper Text Markup Language\">HTML</abbr></strong></p>",
+        "should support abbreviations in emphasis and strong"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: Hyper Text Markup Language\n\n`HTML`\n\n```\nHTML\n```",
+            &abbreviation
+        )?,
+        "<p><code>HTML</code></p>\n<pre><code>HTML\n</code></pre>",
+        "should not support abbreviations in code"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]: Hyper Text Markup Language\n\n[HTML](https://HTML.example) <https://HTML.example> ![HTML](a)", &abbreviation)?,
+        "<p><a href=\"https://HTML.example\">HTML</a> <a href=\"https://HTML.example\">https://HTML.example</a> <img src=\"a\" alt=\"HTML\" /></p>",
+        "should not support abbreviations in links, autolinks, or destinations, and compile to text in image alt"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*[HTML]: Hyper Text Markup Language\n\n# HTML",
+            &abbreviation
+        )?,
+        "<h1><abbr title=\"Hyper Text Markup Language\">HTML</abbr></h1>",
+        "should support abbreviations in headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("> *[HTML]: Hyper Text Markup Language\n\nHTML", &abbreviation)?,
+        "<blockquote>\n</blockquote>\n<p><abbr title=\"Hyper Text Markup Language\">HTML</abbr></p>",
+        "should support definitions in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("   *[HTML]: a\n\nHTML", &abbreviation)?,
+        "<p><abbr title=\"a\">HTML</abbr></p>",
+        "should support up to three spaces of indent"
+    );
+
+    assert_eq!(
+        to_html_with_options("    *[HTML]: a\n\nHTML", &abbreviation)?,
+        "<pre><code>*[HTML]: a\n</code></pre>\n<p>HTML</p>",
+        "should not support four spaces of indent"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[HTML]:\n\nHTML", &abbreviation)?,
+        "<p><abbr>HTML</abbr></p>",
+        "should support a definition w/o value"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[]: a\n\n*[a] b", &abbreviation)?,
+        "<p>*[]: a</p>\n<p>*[a] b</p>",
+        "should not support an empty label, or a missing `:`"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[<a>]: \"&\"\n\nb <a>", &abbreviation)?,
+        "<p>b &lt;a&gt;</p>",
+        "should not support abbreviations in HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("*[a&b]: \"c\"\n\na&b", &abbreviation)?,
+        "<p><abbr title=\"&quot;c&quot;\">a&amp;b</abbr></p>",
+        "should support punctuation in labels, and encode values"
+    );
+
+    assert_eq!(
+        to_html_with_options("***\n*a*", &abbreviation)?,
+        "<hr />\n<p><em>a</em></p>",
+        "should not affect thematic breaks and emphasis"
+    );
+
+    assert_eq!(
+        to_mdast("*[HTML]: a\n\nb HTML", &abbreviation.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "b HTML".into(),
+                    position: Some(Position::new(3, 1, 12, 3, 7, 18))
+                })],
+                position: Some(Position::new(3, 1, 12, 3, 7, 18))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 7, 18))
+        }),
+        "should support abbreviations as text in mdast"
+    );
+
+    Ok(())
+}
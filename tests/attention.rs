@@ -1,6 +1,6 @@
 use markdown::{
     mdast::{Emphasis, Node, Paragraph, Root, Strong, Text},
-    to_html, to_html_with_options, to_mdast,
+    to_html, to_html_with_constructs, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
 };
@@ -42,6 +42,72 @@ fn attention() -> Result<(), String> {
         "should not support emphasis unicode whitespace either"
     );
 
+    assert_eq!(
+        to_html("a *\tfoo*"),
+        "<p>a *\tfoo*</p>",
+        "should not support emphasis if the opening is followed by a tab"
+    );
+
+    assert_eq!(
+        to_html("*foo\t*"),
+        "<p>*foo\t*</p>",
+        "should not support emphasis if the closing is preceded by a tab"
+    );
+
+    assert_eq!(
+        to_html("foo\t*bar*"),
+        "<p>foo\t<em>bar</em></p>",
+        "should support emphasis if the opening is preceded by a tab"
+    );
+
+    assert_eq!(
+        to_html("a *\u{3000}foo*"),
+        "<p>a *\u{3000}foo*</p>",
+        "should not support emphasis if the opening is followed by other unicode whitespace"
+    );
+
+    assert_eq!(
+        to_html("*\u{A0}a\u{A0}*"),
+        "<p>*\u{A0}a\u{A0}*</p>",
+        "should not support emphasis if the opening is followed or closing preceded by a no-break space"
+    );
+
+    assert_eq!(
+        to_html("a\u{A0}*b*\u{A0}c"),
+        "<p>a\u{A0}<em>b</em>\u{A0}c</p>",
+        "should support emphasis surrounded by no-break spaces"
+    );
+
+    assert_eq!(
+        to_html("**\u{2003}a** *a\u{2003}*"),
+        "<p>**\u{2003}a** *a\u{2003}*</p>",
+        "should not support attention if the opening is followed or closing preceded by an em space"
+    );
+
+    assert_eq!(
+        to_html("\u{2003}*a*\u{2003}"),
+        "<p>\u{2003}<em>a</em>\u{2003}</p>",
+        "should support emphasis surrounded by em spaces"
+    );
+
+    assert_eq!(
+        to_html("、*a*、"),
+        "<p>、<em>a</em>、</p>",
+        "should support emphasis surrounded by unicode punctuation"
+    );
+
+    assert_eq!(
+        to_html("a*、b*、"),
+        "<p>a*、b*、</p>",
+        "should not support emphasis if the opening is followed by unicode punctuation and preceded by a letter"
+    );
+
+    assert_eq!(
+        to_html("a_b_、"),
+        "<p>a_b_、</p>",
+        "should not support intraword emphasis w/ `_` before unicode punctuation"
+    );
+
     assert_eq!(
         to_html("foo*bar*"),
         "<p>foo<em>bar</em></p>",
@@ -837,6 +903,168 @@ fn attention() -> Result<(), String> {
         "should support turning off attention"
     );
 
+    assert_eq!(
+        to_html_with_constructs(
+            "*a* `b`",
+            &Constructs {
+                attention: false,
+                ..Default::default()
+            },
+            &Default::default()
+        )?,
+        "<p>*a* <code>b</code></p>",
+        "should support turning off attention per call"
+    );
+
+    let tags = Options {
+        compile: CompileOptions {
+            emphasis_tag_name: Some("i".into()),
+            strong_tag_name: Some("b".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("*a* **b**", &tags)?,
+        "<p><i>a</i> <b>b</b></p>",
+        "should support `emphasis_tag_name` and `strong_tag_name`"
+    );
+
+    assert_eq!(
+        to_html_with_options("***c*** *d **e** f*", &tags)?,
+        "<p><i><b>c</b></i> <i>d <b>e</b> f</i></p>",
+        "should support custom tag names when nested"
+    );
+
+    assert_eq!(
+        to_html_with_options("![*a* **b**](c)", &tags)?,
+        "<p><img src=\"c\" alt=\"a b\" /></p>",
+        "should not use custom tag names in image alt"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*a* **b**",
+            &Options {
+                compile: CompileOptions {
+                    allowed_elements: Some(vec!["p".into(), "i".into()]),
+                    emphasis_tag_name: Some("i".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><i>a</i> b</p>",
+        "should check custom tag names against `allowed_elements`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*a*",
+            &Options {
+                compile: CompileOptions {
+                    emphasis_tag_name: Some("i onclick=x".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        ),
+        Err("Cannot use `i onclick=x` as `emphasis_tag_name`, expected an ASCII letter followed by ASCII alphanumerics or `-`".into()),
+        "should crash on invalid `emphasis_tag_name`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "**a**",
+            &Options {
+                compile: CompileOptions {
+                    strong_tag_name: Some("b>".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        ),
+        Err("Cannot use `b>` as `strong_tag_name`, expected an ASCII letter followed by ASCII alphanumerics or `-`".into()),
+        "should crash on invalid `strong_tag_name`"
+    );
+
+    assert_eq!(
+        to_html_with_constructs(
+            "*a* ~b~",
+            &Constructs {
+                gfm_strikethrough: true,
+                ..Default::default()
+            },
+            &Default::default()
+        )?,
+        "<p><em>a</em> <del>b</del></p>",
+        "should support turning on other constructs per call, such as in text"
+    );
+
+    let cjk = Options {
+        parse: ParseOptions {
+            cjk_friendly_emphasis: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("**この文を強調：**この後"),
+        "<p>**この文を強調：**この後</p>",
+        "should not close strong after punctuation before CJK by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("**この文を強調：**この後", &cjk)?,
+        "<p><strong>この文を強調：</strong>この後</p>",
+        "should close strong after punctuation before CJK if `cjk_friendly_emphasis`"
+    );
+
+    assert_eq!(
+        to_html("中文**「强调」**中文"),
+        "<p>中文**「强调」**中文</p>",
+        "should not support strong around punctuation between CJK by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文**「强调」**中文", &cjk)?,
+        "<p>中文<strong>「强调」</strong>中文</p>",
+        "should support strong around punctuation between CJK if `cjk_friendly_emphasis`"
+    );
+
+    assert_eq!(
+        to_html_with_options("한국어*(강조)*한국어", &cjk)?,
+        "<p>한국어<em>(강조)</em>한국어</p>",
+        "should support emphasis around punctuation between hangul if `cjk_friendly_emphasis`"
+    );
+
+    assert_eq!(
+        to_html_with_options("中文*bold*中文", &cjk)?,
+        "<p>中文<em>bold</em>中文</p>",
+        "should support emphasis between CJK if `cjk_friendly_emphasis`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a**(b)**c", &cjk)?,
+        "<p>a**(b)**c</p>",
+        "should not affect non-CJK characters if `cjk_friendly_emphasis`"
+    );
+
+    // Long runs of sequences, which used to take quadratic time.
+    assert_eq!(
+        to_html(&"a*".repeat(50_000)),
+        format!("<p>{}</p>", "a<em>a</em>".repeat(25_000)),
+        "should support many matching sequences in linear time"
+    );
+
+    assert_eq!(
+        to_html(&"a* ".repeat(30_000)),
+        format!("<p>{}</p>", "a* ".repeat(30_000).trim_end()),
+        "should support many closing sequences w/o openers in linear time"
+    );
+
     assert_eq!(
         to_mdast("a *alpha* b **bravo** c.", &Default::default())?,
         Node::Root(Root {
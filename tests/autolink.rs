@@ -83,6 +83,42 @@ fn autolink() -> Result<(), String> {
         "should not support protocol autolinks w/ spaces"
     );
 
+    assert_eq!(
+        to_html("<http://example.com/foo bar>"),
+        "<p>&lt;http://example.com/foo bar&gt;</p>",
+        "should not support protocol autolinks w/ a space in the path"
+    );
+
+    assert_eq!(
+        to_html("<http://example.com/foo%20bar>"),
+        "<p><a href=\"http://example.com/foo%20bar\">http://example.com/foo%20bar</a></p>",
+        "should support protocol autolinks w/ an encoded space"
+    );
+
+    assert_eq!(
+        to_html("<http://a\tb> <http://a\u{1}b>"),
+        "<p>&lt;http://a\tb&gt; &lt;http://a\u{1}b&gt;</p>",
+        "should not support protocol autolinks w/ control characters"
+    );
+
+    assert_eq!(
+        to_html("<http://a\nb>"),
+        "<p>&lt;http://a\nb&gt;</p>",
+        "should not support protocol autolinks w/ line endings"
+    );
+
+    assert_eq!(
+        to_html("<http://a<b>"),
+        "<p>&lt;http://a&lt;b&gt;</p>",
+        "should not support protocol autolinks w/ a less than"
+    );
+
+    assert_eq!(
+        to_html("<http://a>b>"),
+        "<p><a href=\"http://a\">http://a</a>b&gt;</p>",
+        "should end protocol autolinks at the first greater than"
+    );
+
     assert_eq!(
         to_html("<http://example.com/\\[\\>"),
         "<p><a href=\"http://example.com/%5C%5B%5C\">http://example.com/\\[\\</a></p>",
@@ -251,6 +287,72 @@ fn autolink() -> Result<(), String> {
         "should not support a dash before a dot in email autolinks"
     );
 
+    assert_eq!(
+        to_html("<a@b>"),
+        "<p><a href=\"mailto:a@b\">a@b</a></p>",
+        "should support email autolinks w/o dot in domain by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<a@b>",
+            &Options {
+                parse: ParseOptions {
+                    strict_email_autolink: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>&lt;a@b&gt;</p>",
+        "should not support email autolinks w/o dot in domain w/ `strict_email_autolink`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<a@b.c>",
+            &Options {
+                parse: ParseOptions {
+                    strict_email_autolink: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"mailto:a@b.c\">a@b.c</a></p>",
+        "should support email autolinks w/ dot in domain w/ `strict_email_autolink`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<a@b.c> <d@e>",
+            &Options {
+                parse: ParseOptions {
+                    strict_email_autolink: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"mailto:a@b.c\">a@b.c</a> &lt;d@e&gt;</p>",
+        "should check each email autolink w/ `strict_email_autolink`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<a@b> <https://c>",
+            &Options {
+                parse: ParseOptions {
+                    strict_email_autolink: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>&lt;a@b&gt; <a href=\"https://c\">https://c</a></p>",
+        "should not affect URL autolinks w/ `strict_email_autolink`"
+    );
+
     assert_eq!(
         to_html_with_options(
             "<a@b.co>",
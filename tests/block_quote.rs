@@ -2,7 +2,7 @@ use markdown::{
     mdast::{BlockQuote, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -56,6 +56,30 @@ fn block_quote() -> Result<(), String> {
         "should not support lazy setext headings underlines in block quotes"
     );
 
+    assert_eq!(
+        to_html("> foo\n# bar"),
+        "<blockquote>\n<p>foo</p>\n</blockquote>\n<h1>bar</h1>",
+        "should not support lazy headings (atx) in block quotes"
+    );
+
+    assert_eq!(
+        to_html("> foo\n***"),
+        "<blockquote>\n<p>foo</p>\n</blockquote>\n<hr />",
+        "should not support lazy thematic breaks in block quotes"
+    );
+
+    assert_eq!(
+        to_html("> > foo\n# bar"),
+        "<blockquote>\n<blockquote>\n<p>foo</p>\n</blockquote>\n</blockquote>\n<h1>bar</h1>",
+        "should not support lazy headings (atx) in nested block quotes"
+    );
+
+    assert_eq!(
+        to_html("> foo\n> # bar"),
+        "<blockquote>\n<p>foo</p>\n<h1>bar</h1>\n</blockquote>",
+        "should support headings (atx) interrupting paragraphs in block quotes"
+    );
+
     assert_eq!(
         to_html("> - a\n> - b"),
         "<blockquote>\n<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n</blockquote>",
@@ -218,6 +242,50 @@ fn block_quote() -> Result<(), String> {
         "should support turning off block quotes"
     );
 
+    let cite = Options {
+        compile: CompileOptions {
+            block_quote_cite: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("> Source: <https://a.b/c>\n> d", &cite)?,
+        "<blockquote cite=\"https://a.b/c\">\n<p>Source: <a href=\"https://a.b/c\">https://a.b/c</a>\nd</p>\n</blockquote>",
+        "should support `block_quote_cite`"
+    );
+
+    assert_eq!(
+        to_html_with_options(">   Source:   <https://a.b>  \n>\n> c", &cite)?,
+        "<blockquote cite=\"https://a.b\">\n<p>Source:   <a href=\"https://a.b\">https://a.b</a></p>\n<p>c</p>\n</blockquote>",
+        "should support whitespace around a citation"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> Source: <https://b.c>", &cite)?,
+        "<blockquote>\n<p>a\nSource: <a href=\"https://b.c\">https://b.c</a></p>\n</blockquote>",
+        "should not support a citation that is not on the first line"
+    );
+
+    assert_eq!(
+        to_html_with_options("> Source: <https://a.b> c\n\n> Source: [a](b)\n\n> From: <https://a.b>", &cite)?,
+        "<blockquote>\n<p>Source: <a href=\"https://a.b\">https://a.b</a> c</p>\n</blockquote>\n<blockquote>\n<p>Source: <a href=\"b\">a</a></p>\n</blockquote>\n<blockquote>\n<p>From: <a href=\"https://a.b\">https://a.b</a></p>\n</blockquote>",
+        "should not support other things as citations"
+    );
+
+    assert_eq!(
+        to_html_with_options("> Source: <a@b.c>\n\n> Source: <javascript:alert(1)>", &cite)?,
+        "<blockquote>\n<p>Source: <a href=\"mailto:a@b.c\">a@b.c</a></p>\n</blockquote>\n<blockquote>\n<p>Source: <a href=\"\">javascript:alert(1)</a></p>\n</blockquote>",
+        "should not support email autolinks, and sanitize citations"
+    );
+
+    assert_eq!(
+        to_html_with_options("> > Source: <https://a.b>", &cite)?,
+        "<blockquote>\n<blockquote cite=\"https://a.b\">\n<p>Source: <a href=\"https://a.b\">https://a.b</a></p>\n</blockquote>\n</blockquote>",
+        "should support citations in nested block quotes"
+    );
+
     assert_eq!(
         to_mdast("> a", &Default::default())?,
         Node::Root(Root {
@@ -55,6 +55,12 @@ fn character_escape() -> Result<(), String> {
         "should not escape in indented code"
     );
 
+    assert_eq!(
+        to_html("```\n\\[\\`\n```"),
+        "<pre><code>\\[\\`\n</code></pre>",
+        "should not escape in fenced code"
+    );
+
     assert_eq!(
         to_html("<http://example.com?find=\\*>"),
         "<p><a href=\"http://example.com?find=%5C*\">http://example.com?find=\\*</a></p>",
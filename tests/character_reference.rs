@@ -16,6 +16,24 @@ fn character_reference() -> Result<(), String> {
         "should support named character references"
     );
 
+    assert_eq!(
+        to_html("&NotEqualTilde; &bne; &fjlig; &ThickSpace; &nvlt;"),
+        "<p>\u{2242}\u{338} =\u{20e5} fj \u{205f}\u{200a} &lt;\u{20d2}</p>",
+        "should support named character references that expand to multiple characters"
+    );
+
+    assert_eq!(
+        to_html("&CounterClockwiseContourIntegral; &andslope; &Afr; &zwnj;"),
+        "<p>∳ ⩘ 𝔄 \u{200c}</p>",
+        "should support uncommon named character references"
+    );
+
+    assert_eq!(
+        to_html("[a](&nvlt;)"),
+        "<p><a href=\"%3C%E2%83%92\">a</a></p>",
+        "should support named character references that expand to multiple characters in destinations"
+    );
+
     assert_eq!(
         to_html("&#35; &#1234; &#992; &#0;"),
         "<p># Ӓ Ϡ �</p>",
@@ -28,6 +46,42 @@ fn character_reference() -> Result<(), String> {
         "should support hexadecimal character references"
     );
 
+    assert_eq!(
+        to_html("&auml; &Auml; &ouml; &uuml; &szlig;"),
+        "<p>ä Ä ö ü ß</p>",
+        "should support named character references from the HTML5 table"
+    );
+
+    assert_eq!(
+        to_html("&#128512; &#x1F600; &#X1d504; &#1114111;"),
+        "<p>😀 😀 𝔄 \u{10ffff}</p>",
+        "should support numeric character references beyond the BMP"
+    );
+
+    assert_eq!(
+        to_html("&#0; &#x110000; &#xFFFFFF; &#9999999;"),
+        "<p>� � � �</p>",
+        "should turn `0` and out of range numeric character references into the replacement character"
+    );
+
+    assert_eq!(
+        to_html("&#xD800; &#xDFFF; &#55296;"),
+        "<p>� � �</p>",
+        "should turn lone surrogates into the replacement character"
+    );
+
+    assert_eq!(
+        to_html("&#1; &#x7F; &#x80; &#159;"),
+        "<p>� � � �</p>",
+        "should turn disallowed control characters into the replacement character"
+    );
+
+    assert_eq!(
+        to_html("a&#9;b&#x9;c"),
+        "<p>a\tb\tc</p>",
+        "should support a tab as a numeric character reference"
+    );
+
     assert_eq!(
       to_html(
         "&nbsp &x; &#; &#x;\n&#987654321;\n&#abcdef0;\n&ThisIsNotDefined; &hi?;"),
@@ -47,6 +101,12 @@ fn character_reference() -> Result<(), String> {
         "should not support unknown named character references"
     );
 
+    assert_eq!(
+        to_html("&unknown;"),
+        "<p>&amp;unknown;</p>",
+        "should not support unknown lowercase named character references"
+    );
+
     assert_eq!(
         to_html_with_options(
             "<a href=\"&ouml;&ouml;.html\">",
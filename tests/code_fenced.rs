@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Code, Node, Root},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -50,6 +50,36 @@ fn code_fenced() -> Result<(), String> {
         "should support a closing sequence equal to, but not shorter than, the opening"
     );
 
+    assert_eq!(
+        to_html("~~~\nx\n~~~~~"),
+        "<pre><code>x\n</code></pre>",
+        "should support a tilde closing sequence longer than the opening"
+    );
+
+    assert_eq!(
+        to_html("~~~\nx\n```"),
+        "<pre><code>x\n```\n</code></pre>\n",
+        "should not close tilde fenced code w/ grave accents, even at eof"
+    );
+
+    assert_eq!(
+        to_html("```\nx\n~~~"),
+        "<pre><code>x\n~~~\n</code></pre>\n",
+        "should not close grave accent fenced code w/ tildes, even at eof"
+    );
+
+    assert_eq!(
+        to_html("```\nx\n``~\n~~~\n```"),
+        "<pre><code>x\n``~\n~~~\n</code></pre>",
+        "should not support a closing sequence w/ mixed markers"
+    );
+
+    assert_eq!(
+        to_html("~~~~\nx\n```\n~~~\n~~~~"),
+        "<pre><code>x\n```\n~~~\n</code></pre>",
+        "should not support a closing sequence of the other marker or shorter"
+    );
+
     assert_eq!(
         to_html("```"),
         "<pre><code></code></pre>\n",
@@ -98,6 +128,18 @@ fn code_fenced() -> Result<(), String> {
       "should remove up to three space from the content if the opening sequence is indented w/ 3 spaces"
     );
 
+    assert_eq!(
+        to_html("  ```\n    a\n b\n\tc\n  ```"),
+        "<pre><code>  a\nb\n  c\n</code></pre>",
+        "should remove up to the indent of the opening sequence from the content, tab-aware"
+    );
+
+    assert_eq!(
+        to_html("- a\n\n    ```\n      b\n     c\n    d\n   e\n    ```"),
+        "<ul>\n<li>\n<p>a</p>\n<pre><code>  b\n c\nd\ne\n</code></pre>\n</li>\n</ul>",
+        "should remove up to the indent of the opening sequence from the content, relative to a list item"
+    );
+
     assert_eq!(
         to_html("    ```\n    aaa\n    ```"),
         "<pre><code>```\naaa\n```\n</code></pre>",
@@ -207,6 +249,36 @@ fn code_fenced() -> Result<(), String> {
         "should support whitespace after the info string"
     );
 
+    assert_eq!(
+        to_html("```rust\t\nfn a() {}\n```"),
+        "<pre><code class=\"language-rust\">fn a() {}\n</code></pre>",
+        "should support a tab after the info string"
+    );
+
+    assert_eq!(
+        to_html("```rust \t  \nfn a() {}\n```"),
+        "<pre><code class=\"language-rust\">fn a() {}\n</code></pre>",
+        "should support tabs and spaces after the info string"
+    );
+
+    assert_eq!(
+        to_html("```\trust\nfn a() {}\n```"),
+        "<pre><code class=\"language-rust\">fn a() {}\n</code></pre>",
+        "should support a tab between the sequence and the info string"
+    );
+
+    assert_eq!(
+        to_html("~~~rust\tb\tc\nfn a() {}\n~~~"),
+        "<pre><code class=\"language-rust\">fn a() {}\n</code></pre>",
+        "should support a tab between the info string and the meta string"
+    );
+
+    assert_eq!(
+        to_html("```rust\t"),
+        "<pre><code class=\"language-rust\"></code></pre>\n",
+        "should support an eof after a tab after the info string"
+    );
+
     assert_eq!(
         to_html("```\n  "),
         "<pre><code>  \n</code></pre>\n",
@@ -279,6 +351,103 @@ fn code_fenced() -> Result<(), String> {
         "should support turning off code (fenced)"
     );
 
+    let line_numbers = Options {
+        compile: CompileOptions {
+            code_line_numbers: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("```js\na < b\n\nc\n```", &line_numbers)?,
+        "<pre><code class=\"language-js\"><span class=\"line\" data-line=\"1\">a &lt; b</span>\n<span class=\"line\" data-line=\"2\"></span>\n<span class=\"line\" data-line=\"3\">c</span>\n</code></pre>",
+        "should support `code_line_numbers` (three lines)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\r\na\r\nb\r\n```", &line_numbers)?,
+        "<pre><code><span class=\"line\" data-line=\"1\">a</span>\r\n<span class=\"line\" data-line=\"2\">b</span>\r\n</code></pre>",
+        "should support `code_line_numbers` (CRLF)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na", &line_numbers)?,
+        "<pre><code><span class=\"line\" data-line=\"1\">a</span>\n</code></pre>\n",
+        "should support `code_line_numbers` (unclosed)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n```", &line_numbers)?,
+        "<pre><code></code></pre>",
+        "should support `code_line_numbers` (empty)"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a\n    b", &line_numbers)?,
+        "<pre><code><span class=\"line\" data-line=\"1\">a</span>\n<span class=\"line\" data-line=\"2\">b</span>\n</code></pre>",
+        "should support `code_line_numbers` (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options("- ```\n  a\n  b\n  ```", &line_numbers)?,
+        "<ul>\n<li>\n<pre><code><span class=\"line\" data-line=\"1\">a</span>\n<span class=\"line\" data-line=\"2\">b</span>\n</code></pre>\n</li>\n</ul>",
+        "should support `code_line_numbers` (in container)"
+    );
+
+    let meta = Options {
+        compile: CompileOptions {
+            code_meta_attribute: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("```js title=\"a.js\"\nb\n```"),
+        "<pre><code class=\"language-js\">b\n</code></pre>",
+        "should drop the meta by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js title=\"a.js\"\nb\n```", &meta)?,
+        "<pre><code class=\"language-js\" data-meta=\"title=&quot;a.js&quot;\">b\n</code></pre>",
+        "should support `code_meta_attribute`"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~~js a &amp; \\\" <b>\n~~~", &meta)?,
+        "<pre><code class=\"language-js\" data-meta=\"a &amp; &quot; &lt;b&gt;\"></code></pre>",
+        "should support escapes and references in `code_meta_attribute`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\nb\n```", &meta)?,
+        "<pre><code class=\"language-js\">b\n</code></pre>",
+        "should not add `data-meta` w/o meta"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$$ a\nb\n$$",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    code_meta_attribute: true,
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<pre><code class=\"language-math math-display\">b\n</code></pre>",
+        "should not add `data-meta` to math (flow)"
+    );
+
     assert_eq!(
         to_mdast(
             "```js extra\nconsole.log(1)\nconsole.log(2)\n```",
@@ -296,6 +465,20 @@ fn code_fenced() -> Result<(), String> {
         "should support code (fenced) as `Code`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("```\trust\tb\tc\nd\n```", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Code(Code {
+                lang: Some("rust".into()),
+                meta: Some("b\tc".into()),
+                value: "d".into(),
+                position: Some(Position::new(1, 1, 0, 3, 4, 18))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 18))
+        }),
+        "should support tabs around the info string in mdast"
+    );
+
     assert_eq!(
         to_mdast("```\nasd", &Default::default())?,
         Node::Root(Root {
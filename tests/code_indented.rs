@@ -190,6 +190,24 @@ fn code_indented() -> Result<(), String> {
         "should support turning off code (indented, 7)"
     );
 
+    assert_eq!(
+        to_html_with_options("a\n\n    b\n    c", &off)?,
+        "<p>a</p>\n<p>b\nc</p>",
+        "should support turning off code (indented, 8)"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n\n      b", &off)?,
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "should support turning off code (indented, 9)"
+    );
+
+    assert_eq!(
+        to_html_with_options(">     a", &off)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>",
+        "should support turning off code (indented, 10)"
+    );
+
     assert_eq!(
         to_html_with_options("- Foo\n---", &off)?,
         "<ul>\n<li>Foo</li>\n</ul>\n<hr />",
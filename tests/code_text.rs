@@ -83,6 +83,24 @@ fn code_text() -> Result<(), String> {
         "should not support character escapes"
     );
 
+    assert_eq!(
+        to_html("`a\\*b\\\\c`"),
+        "<p><code>a\\*b\\\\c</code></p>",
+        "should not support character escapes (other punctuation)"
+    );
+
+    assert_eq!(
+        to_html("``a\\``b``"),
+        "<p><code>a\\</code>b``</p>",
+        "should not support character escapes before a closing sequence"
+    );
+
+    assert_eq!(
+        to_html("\\`a\\`"),
+        "<p>`a`</p>",
+        "should support escaped grave accents outside code"
+    );
+
     assert_eq!(
         to_html("``foo`bar``"),
         "<p><code>foo`bar</code></p>",
@@ -180,6 +198,47 @@ fn code_text() -> Result<(), String> {
         "should support turning off code (text)"
     );
 
+    let class = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                math_text: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        compile: CompileOptions {
+            default_inline_code_class: Some("language-rust".into()),
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("a `b` c", &class)?,
+        "<p>a <code class=\"language-rust\">b</code> c</p>",
+        "should support `default_inline_code_class`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n```\n\n$b$", &class)?,
+        "<pre><code>a\n</code></pre>\n<p><code class=\"language-math math-inline\">b</code></p>",
+        "should not add `default_inline_code_class` to code (fenced) or math (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "`a`",
+            &Options {
+                compile: CompileOptions {
+                    default_inline_code_class: Some("a\"b".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><code class=\"a&quot;b\">a</code></p>",
+        "should encode `default_inline_code_class`"
+    );
+
     assert_eq!(
         to_mdast("a `alpha` b.", &Default::default())?,
         Node::Root(Root {
@@ -205,5 +264,15 @@ fn code_text() -> Result<(), String> {
         "should support code (text) as `InlineCode`s in mdast"
     );
 
+    let value = "a ``b ` ü`` c";
+    let tree = to_mdast(value, &Default::default())?;
+    let code = &tree.children().unwrap()[0].children().unwrap()[1];
+
+    assert_eq!(
+        code.position().unwrap().slice(value),
+        "``b ` ü``",
+        "should support getting the source of code (text) from its position"
+    );
+
     Ok(())
 }
@@ -53,6 +53,12 @@ fn definition() -> Result<(), String> {
         "should support complex definitions (2)"
     );
 
+    assert_eq!(
+        to_html("[a]: b \"c\\\"d &amp; <e>\"\n\n[a]"),
+        "<p><a href=\"b\" title=\"c&quot;d &amp; &lt;e&gt;\">a</a></p>",
+        "should encode quotes, `&`, and `<` in titles"
+    );
+
     assert_eq!(
         to_html("[foo]: /url '\ntitle\nline1\nline2\n'\n\n[foo]"),
         "<p><a href=\"/url\" title=\"\ntitle\nline1\nline2\n\">foo</a></p>",
@@ -83,6 +89,36 @@ fn definition() -> Result<(), String> {
         "should support definitions w/ explicit empty destinations"
     );
 
+    assert_eq!(
+        to_html("[foo]:\n<>\n\n[foo]"),
+        "<p><a href=\"\">foo</a></p>",
+        "should support definitions w/ explicit empty destinations on the next line"
+    );
+
+    assert_eq!(
+        to_html("[foo]: #section\n\n[foo]"),
+        "<p><a href=\"#section\">foo</a></p>",
+        "should support definitions w/ fragment-only destinations"
+    );
+
+    assert_eq!(
+        to_html("[foo]: <#section> \"a\"\n\n[foo]"),
+        "<p><a href=\"#section\" title=\"a\">foo</a></p>",
+        "should support definitions w/ enclosed fragment-only destinations"
+    );
+
+    assert_eq!(
+        to_html("[foo]: #\n\n[foo]"),
+        "<p><a href=\"#\">foo</a></p>",
+        "should support definitions w/ empty fragment destinations"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /\n\n[foo]"),
+        "<p><a href=\"/\">foo</a></p>",
+        "should support definitions w/ root destinations"
+    );
+
     assert_eq!(
         to_html_with_options("[foo]: <bar>(baz)\n\n[foo]", &danger)?,
         "<p>[foo]: <bar>(baz)</p>\n<p>[foo]</p>",
@@ -326,6 +362,42 @@ fn definition() -> Result<(), String> {
         "should support paren enclosed titles"
     );
 
+    assert_eq!(
+        to_html("[x]: /u (a title)\n\n[x]"),
+        "<p><a href=\"/u\" title=\"a title\">x</a></p>",
+        "should support paren enclosed titles w/ whitespace"
+    );
+
+    assert_eq!(
+        to_html("[x]: /u\n(a title)\n\n[x]"),
+        "<p><a href=\"/u\" title=\"a title\">x</a></p>",
+        "should support paren enclosed titles on the next line"
+    );
+
+    assert_eq!(
+        to_html("[x]: /u (a\nb)\n\n[x]"),
+        "<p><a href=\"/u\" title=\"a\nb\">x</a></p>",
+        "should support paren enclosed titles w/ line endings"
+    );
+
+    assert_eq!(
+        to_html("[x]:\n/u\n'a\nb'\n\n[x]"),
+        "<p><a href=\"/u\" title=\"a\nb\">x</a></p>",
+        "should support titles w/ line endings on the line after the destination"
+    );
+
+    assert_eq!(
+        to_html("[x]: /u (a \\(b\\) c)\n\n[x]"),
+        "<p><a href=\"/u\" title=\"a (b) c\">x</a></p>",
+        "should support escaped parens in paren enclosed titles"
+    );
+
+    assert_eq!(
+        to_html("[x]: /u (a (b) c)\n\n[x]"),
+        "<p>[x]: /u (a (b) c)</p>\n<p>[x]</p>",
+        "should not support unescaped parens in paren enclosed titles"
+    );
+
     assert_eq!(
         to_html("[x]: a(()\n\n[x]"),
         "<p>[x]: a(()</p>\n<p>[x]</p>",
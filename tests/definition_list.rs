@@ -0,0 +1,235 @@
+use markdown::{
+    mdast::{DefinitionList, DefinitionListDescription, DefinitionListTerm, Node, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn definition_list() -> Result<(), String> {
+    let definition_list = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                definition_list: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a\n: b"),
+        "<p>a\n: b</p>",
+        "should not support definition lists by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>",
+        "should support definition lists w/ `definition_list: true`"
+    );
+
+    assert_eq!(
+        to_html_with_options("Apple\n: Pomaceous fruit\n: Tech company", &definition_list)?,
+        "<dl>\n<dt>Apple</dt>\n<dd>Pomaceous fruit</dd>\n<dd>Tech company</dd>\n</dl>",
+        "should support several descriptions for a term"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a* `b`\n: c **d**", &definition_list)?,
+        "<dl>\n<dt><em>a</em> <code>b</code></dt>\n<dd>c <strong>d</strong></dd>\n</dl>",
+        "should support text in terms and descriptions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\nc\n: d", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n<dt>c</dt>\n<dd>d</dd>\n</dl>",
+        "should support several terms in one list"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n: b\n\n: c", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd><p>b</p></dd>\n<dd><p>c</p></dd>\n</dl>",
+        "should support loose descriptions (after blank lines)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\n: c", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n<dd><p>c</p></dd>\n</dl>",
+        "should support a mix of tight and loose descriptions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n: c", &definition_list)?,
+        "<dl>\n<dt>a\nb</dt>\n<dd>c</dd>\n</dl>",
+        "should use the whole paragraph before as the term"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\nc", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n<p>c</p>",
+        "should not support unindented continuation lines in descriptions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n  c", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b\nc</dd>\n</dl>",
+        "should support indented continuation lines in descriptions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: ```\n  x\n  ```", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd><code>x</code></dd>\n</dl>",
+        "should treat flow constructs in continuation lines as text"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b *c\n  d*", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b <em>c\nd</em></dd>\n</dl>",
+        "should support text across continuation lines"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n\n  c", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n<p>c</p>",
+        "should not support continuation lines after a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: b\n : c", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n<dd>c</dd>\n</dl>",
+        "should prefer another description over a continuation line"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  : b\n    c\n* d", &definition_list)?,
+        "<ul>\n<li>\n<dl>\n<dt>a</dt>\n<dd>b\nc</dd>\n</dl>\n</li>\n<li>d</li>\n</ul>",
+        "should support continuation lines in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> : b\n  c", &definition_list)?,
+        "<blockquote>\n<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n</blockquote>\n<p>c</p>",
+        "should not support lazy continuation lines"
+    );
+
+    assert_eq!(
+        to_html_with_options(": a\n  b", &definition_list)?,
+        "<p>: a\nb</p>",
+        "should support continuation lines in a description w/o term"
+    );
+
+    assert_eq!(
+        to_html_with_options(": a", &definition_list)?,
+        "<p>: a</p>",
+        "should not support a description w/o term"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n: b", &definition_list)?,
+        "<h1>a</h1>\n<p>: b</p>",
+        "should not support a heading as a term"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n:b", &definition_list)?,
+        "<p>a\n:b</p>",
+        "should not support a description w/o whitespace after the marker"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n: \nb", &definition_list)?,
+        "<p>a\n:\nb</p>",
+        "should not support an empty description"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n   : b", &definition_list)?,
+        "<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>",
+        "should support up to three spaces of indent"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n    : b", &definition_list)?,
+        "<p>a\n: b</p>",
+        "should not support four spaces of indent"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> : b", &definition_list)?,
+        "<blockquote>\n<dl>\n<dt>a</dt>\n<dd>b</dd>\n</dl>\n</blockquote>",
+        "should support definition lists in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n: b", &definition_list)?,
+        "<blockquote>\n<p>a\n: b</p>\n</blockquote>",
+        "should not support a lazy description"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n\n: b", &definition_list)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>\n<p>: b</p>",
+        "should not support a term in another container"
+    );
+
+    assert_eq!(
+        to_mdast("a\n: b", &definition_list.parse)?,
+        Node::Root(Root {
+            children: vec![Node::DefinitionList(DefinitionList {
+                children: vec![
+                    Node::DefinitionListTerm(DefinitionListTerm {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                    }),
+                    Node::DefinitionListDescription(DefinitionListDescription {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(2, 3, 4, 2, 4, 5))
+                        })],
+                        position: Some(Position::new(2, 1, 2, 2, 4, 5)),
+                        spread: false
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 2, 4, 5))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 4, 5))
+        }),
+        "should support definition lists in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("a\n\n: b", &definition_list.parse)?,
+        Node::Root(Root {
+            children: vec![Node::DefinitionList(DefinitionList {
+                children: vec![
+                    Node::DefinitionListTerm(DefinitionListTerm {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                    }),
+                    Node::DefinitionListDescription(DefinitionListDescription {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(3, 3, 5, 3, 4, 6))
+                        })],
+                        position: Some(Position::new(3, 1, 3, 3, 4, 6)),
+                        spread: true
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 3, 4, 6))
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 4, 6))
+        }),
+        "should support spread descriptions in mdast"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn emoji() -> Result<(), String> {
+    let emoji = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                emoji: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html(":smile:"),
+        "<p>:smile:</p>",
+        "should not support emoji by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :smile: b", &emoji)?,
+        "<p>a 😄 b</p>",
+        "should support emoji w/ `emoji: true`"
+    );
+
+    assert_eq!(
+        to_html_with_options(":+1: :-1: :100: :white_check_mark:", &emoji)?,
+        "<p>👍 👎 💯 ✅</p>",
+        "should support `+`, `-`, digits, and `_` in names"
+    );
+
+    assert_eq!(
+        to_html_with_options(":heart:", &emoji)?,
+        "<p>❤\u{fe0f}</p>",
+        "should support emoji that expand to multiple characters"
+    );
+
+    assert_eq!(
+        to_html_with_options(":shipit: :Smile:", &emoji)?,
+        "<p>:shipit: :Smile:</p>",
+        "should not support unknown names"
+    );
+
+    assert_eq!(
+        to_html_with_options(":smile", &emoji)?,
+        "<p>:smile</p>",
+        "should not support a missing closing marker"
+    );
+
+    assert_eq!(
+        to_html_with_options("::", &emoji)?,
+        "<p>::</p>",
+        "should not support an empty name"
+    );
+
+    assert_eq!(
+        to_html_with_options(": smile:", &emoji)?,
+        "<p>: smile:</p>",
+        "should not support whitespace in names"
+    );
+
+    assert_eq!(
+        to_html_with_options("::smile::", &emoji)?,
+        "<p>:😄:</p>",
+        "should support adjacent colons around emoji"
+    );
+
+    assert_eq!(
+        to_html_with_options(":smile::smile:", &emoji)?,
+        "<p>😄😄</p>",
+        "should support adjacent emoji"
+    );
+
+    assert_eq!(
+        to_html_with_options(":nope::smile:", &emoji)?,
+        "<p>:nope:😄</p>",
+        "should support emoji after an unknown shortcode"
+    );
+
+    assert_eq!(
+        to_html_with_options("a:smile: 10:30:00", &emoji)?,
+        "<p>a:smile: 10:30:00</p>",
+        "should not support emoji after ASCII alphanumerics"
+    );
+
+    assert_eq!(
+        to_html_with_options("a :smile:b (:smile:)", &emoji)?,
+        "<p>a 😄b (😄)</p>",
+        "should support emoji before alphanumerics, and after punctuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("http://a.b :smile:", &emoji)?,
+        "<p>http://a.b 😄</p>",
+        "should support colons in URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("`:smile:`", &emoji)?,
+        "<p><code>:smile:</code></p>",
+        "should not support emoji in code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\n:smile:\n```", &emoji)?,
+        "<pre><code>:smile:\n</code></pre>",
+        "should not support emoji in code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\:smile:", &emoji)?,
+        "<p>:smile:</p>",
+        "should not support an escaped opening marker"
+    );
+
+    assert_eq!(
+        to_html_with_options("[:smile:](<:smile:>) ![:smile:](a)", &emoji)?,
+        "<p><a href=\"\">😄</a> <img src=\"a\" alt=\"😄\" /></p>",
+        "should support emoji in labels, but not in destinations"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            ":shipit: :smile:",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        emoji: true,
+                        ..Default::default()
+                    },
+                    emoji_shortcodes: vec![
+                        ("shipit".into(), "🐿️".into()),
+                        ("smile".into(), "<:)>".into())
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>🐿️ &lt;:)&gt;</p>",
+        "should support custom shortcodes, which take precedence"
+    );
+
+    assert_eq!(
+        to_mdast("a :smile: b", &emoji.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a 😄 b".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 12, 11))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 12, 11))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 12, 11))
+        }),
+        "should support emoji as `Text`s in mdast"
+    );
+
+    Ok(())
+}
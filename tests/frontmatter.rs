@@ -1,6 +1,6 @@
 use markdown::{
     mdast::{Node, Root, Toml, Yaml},
-    to_html, to_html_with_options, to_mdast,
+    to_html, to_html_with_options, to_html_with_output, to_mdast,
     unist::Position,
     Constructs, Options, ParseOptions,
 };
@@ -37,6 +37,36 @@ fn frontmatter() -> Result<(), String> {
         "should support frontmatter (toml)"
     );
 
+    let output = to_html_with_output("---\ntitle: Jupyter\n---\n# a", &frontmatter)?;
+
+    assert_eq!(
+        (output.value, output.frontmatter),
+        ("<h1>a</h1>".into(), Some("title: Jupyter".into())),
+        "should expose the raw frontmatter"
+    );
+
+    assert_eq!(
+        to_html_with_output(
+            "+++\r\ntitle = \"Jupyter\"\r\n\r\nx = 1\r\n+++",
+            &frontmatter
+        )?
+        .frontmatter,
+        Some("title = \"Jupyter\"\r\n\r\nx = 1".into()),
+        "should expose the raw frontmatter, with CRLF line endings as-is"
+    );
+
+    assert_eq!(
+        to_html_with_output("---\n---", &frontmatter)?.frontmatter,
+        Some("".into()),
+        "should expose empty frontmatter"
+    );
+
+    assert_eq!(
+        to_html_with_output("# a", &frontmatter)?.frontmatter,
+        None,
+        "should not expose frontmatter if there is none"
+    );
+
     assert_eq!(
         to_html_with_options("---\n---", &frontmatter)?,
         "",
@@ -116,6 +116,42 @@ fn gfm_autolink_literal() -> Result<(), String> {
         "should support non-ascii characters in a path"
     );
 
+    assert_eq!(
+        to_html_with_options("xwww.a.com", &Options::gfm())?,
+        "<p>xwww.a.com</p>",
+        "should *not* support a www literal after a letter"
+    );
+
+    assert_eq!(
+        to_html_with_options("1www.a.com", &Options::gfm())?,
+        "<p>1www.a.com</p>",
+        "should *not* support a www literal after a digit"
+    );
+
+    assert_eq!(
+        to_html_with_options(" www.a.com", &Options::gfm())?,
+        "<p><a href=\"http://www.a.com\">www.a.com</a></p>",
+        "should support a www literal after whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("(www.a.com)", &Options::gfm())?,
+        "<p>(<a href=\"http://www.a.com\">www.a.com</a>)</p>",
+        "should support a www literal after a left paren"
+    );
+
+    assert_eq!(
+        to_html_with_options("*www.a.com* _www.b.com_ ~www.c.com~", &Options::gfm())?,
+        "<p><em><a href=\"http://www.a.com\">www.a.com</a></em> <em><a href=\"http://www.b.com\">www.b.com</a></em> <del><a href=\"http://www.c.com\">www.c.com</a></del></p>",
+        "should support a www literal after attention markers"
+    );
+
+    assert_eq!(
+        to_html_with_options("https://www.a.com", &Options::gfm())?,
+        "<p><a href=\"https://www.a.com\">https://www.a.com</a></p>",
+        "should *not* add `http://` to a www literal that has a protocol"
+    );
+
     assert_eq!(
         to_html_with_options("www.-a.b", &Options::gfm())?,
         "<p><a href=\"http://www.-a.b\">www.-a.b</a></p>",
@@ -182,6 +218,30 @@ fn gfm_autolink_literal() -> Result<(), String> {
         "should support two closing parens in a path"
     );
 
+    assert_eq!(
+        to_html_with_options("(https://x.com)", &Options::gfm())?,
+        "<p>(<a href=\"https://x.com\">https://x.com</a>)</p>",
+        "should support a literal after an opening paren, and exclude the closing paren"
+    );
+
+    assert_eq!(
+        to_html_with_options("[see https://x.com]", &Options::gfm())?,
+        "<p>[see <a href=\"https://x.com\">https://x.com</a>]</p>",
+        "should support a literal in brackets, and exclude the closing bracket"
+    );
+
+    assert_eq!(
+        to_html_with_options("\"https://x.com\"", &Options::gfm())?,
+        "<p>&quot;<a href=\"https://x.com\">https://x.com</a>&quot;</p>",
+        "should support a literal in quotes, and exclude the closing quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("(www.x.com)", &Options::gfm())?,
+        "<p>(<a href=\"http://www.x.com\">www.x.com</a>)</p>",
+        "should support a www literal after an opening paren"
+    );
+
     assert_eq!(
         to_html_with_options("ftp://a/b/c.txt", &Options::gfm())?,
         "<p>ftp://a/b/c.txt</p>",
@@ -2734,6 +2794,30 @@ www.a/~
         "should match www (path start) like GitHub does (except for the bracket bug)"
     );
 
+    assert_eq!(
+        to_html_with_options("**bold www.a.com**", &Options::gfm())?,
+        "<p><strong>bold <a href=\"http://www.a.com\">www.a.com</a></strong></p>",
+        "should support GFM autolink literals in strong"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a https://b.c* ~~d@e.f~~", &Options::gfm())?,
+        "<p><em>a <a href=\"https://b.c\">https://b.c</a></em> <del><a href=\"mailto:d@e.f\">d@e.f</a></del></p>",
+        "should support GFM autolink literals in emphasis and strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("| https://a.b |\n| - |\n| **x www.a.com** |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th><a href=\"https://a.b\">https://a.b</a></th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><strong>x <a href=\"http://www.a.com\">www.a.com</a></strong></td>\n</tr>\n</tbody>\n</table>",
+        "should support GFM autolink literals in table cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("- www.a.com\n> a@b.c\n# https://d.e", &Options::gfm())?,
+        "<ul>\n<li><a href=\"http://www.a.com\">www.a.com</a></li>\n</ul>\n<blockquote>\n<p><a href=\"mailto:a@b.c\">a@b.c</a></p>\n</blockquote>\n<h1><a href=\"https://d.e\">https://d.e</a></h1>",
+        "should support GFM autolink literals in list items, block quotes, and headings"
+    );
+
     assert_eq!(
         to_mdast(
             "a https://alpha.com b bravo@charlie.com c www.delta.com d xmpp:echo@foxtrot.com e mailto:golf@hotel.com f.",
@@ -52,6 +52,30 @@ fn gfm_footnote() -> Result<(), String> {
         "should support `options.gfm_footnote_label`, `options.gfm_footnote_back_label`"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "Note.[^a]\n\n[^a]: Dinge",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_label: Some("Fußnoten & <Quellen>".into()),
+                    gfm_footnote_back_label: Some("Zurück zum \"Inhalt\" & <oben>".into()),
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p>Note.<sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Fußnoten &amp; &lt;Quellen&gt;</h2>
+<ol>
+<li id=\"user-content-fn-a\">
+<p>Dinge <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Zurück zum &quot;Inhalt&quot; &amp; &lt;oben&gt;\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should encode `options.gfm_footnote_label`, `options.gfm_footnote_back_label`"
+    );
+
     assert_eq!(
         to_html_with_options(
             "[^a]\n\n[^a]: b",
@@ -121,6 +145,73 @@ fn gfm_footnote() -> Result<(), String> {
         "should support `options.gfm_footnote_clobber_prefix`"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "a[^b] c[^d] e[^b]\n\n[^b]: f\n[^d]: g",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_call_template: Some(
+                        "<a class=\"note\" href=\"#{id}\" id=\"{ref_id}\">[{index}]</a>".into()
+                    ),
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p>a<a class=\"note\" href=\"#user-content-fn-b\" id=\"user-content-fnref-b\">[1]</a> c<a class=\"note\" href=\"#user-content-fn-d\" id=\"user-content-fnref-d\">[2]</a> e<a class=\"note\" href=\"#user-content-fn-b\" id=\"user-content-fnref-b-2\">[1]</a></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-b\">
+<p>f <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#user-content-fnref-b-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>
+</li>
+<li id=\"user-content-fn-d\">
+<p>g <a href=\"#user-content-fnref-d\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support `options.gfm_footnote_call_template`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^b]\n\n[^b]: c",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_call_template: Some(
+                        "<sup title=\"{ref_id}\">{index}{unknown}{</sup>".into()
+                    ),
+                    gfm_footnote_clobber_prefix: Some("\"<&>".into()),
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?
+        .lines()
+        .next()
+        .unwrap(),
+        "<p>a<sup title=\"&quot;&lt;&amp;&gt;fnref-b\">1{unknown}{</sup></p>",
+        "should encode values in `options.gfm_footnote_call_template`, and keep unknown placeholders"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a[^b]](c)\n\n[^b]: d",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_call_template: Some("[{index}]".into()),
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?
+        .lines()
+        .next()
+        .unwrap(),
+        "<p><img src=\"c\" alt=\"a\" /></p>",
+        "should not use `options.gfm_footnote_call_template` in image alts"
+    );
+
     assert_eq!(
         to_html_with_options("A paragraph.\n\n[^a]: whatevs", &Options::gfm())?,
         "<p>A paragraph.</p>\n",
@@ -20,6 +20,24 @@ fn gfm_table() -> Result<(), String> {
         "should support tables"
     );
 
+    assert_eq!(
+        to_html_with_options("| a | b | c | d |\n| --- | :-- | --: | :-: |\n| e | f | g | h |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th align=\"left\">b</th>\n<th align=\"right\">c</th>\n<th align=\"center\">d</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>e</td>\n<td align=\"left\">f</td>\n<td align=\"right\">g</td>\n<td align=\"center\">h</td>\n</tr>\n</tbody>\n</table>",
+        "should support all alignments, in head and body cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| :-: |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th align=\"center\">a</th>\n</tr>\n</thead>\n</table>",
+        "should support a center alignment w/ a single dash"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| : |", &Options::gfm())?,
+        "<p>| a |\n| : |</p>",
+        "should not support an alignment w/o dashes"
+    );
+
     assert_eq!(
         to_html_with_options("| a |", &Options::gfm())?,
         "<p>| a |</p>",
@@ -128,6 +146,36 @@ fn gfm_table() -> Result<(), String> {
         "should support empty body cells"
     );
 
+    assert_eq!(
+        to_html_with_options("a | b\n--- | ---\n1 | 2", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>1</td>\n<td>2</td>\n</tr>\n</tbody>\n</table>",
+        "should support rows w/o leading or trailing pipes"
+    );
+
+    assert_eq!(
+        to_html_with_options("<x> | y\n--- | ---\n1 | 2", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>&lt;x&gt;</th>\n<th>y</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>1</td>\n<td>2</td>\n</tr>\n</tbody>\n</table>",
+        "should support header rows starting w/ a less than"
+    );
+
+    assert_eq!(
+        to_html_with_options("<\n-:", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th align=\"right\">&lt;</th>\n</tr>\n</thead>\n</table>",
+        "should support header rows that are just a less than"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| 1 |\n2", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>1</td>\n<td></td>\n</tr>\n<tr>\n<td>2</td>\n<td></td>\n</tr>\n</tbody>\n</table>",
+        "should fill body rows w/ too few cells w/ empty cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("a | b\n--- | ---\n1 | 2 | 3", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>1</td>\n<td>2</td>\n</tr>\n</tbody>\n</table>",
+        "should drop extra cells in body rows"
+    );
+
     assert_eq!(
         to_html_with_options(":\n|-|\n|a|\n\nb\n|-|\n|c|\n\n|\n|-|\n|d|\n\n|\n|-|\n|e|\n\n|:\n|-|\n|f|\n\n||\n|-|\n|g|\n\n| |\n|-|\n|h|\n", &Options::gfm())?,
         "<table>\n<thead>\n<tr>\n<th>:</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>a</td>\n</tr>\n</tbody>\n</table>\n<table>\n<thead>\n<tr>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>c</td>\n</tr>\n</tbody>\n</table>\n<p>|\n|-|\n|d|</p>\n<p>|\n|-|\n|e|</p>\n<table>\n<thead>\n<tr>\n<th>:</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>f</td>\n</tr>\n</tbody>\n</table>\n<table>\n<thead>\n<tr>\n<th></th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>g</td>\n</tr>\n</tbody>\n</table>\n<table>\n<thead>\n<tr>\n<th></th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>h</td>\n</tr>\n</tbody>\n</table>\n",
@@ -239,6 +239,30 @@ Text.</li>
         "should handle things like GitHub"
     );
 
+    assert_eq!(
+        to_html_with_options("- [x] one\n\n  more text", &Options::gfm())?,
+        "<ul>\n<li>\n<p><input type=\"checkbox\" disabled=\"\" checked=\"\" /> one</p>\n<p>more text</p>\n</li>\n</ul>",
+        "should support a second paragraph in a task list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("- [x] a\n  - [ ] b\n  - c\n\n  [x] d", &Options::gfm())?,
+        "<ul>\n<li>\n<p><input type=\"checkbox\" disabled=\"\" checked=\"\" /> a</p>\n<ul>\n<li><input type=\"checkbox\" disabled=\"\" /> b</li>\n<li>c</li>\n</ul>\n<p>[x] d</p>\n</li>\n</ul>",
+        "should support a sub-list in a task list item, and only check the first paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("- [ ] a\n  [x] b", &Options::gfm())?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" /> a\n[x] b</li>\n</ul>",
+        "should not support a check on a continuation line"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n\n  [x] b", &Options::gfm())?,
+        "<ul>\n<li>\n<p>a</p>\n<p>[x] b</p>\n</li>\n</ul>",
+        "should not support a check in a later paragraph"
+    );
+
     assert_eq!(
         to_mdast("* [x] a\n* [ ] b\n* c", &ParseOptions::gfm())?,
         Node::Root(Root {
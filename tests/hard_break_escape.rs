@@ -38,6 +38,30 @@ fn hard_break_escape() -> Result<(), String> {
         "should not support escape hard breaks at the end of a paragraph"
     );
 
+    assert_eq!(
+        to_html("foo\\\n"),
+        "<p>foo\\</p>\n",
+        "should not support escape hard breaks at the end of a paragraph, before a line ending"
+    );
+
+    assert_eq!(
+        to_html("foo\\\n\nbar"),
+        "<p>foo\\</p>\n<p>bar</p>",
+        "should not support escape hard breaks before a blank line"
+    );
+
+    assert_eq!(
+        to_html("foo\\\n***"),
+        "<p>foo\\</p>\n<hr />",
+        "should not support escape hard breaks before an interrupting block"
+    );
+
+    assert_eq!(
+        to_html("> foo\\\n\nbar"),
+        "<blockquote>\n<p>foo\\</p>\n</blockquote>\n<p>bar</p>",
+        "should not support escape hard breaks at the end of a block quote"
+    );
+
     assert_eq!(
         to_html("### foo\\"),
         "<h3>foo\\</h3>",
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Heading, Node, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -158,6 +158,39 @@ fn heading_setext() -> Result<(), String> {
         "should support line endings in setext headings"
     );
 
+    assert_eq!(
+        to_html("a\nb\nc\n==="),
+        "<h1>a\nb\nc</h1>",
+        "should use all lines of the paragraph as the heading"
+    );
+
+    assert_eq!(
+        to_html("*a\nb* **c\nd**\n---"),
+        "<h2><em>a\nb</em> <strong>c\nd</strong></h2>",
+        "should support attention across lines in setext headings"
+    );
+
+    assert_eq!(
+        to_html("- a\n  b\n  ---"),
+        "<ul>\n<li>\n<h2>a\nb</h2>\n</li>\n</ul>",
+        "should support multiple lines in setext headings in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\nb\n===",
+            &Options {
+                compile: CompileOptions {
+                    hard_break_all: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1>a<br />\nb</h1>",
+        "should support `hard_break_all` in setext headings"
+    );
+
     assert_eq!(
         to_html("---\nFoo\n---\nBar\n---\nBaz"),
         "<hr />\n<h2>Foo</h2>\n<h2>Bar</h2>\n<p>Baz</p>",
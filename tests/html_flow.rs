@@ -823,6 +823,33 @@ okay.",
         "should not support lazyness (2)"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "<details>\n<summary>*a*</summary>\n\n*b*\n\n</details>",
+            &danger
+        )?,
+        "<details>\n<summary>*a*</summary>\n<p><em>b</em></p>\n</details>",
+        "should support `details` and `summary`, ending at a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<DeTaIlS>\nb", &danger)?,
+        "<p>a</p>\n<DeTaIlS>\nb",
+        "should support block names case-insensitively, interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<search>\n\nb", &danger)?,
+        "<p>a</p>\n<search>\n<p>b</p>",
+        "should support `search`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n<source>", &danger)?,
+        "<p>a\n<source></p>",
+        "should not support `source`"
+    );
+
     Ok(())
 }
 
@@ -29,6 +29,30 @@ fn html_text() -> Result<(), String> {
         "should support opening tags"
     );
 
+    assert_eq!(
+        to_html_with_options("a < b", &danger)?,
+        "<p>a &lt; b</p>",
+        "should not support a less than followed by whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <1> <-b> <=c", &danger)?,
+        "<p>a &lt;1&gt; &lt;-b&gt; &lt;=c</p>",
+        "should not support a less than followed by something other than a letter, `/`, `!`, or `?`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <", &danger)?,
+        "<p>a &lt;</p>",
+        "should not support a less than at the end"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <valid-tag>", &danger)?,
+        "<p>a <valid-tag></p>",
+        "should support tag names w/ dashes"
+    );
+
     assert_eq!(
         to_html_with_options("<a/><b2/>", &danger)?,
         "<p><a/><b2/></p>",
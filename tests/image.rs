@@ -1,8 +1,8 @@
 use markdown::{
-    mdast::{Definition, Image, ImageReference, Node, Paragraph, ReferenceKind, Root, Text},
+    mdast::{Definition, Image, ImageReference, Link, Node, Paragraph, ReferenceKind, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, ImageEmptyAlt, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -235,6 +235,100 @@ fn image() -> Result<(), String> {
         "should allow non-http protocols w/ `allowDangerousProtocol`"
     );
 
+    assert_eq!(
+        to_html("![a [b] c](/u)"),
+        "<p><img src=\"/u\" alt=\"a [b] c\" /></p>",
+        "should support balanced brackets in the description"
+    );
+
+    assert_eq!(
+        to_html("![a [b [c] d] e](/u)"),
+        "<p><img src=\"/u\" alt=\"a [b [c] d] e\" /></p>",
+        "should support nested balanced brackets in the description"
+    );
+
+    assert_eq!(
+        to_html("![a](/u"),
+        "<p>![a](/u</p>",
+        "should not support an unclosed resource"
+    );
+
+    assert_eq!(
+        to_html("![a [b](/u)"),
+        "<p>![a <a href=\"/u\">b</a></p>",
+        "should not support unbalanced brackets in the description"
+    );
+
+    let presentation = Options {
+        compile: CompileOptions {
+            image_empty_alt: ImageEmptyAlt::Presentation,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let drop = Options {
+        compile: CompileOptions {
+            image_empty_alt: ImageEmptyAlt::Drop,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("![](a.png)"),
+        "<p><img src=\"a.png\" alt=\"\" /></p>",
+        "should keep images w/ an empty alt by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("![](a.png \"b\")", &presentation)?,
+        "<p><img src=\"a.png\" alt=\"\" title=\"b\" role=\"presentation\" /></p>",
+        "should support `ImageEmptyAlt::Presentation`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b.png)", &presentation)?,
+        "<p><img src=\"b.png\" alt=\"a\" /></p>",
+        "should not add a role to images w/ an alt"
+    );
+
+    assert_eq!(
+        to_html_with_options("![][a]\n\n[a]: b.png", &presentation)?,
+        "<p><img src=\"b.png\" alt=\"\" role=\"presentation\" /></p>\n",
+        "should support `ImageEmptyAlt::Presentation` for references"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ![](b.png) c", &drop)?,
+        "<p>a  c</p>",
+        "should support `ImageEmptyAlt::Drop`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![](a.png)](b) ![c](d.png)", &drop)?,
+        "<p> <img src=\"d.png\" alt=\"c\" /></p>",
+        "should not drop images w/ an alt"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![](a.png)](b)", &drop)?,
+        "<p></p>",
+        "should drop links that are empty because of dropped images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![](a.png) c](b) [](d)", &drop)?,
+        "<p><a href=\"b\"> c</a> <a href=\"d\"></a></p>",
+        "should not drop links w/ other content, or links that were empty"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![](a.png)][b]\n\n[b]: c", &drop)?,
+        "<p></p>\n",
+        "should drop link references that are empty because of dropped images"
+    );
+
     assert_eq!(
         to_mdast(
             "a ![alpha]() b ![bravo](charlie 'delta') c.",
@@ -336,5 +430,28 @@ fn image() -> Result<(), String> {
         }),
         "should support image (reference) as `ImageReference`s in mdast"
     );
+
+    assert_eq!(
+        to_mdast("[![a](b)](c)", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Link(Link {
+                    children: vec![Node::Image(Image {
+                        alt: "a".into(),
+                        url: "b".into(),
+                        title: None,
+                        position: Some(Position::new(1, 2, 1, 1, 9, 8))
+                    })],
+                    url: "c".into(),
+                    title: None,
+                    position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 13, 12))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+        }),
+        "should support images in links in mdast"
+    );
+
     Ok(())
 }
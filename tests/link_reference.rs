@@ -368,6 +368,12 @@ fn link_reference() -> Result<(), String> {
         "should properly handle labels w/ character references and -escapes, and phrasing"
     );
 
+    assert_eq!(
+        to_html(&"[".repeat(100_000)),
+        format!("<p>{}</p>", "[".repeat(100_000)),
+        "should support many label starts w/o ends in linear time"
+    );
+
     // 999 `x` characters.
     let max = "x".repeat(999);
 
@@ -389,6 +395,36 @@ fn link_reference() -> Result<(), String> {
         "should not fail on a missing colon in a definition"
     );
 
+    assert_eq!(
+        to_html("[foo]: /url\n\n[foo][bar]"),
+        "<p>[foo][bar]</p>",
+        "should not fall back to a shortcut reference if a full reference is not defined"
+    );
+
+    assert_eq!(
+        to_html("[bar]: /url\n\n[foo][]"),
+        "<p>[foo][]</p>",
+        "should not support collapsed references that are not defined"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\n\n[foo] []"),
+        "<p><a href=\"/url\">foo</a> []</p>",
+        "should not support whitespace between label and collapsed reference (2)"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url1\n[bar]: /url2\n\n[foo] [bar]"),
+        "<p><a href=\"/url1\">foo</a> <a href=\"/url2\">bar</a></p>",
+        "should support shortcut references separated by whitespace"
+    );
+
+    assert_eq!(
+        to_html("[foo]: /url\n\n[foo][] [bar]"),
+        "<p><a href=\"/url\">foo</a> [bar]</p>",
+        "should not support undefined shortcut references after collapsed references"
+    );
+
     assert_eq!(
         to_html_with_options(
             "[x]()",
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Link, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Options,
+    CompileOptions, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -191,6 +191,24 @@ fn link_resource() -> Result<(), String> {
         "should support the other quotes in titles"
     );
 
+    assert_eq!(
+        to_html("[a](b \"c\\\"d\")"),
+        "<p><a href=\"b\" title=\"c&quot;d\">a</a></p>",
+        "should encode escaped double quotes in titles"
+    );
+
+    assert_eq!(
+        to_html("[a](b \"c & d < e > f\")"),
+        "<p><a href=\"b\" title=\"c &amp; d &lt; e &gt; f\">a</a></p>",
+        "should encode `&`, `<`, and `>` in titles"
+    );
+
+    assert_eq!(
+        to_html("![a](b (c\\)\"d))"),
+        "<p><img src=\"b\" alt=\"a\" title=\"c)&quot;d\" /></p>",
+        "should encode double quotes in image titles"
+    );
+
     assert_eq!(
         to_html("[link](   /uri\n  \"title\"  )"),
         "<p><a href=\"/uri\" title=\"title\">link</a></p>",
@@ -269,6 +287,30 @@ fn link_resource() -> Result<(), String> {
         "should not support links in links (3)"
     );
 
+    assert_eq!(
+        to_html("[a [b](/u) c](/u2)"),
+        "<p>[a <a href=\"/u\">b</a> c](/u2)</p>",
+        "should not support links in links (4)"
+    );
+
+    assert_eq!(
+        to_html("[a [b][] c](/u2)\n\n[b]: /u"),
+        "<p>[a <a href=\"/u\">b</a> c](/u2)</p>\n",
+        "should not support links in links (5)"
+    );
+
+    assert_eq!(
+        to_html("[a ![b](/u) c](/u2)"),
+        "<p><a href=\"/u2\">a <img src=\"/u\" alt=\"b\" /> c</a></p>",
+        "should support images in links"
+    );
+
+    assert_eq!(
+        to_html("[a](/u) [b](/u2)"),
+        "<p><a href=\"/u\">a</a> <a href=\"/u2\">b</a></p>",
+        "should support links after links"
+    );
+
     assert_eq!(
         to_html("*[foo*](/uri)"),
         "<p>*<a href=\"/uri\">foo*</a></p>",
@@ -465,6 +507,57 @@ fn link_resource() -> Result<(), String> {
         "should support a single NUL character as a link resource"
     );
 
+    let external = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            external_link_rel: Some("nofollow noopener".into()),
+            external_link_target: Some("_blank".into()),
+            ..CompileOptions::gfm()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](https://b.c)", &external)?,
+        "<p><a href=\"https://b.c\" rel=\"nofollow noopener\" target=\"_blank\">a</a></p>",
+        "should support `external_link_rel`, `external_link_target` on links w/ a protocol"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](//b.c \"d\")", &external)?,
+        "<p><a href=\"//b.c\" title=\"d\" rel=\"nofollow noopener\" target=\"_blank\">a</a></p>",
+        "should support `external_link_rel`, `external_link_target` on protocol-relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](#b) [c](/d) [e](f.html) [g](mailto:h@i.j)", &external)?,
+        "<p><a href=\"#b\">a</a> <a href=\"/d\">c</a> <a href=\"f.html\">e</a> <a href=\"mailto:h@i.j\">g</a></p>",
+        "should not add `external_link_rel`, `external_link_target` to fragments, relative links, or links w/o host"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]\n\n[a]: https://b.c", &external)?,
+        "<p><a href=\"https://b.c\" rel=\"nofollow noopener\" target=\"_blank\">a</a></p>\n",
+        "should support `external_link_rel`, `external_link_target` on references"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://b.c> www.d.e", &external)?,
+        "<p><a href=\"https://b.c\" rel=\"nofollow noopener\" target=\"_blank\">https://b.c</a> <a href=\"http://www.d.e\" rel=\"nofollow noopener\" target=\"_blank\">www.d.e</a></p>",
+        "should support `external_link_rel`, `external_link_target` on autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](https://b.c)", &external)?,
+        "<p><img src=\"https://b.c\" alt=\"a\" /></p>",
+        "should not add `external_link_rel`, `external_link_target` to images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](javascript:alert(1))", &external)?,
+        "<p><a href=\"\">a</a></p>",
+        "should not add `external_link_rel`, `external_link_target` to dangerous links"
+    );
+
     assert_eq!(
         to_mdast(
             "a [alpha]() b [bravo](charlie 'delta') c.",
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{List, ListItem, Node, Paragraph, Root, Text},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, ListIndent, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -162,6 +162,36 @@ fn list() -> Result<(), String> {
         "should support indented code in list items (8)"
     );
 
+    assert_eq!(
+        to_html("- a\n\n  b"),
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "should use the content column after 1 space after a list marker"
+    );
+
+    assert_eq!(
+        to_html("-    a\n\n     b"),
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "should use the content column after 4 spaces after a list marker"
+    );
+
+    assert_eq!(
+        to_html("-    a\n\n    b"),
+        "<ul>\n<li>a</li>\n</ul>\n<pre><code>b\n</code></pre>",
+        "should not continue if less indented than the content column after 4 spaces after a list marker"
+    );
+
+    assert_eq!(
+        to_html("-     a\n\n  b"),
+        "<ul>\n<li>\n<pre><code>a\n</code></pre>\n<p>b</p>\n</li>\n</ul>",
+        "should use the content column after 1 space, and indented code, after 5 spaces after a list marker"
+    );
+
+    assert_eq!(
+        to_html("-      a"),
+        "<ul>\n<li>\n<pre><code> a\n</code></pre>\n</li>\n</ul>",
+        "should keep further spaces in indented code after 6 spaces after a list marker"
+    );
+
     assert_eq!(
         to_html("-\n  foo\n-\n  ```\n  bar\n  ```\n-\n      baz"),
         "<ul>\n<li>foo</li>\n<li>\n<pre><code>bar\n</code></pre>\n</li>\n<li>\n<pre><code>baz\n</code></pre>\n</li>\n</ul>",
@@ -174,6 +204,36 @@ fn list() -> Result<(), String> {
         "should support blank first lines (2)"
     );
 
+    assert_eq!(
+        to_html("- \n  foo"),
+        "<ul>\n<li>foo</li>\n</ul>",
+        "should support blank first lines (3)"
+    );
+
+    assert_eq!(
+        to_html("-\t\n  foo"),
+        "<ul>\n<li>foo</li>\n</ul>",
+        "should support blank first lines w/ a tab"
+    );
+
+    assert_eq!(
+        to_html("1.\n   foo"),
+        "<ol>\n<li>foo</li>\n</ol>",
+        "should support blank first lines (ordered)"
+    );
+
+    assert_eq!(
+        to_html("-\n  foo\n\n  bar"),
+        "<ul>\n<li>\n<p>foo</p>\n<p>bar</p>\n</li>\n</ul>",
+        "should use the content column after a blank first line for further lines"
+    );
+
+    assert_eq!(
+        to_html("-\n foo"),
+        "<ul>\n<li></li>\n</ul>\n<p>foo</p>",
+        "should not continue if less indented than the content column after a blank first line"
+    );
+
     assert_eq!(
         to_html("-\n\n  foo"),
         "<ul>\n<li></li>\n</ul>\n<p>foo</p>",
@@ -353,6 +413,48 @@ fn list() -> Result<(), String> {
         "should “interrupt” a blank line (2)"
     );
 
+    assert_eq!(
+        to_html("3. a\n4. b"),
+        "<ol start=\"3\">\n<li>a</li>\n<li>b</li>\n</ol>",
+        "should use the number of the first item as `start`"
+    );
+
+    assert_eq!(
+        to_html("1. a\n3. b"),
+        "<ol>\n<li>a</li>\n<li>b</li>\n</ol>",
+        "should not add `start` if the first item is `1`, ignoring later numbers"
+    );
+
+    assert_eq!(
+        to_html("3) a\n4) b"),
+        "<ol start=\"3\">\n<li>a</li>\n<li>b</li>\n</ol>",
+        "should support `start` w/ parens"
+    );
+
+    assert_eq!(
+        to_html("- a\n- b\n\n\n"),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n",
+        "should not be loose w/ trailing blank lines in the last item"
+    );
+
+    assert_eq!(
+        to_html("> - a\n> - b\n>\n>\n"),
+        "<blockquote>\n<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n</blockquote>\n",
+        "should not be loose w/ trailing blank lines in the last item, in a block quote"
+    );
+
+    assert_eq!(
+        to_html("- a\n- b\n\n\n- c"),
+        "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n<li>\n<p>c</p>\n</li>\n</ul>",
+        "should be loose w/ blank lines between items"
+    );
+
+    assert_eq!(
+        to_html("- a\n\n  b\n\n\n"),
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>\n",
+        "should be loose w/ blank lines between blocks in an item, w/ trailing blank lines"
+    );
+
     assert_eq!(
         to_html("a\n1. b"),
         "<p>a</p>\n<ol>\n<li>b</li>\n</ol>",
@@ -589,6 +691,72 @@ fn list() -> Result<(), String> {
         "should support turning off lists"
     );
 
+    assert_eq!(
+        to_html("- a\n\n b"),
+        "<ul>\n<li>a</li>\n</ul>\n<p>b</p>",
+        "should not support content indented less than the list item by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "- a\n\n b",
+            &Options {
+                parse: ParseOptions {
+                    list_item_indent: ListIndent::One,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "should support content indented by one space w/ `ListIndent::One`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "1.  a\n\n b\n\n      c",
+            &Options {
+                parse: ParseOptions {
+                    list_item_indent: ListIndent::One,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<ol>\n<li>\n<p>a</p>\n<p>b</p>\n<pre><code> c\n</code></pre>\n</li>\n</ol>",
+        "should count indented code from one space w/ `ListIndent::One`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "- a\n - b",
+            &Options {
+                parse: ParseOptions {
+                    list_item_indent: ListIndent::One,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>",
+        "should nest lists indented by one space w/ `ListIndent::One`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "- a\n\n  b\n\n- c\n\n    d",
+            &Options {
+                parse: ParseOptions {
+                    list_item_indent: ListIndent::Tab,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<ul>\n<li>a</li>\n</ul>\n<p>b</p>\n<ul>\n<li>\n<p>c</p>\n<p>d</p>\n</li>\n</ul>",
+        "should require indenting to a tab stop w/ `ListIndent::Tab`"
+    );
+
     assert_eq!(
         to_mdast("* a", &Default::default())?,
         Node::Root(Root {
@@ -615,6 +783,32 @@ fn list() -> Result<(), String> {
         "should support lists, list items as `List`, `ListItem`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("-\n  foo", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::List(List {
+                ordered: false,
+                spread: false,
+                start: None,
+                children: vec![Node::ListItem(ListItem {
+                    checked: None,
+                    spread: false,
+                    children: vec![Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "foo".into(),
+                            position: Some(Position::new(2, 3, 4, 2, 6, 7))
+                        }),],
+                        position: Some(Position::new(2, 3, 4, 2, 6, 7))
+                    })],
+                    position: Some(Position::new(1, 1, 0, 2, 6, 7))
+                })],
+                position: Some(Position::new(1, 1, 0, 2, 6, 7))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 6, 7))
+        }),
+        "should support list items w/ blank first lines in mdast"
+    );
+
     assert_eq!(
         to_mdast("3. a\n4. b", &Default::default())?,
         Node::Root(Root {
@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Math, Node, Root},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -255,6 +255,112 @@ fn math_flow() -> Result<(), String> {
         "should not support lazyness (3)"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "$$\n\\begin{aligned}\na &= b \\\\\nc &< d\n\\end{aligned}\n$$",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    math_flow_tag_name: Some("div".into()),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<div class=\"math math-display\">\\begin{aligned}\na &amp;= b \\\\\nc &amp;&lt; d\n\\end{aligned}\n</div>",
+        "should support a multiline display math block w/ `math_flow_tag_name`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$$\n$$\n\n$$\na",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    math_flow_tag_name: Some("div".into()),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<div class=\"math math-display\"></div>\n<div class=\"math math-display\">a\n</div>\n",
+        "should support empty and unclosed math (flow) w/ `math_flow_tag_name`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> $$\n> a\n\n~~~\nb\n~~~",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    math_flow_tag_name: Some("div".into()),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<blockquote>\n<div class=\"math math-display\">a\n</div>\n</blockquote>\n<pre><code>b\n</code></pre>",
+        "should not affect code (fenced) w/ `math_flow_tag_name`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$$\na\n$$",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    math_flow_tag_name: Some("div".into()),
+                    math_flow_class: Some("katex-display".into()),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<div class=\"katex-display\">a\n</div>",
+        "should support `math_flow_class` w/ `math_flow_tag_name`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$$\na\n$$",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_flow: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    math_flow_class: Some("a\"b".into()),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<pre><code class=\"a&quot;b\">a\n</code></pre>",
+        "should support `math_flow_class` w/o `math_flow_tag_name`"
+    );
+
     assert_eq!(
         to_mdast("$$extra\nabc\ndef\n$$", &math.parse)?,
         Node::Root(Root {
@@ -188,6 +188,18 @@ fn mdx_esm() -> Result<(), String> {
         "should support a reexport from another esm block (2)"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "import a from 'b'\n\nimport is a word",
+            &Options {
+                parse: ParseOptions::mdx(),
+                ..Default::default()
+            }
+        )?,
+        "<p>import a from 'b'</p>\n<p>import is a word</p>",
+        "should treat import/exports as markdown w/o `mdx_esm_parse`"
+    );
+
     let cases = vec![
         ("default", "import a from \"b\""),
         ("whole", "import * as a from \"b\""),
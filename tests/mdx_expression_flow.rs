@@ -76,6 +76,12 @@ fn mdx_expression_flow_agnostic() -> Result<(), String> {
         "should crash if no closing brace is found (2)"
     );
 
+    assert_eq!(
+        to_html_with_options("{a {b} {c {d}}}\ne", &mdx)?,
+        "<p>e</p>",
+        "should support nested braces"
+    );
+
     assert_eq!(
         to_html_with_options("{\n}\na", &mdx)?,
         "<p>a</p>",
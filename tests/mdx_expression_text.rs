@@ -180,6 +180,12 @@ fn mdx_expression_text_agnostic() -> Result<(), String> {
         "should crash if no closing brace is found (2)"
     );
 
+    assert_eq!(
+        to_html_with_options("a {b {c} d} e", &mdx)?,
+        "<p>a  e</p>",
+        "should support nested braces"
+    );
+
     assert_eq!(
         to_html_with_options("a {\n} b", &mdx)?,
         "<p>a  b</p>",
@@ -1,5 +1,8 @@
 use markdown::{
-    mdast::{List, ListItem, MdxJsxFlowElement, Node, Paragraph, Root, Text},
+    mdast::{
+        AttributeContent, AttributeValue, AttributeValueExpression, List, ListItem,
+        MdxJsxAttribute, MdxJsxFlowElement, Node, Paragraph, Root, Text,
+    },
     to_html_with_options, to_mdast,
     unist::Position,
     Constructs, Options, ParseOptions,
@@ -224,5 +227,55 @@ fn mdx_jsx_flow_essence() -> Result<(), String> {
         "should support mdx jsx (flow) as `MdxJsxFlowElement`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("<a.b.c d=\"x\" e={1} f />", &mdx.parse)?,
+        Node::Root(Root {
+            children: vec![Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                name: Some("a.b.c".into()),
+                attributes: vec![
+                    AttributeContent::Property(MdxJsxAttribute {
+                        name: "d".into(),
+                        value: Some(AttributeValue::Literal("x".into())),
+                    }),
+                    AttributeContent::Property(MdxJsxAttribute {
+                        name: "e".into(),
+                        value: Some(AttributeValue::Expression(AttributeValueExpression {
+                            value: "1".into(),
+                            stops: vec![(0, 16)]
+                        })),
+                    }),
+                    AttributeContent::Property(MdxJsxAttribute {
+                        name: "f".into(),
+                        value: None,
+                    }),
+                ],
+                children: vec![],
+                position: Some(Position::new(1, 1, 0, 1, 24, 23))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 24, 23))
+        }),
+        "should support a self-closing element w/ a member name and attributes in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("<a:b>\n\nc\n\n</a:b>", &mdx.parse)?,
+        Node::Root(Root {
+            children: vec![Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                name: Some("a:b".into()),
+                attributes: vec![],
+                children: vec![Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "c".into(),
+                        position: Some(Position::new(3, 1, 7, 3, 2, 8))
+                    }),],
+                    position: Some(Position::new(3, 1, 7, 3, 2, 8))
+                })],
+                position: Some(Position::new(1, 1, 0, 5, 7, 16))
+            })],
+            position: Some(Position::new(1, 1, 0, 5, 7, 16))
+        }),
+        "should support an element w/ a namespace name and w/o attributes in mdast"
+    );
+
     Ok(())
 }
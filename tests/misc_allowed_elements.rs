@@ -0,0 +1,227 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn allowed_elements() -> Result<(), String> {
+    let no_headings = Options {
+        compile: CompileOptions {
+            allowed_elements: Some(vec![
+                "a".into(),
+                "em".into(),
+                "img".into(),
+                "p".into(),
+                "strong".into(),
+            ]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let text_only = Options {
+        compile: CompileOptions {
+            allowed_elements: Some(vec!["p".into()]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("# a\n\n***\n\n> b"),
+        "<h1>a</h1>\n<hr />\n<blockquote>\n<p>b</p>\n</blockquote>",
+        "should allow all elements by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a *b*\n\nc\n=\n\n###### d", &no_headings)?,
+        "<p>a <em>b</em></p>\n<p>c</p>\n<p>d</p>",
+        "should turn headings that are not allowed into paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a\n\n## b",
+            &Options {
+                compile: CompileOptions {
+                    allowed_elements: Some(vec!["h2".into(), "p".into()]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a</p>\n<h2>b</h2>",
+        "should support allowing some headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ![b *c*](d \"e\") f", &text_only)?,
+        "<p>a b c f</p>",
+        "should turn images that are not allowed into their alt text"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![a](b)](c)", &no_headings)?,
+        "<p><a href=\"c\"><img src=\"b\" alt=\"a\" /></a></p>",
+        "should support allowed images and links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a *b*](c) <https://d>", &text_only)?,
+        "<p>a b https://d</p>",
+        "should turn links that are not allowed into their content"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a* **b** `c`", &text_only)?,
+        "<p>a b c</p>",
+        "should turn emphasis, strong, and code (text) that are not allowed into their content"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n***\n\nb", &text_only)?,
+        "<p>a</p>\n<p>b</p>",
+        "should drop thematic breaks that are not allowed"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n\nb", &text_only)?,
+        "<p>a</p>\n<p>b</p>",
+        "should turn block quotes that are not allowed into their content"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n- b\n\n1. c\n\n   d\n2. e", &text_only)?,
+        "a\nb\n<p>c</p>\n<p>d</p>\n<p>e</p>",
+        "should turn lists and list items that are not allowed into their content"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "3. a",
+            &Options {
+                compile: CompileOptions {
+                    allowed_elements: Some(vec!["ol".into()]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<ol start=\"3\">\na\n</ol>",
+        "should support allowing lists but not list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a\n\n```js\nb\n```", &text_only)?,
+        "<p>a\n</p>\n<p>b\n</p>",
+        "should turn code (flow) that is not allowed into paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "```js\na\n```",
+            &Options {
+                compile: CompileOptions {
+                    allowed_elements: Some(vec!["code".into()]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<code class=\"language-js\">a\n</code>",
+        "should support allowing `code` but not `pre`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\n```\nb\n```\n\nc", &Options {
+            compile: CompileOptions {
+                allowed_elements: Some(vec![]),
+                ..Default::default()
+            },
+            ..Default::default()
+        })?,
+        "a\nb\nc",
+        "should turn headings, code (flow), and paragraphs into their content if paragraphs are not allowed"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a | b |\n| - | - |\n| c | d |",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    allowed_elements: Some(vec!["table".into(), "td".into(), "tr".into()]),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<table>\n<tr>\na\nb\n</tr>\n<tr>\n<td>c</td>\n<td>d</td>\n</tr>\n</table>",
+        "should turn table elements that are not allowed into their content"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "* [x] a\\\nb",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    allowed_elements: Some(vec!["li".into(), "ul".into()]),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<ul>\n<li> a\nb</li>\n</ul>",
+        "should drop checkboxes and breaks that are not allowed"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n: b\n\nc\n\n: d",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        definition_list: true,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    allowed_elements: Some(vec!["dd".into(), "p".into()]),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "a\n<dd>b</dd>\nc\n<dd><p>d</p></dd>",
+        "should turn definition list elements that are not allowed into their content"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^b]\n\n[^b]: c",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    allowed_elements: Some(vec!["ol".into(), "p".into(), "sup".into()]),
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<p>a<sup>1</sup></p>\n<p>Footnotes</p>\n<ol>\n<p>c</p>\n</ol>\n",
+        "should turn footnote elements that are not allowed into their content, and drop backreferences"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<h1>a</h1>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    allowed_elements: Some(vec!["p".into()]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1>a</h1>",
+        "should not affect HTML"
+    );
+
+    Ok(())
+}
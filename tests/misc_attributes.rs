@@ -0,0 +1,64 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn attributes() -> Result<(), String> {
+    let danger = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            allow_dangerous_protocol: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[a](b \"\\\" onmouseover=\\\"alert(1)\")"),
+        "<p><a href=\"b\" title=\"&quot; onmouseover=&quot;alert(1)\">a</a></p>",
+        "should encode quotes in titles"
+    );
+
+    assert_eq!(
+        to_html("[a](<b\" onmouseover=\"alert(1)>) ![c](<d\"&e>)"),
+        "<p><a href=\"b%22%20onmouseover=%22alert(1)\">a</a> <img src=\"d%22&amp;e\" alt=\"c\" /></p>",
+        "should encode quotes and ampersands in URLs"
+    );
+
+    assert_eq!(
+        to_html("![\" onerror=\"alert(1) & <b>](c)"),
+        "<p><img src=\"c\" alt=\"&quot; onerror=&quot;alert(1) &amp; &lt;b&gt;\" /></p>",
+        "should encode quotes, ampersands, and brackets in alt"
+    );
+
+    assert_eq!(
+        to_html_with_options("![<b title=\"x\" onerror=\"alert(1)\">](c)", &danger)?,
+        "<p><img src=\"c\" alt=\"&lt;b title=&quot;x&quot; onerror=&quot;alert(1)&quot;&gt;\" /></p>",
+        "should encode HTML in alt, even w/ `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<b title=\"x\">a</b>", &danger)?,
+        "<p><b title=\"x\">a</b></p>",
+        "should not encode HTML outside of alt w/ `allow_dangerous_html`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "`a` <https://b>\n\n***",
+            &Options {
+                compile: CompileOptions {
+                    default_inline_code_class: Some("\"c".into()),
+                    external_link_rel: Some("\"d".into()),
+                    external_link_target: Some("\"e".into()),
+                    thematic_break_attributes: vec![("title".into(), "\"f".into())],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><code class=\"&quot;c\">a</code> <a href=\"https://b\" rel=\"&quot;d\" target=\"&quot;e\">https://b</a></p>\n<hr title=\"&quot;f\" />",
+        "should encode attribute values from options"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn base_url() -> Result<(), String> {
+    let base = Options {
+        compile: CompileOptions {
+            base_url: Some("https://site/blog/".into()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("![a](./img.png)"),
+        "<p><img src=\"./img.png\" alt=\"a\" /></p>",
+        "should not resolve URLs by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](./img.png)", &base)?,
+        "<p><img src=\"https://site/blog/img.png\" alt=\"a\" /></p>",
+        "should resolve relative image URLs w/ `base_url`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](post.html) [b](../about)", &base)?,
+        "<p><a href=\"https://site/blog/post.html\">a</a> <a href=\"https://site/about\">b</a></p>",
+        "should resolve relative link URLs w/ `base_url`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a][b]\n\n[b]: ./c \"d\"", &base)?,
+        "<p><a href=\"https://site/blog/c\" title=\"d\">a</a></p>\n",
+        "should resolve URLs of references"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](/abs)", &base)?,
+        "<p><a href=\"/abs\">a</a></p>",
+        "should not resolve absolute paths"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](https://x) [b](//x) [c](mailto:d@e.f)", &base)?,
+        "<p><a href=\"https://x\">a</a> <a href=\"//x\">b</a> <a href=\"mailto:d@e.f\">c</a></p>",
+        "should not resolve URLs w/ a protocol, or protocol-relative URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](#frag) [b]()", &base)?,
+        "<p><a href=\"#frag\">a</a> <a href=\"\">b</a></p>",
+        "should not resolve fragments or empty URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://x> <a@b.c>", &base)?,
+        "<p><a href=\"https://x\">https://x</a> <a href=\"mailto:a@b.c\">a@b.c</a></p>",
+        "should not affect autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](<b c>)", &base)?,
+        "<p><a href=\"https://site/blog/b%20c\">a</a></p>",
+        "should encode resolved URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b)",
+            &Options {
+                compile: CompileOptions {
+                    base_url: Some("https://site/blog/index.html".into()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://site/blog/b\">a</a></p>",
+        "should resolve against the directory of a base w/ a file name"
+    );
+
+    Ok(())
+}
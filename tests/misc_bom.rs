@@ -10,4 +10,28 @@ fn bom() {
         "<h1>hea\u{FEFF}ding</h1>",
         "should ignore a bom"
     );
+
+    assert_eq!(
+        to_html("\u{FEFF}# Hi"),
+        "<h1>Hi</h1>",
+        "should ignore a bom before a heading"
+    );
+
+    assert_eq!(
+        to_html("\u{FEFF}a"),
+        "<p>a</p>",
+        "should ignore a bom before a paragraph"
+    );
+
+    assert_eq!(
+        to_html("a\n\n\u{FEFF}b"),
+        "<p>a</p>\n<p>\u{FEFF}b</p>",
+        "should not ignore a bom in the middle of a document"
+    );
+
+    assert_eq!(
+        to_html("\u{FEFF}\u{FEFF}a"),
+        "<p>\u{FEFF}a</p>",
+        "should only ignore one bom"
+    );
 }
@@ -0,0 +1,37 @@
+use markdown::{to_html_from_bytes, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn bytes() -> Result<(), String> {
+    assert_eq!(
+        to_html_from_bytes(b"# a *b*", &Options::default())?,
+        "<h1>a <em>b</em></h1>",
+        "should support bytes"
+    );
+
+    assert_eq!(
+        to_html_from_bytes("\u{FEFF}中文".as_bytes(), &Options::default())?,
+        "<p>中文</p>",
+        "should support multibyte characters (and a bom)"
+    );
+
+    assert_eq!(
+        to_html_from_bytes(b"", &Options::default())?,
+        "",
+        "should support no bytes"
+    );
+
+    assert_eq!(
+        to_html_from_bytes(b"ab\xFFc", &Options::default()),
+        Err("Unexpected invalid UTF-8 at byte 2, expected valid UTF-8".into()),
+        "should error on invalid UTF-8"
+    );
+
+    assert_eq!(
+        to_html_from_bytes(b"a\xE4\xB8", &Options::default()),
+        Err("Unexpected invalid UTF-8 at byte 1, expected valid UTF-8".into()),
+        "should error on a truncated multibyte character"
+    );
+
+    Ok(())
+}
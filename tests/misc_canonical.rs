@@ -0,0 +1,141 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn canonical() -> Result<(), String> {
+    let canonical = Options {
+        compile: CompileOptions {
+            canonical: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[a](b \"c\")"),
+        "<p><a href=\"b\" title=\"c\">a</a></p>",
+        "should not sort attributes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b \"c\")", &canonical)?,
+        "<p><img alt=\"a\" src=\"b\" title=\"c\" /></p>",
+        "should sort attributes alphabetically"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](https://b \"c\")\n\n***",
+            &Options {
+                compile: CompileOptions {
+                    canonical: true,
+                    external_link_target: Some("_blank".into()),
+                    external_link_rel: Some("nofollow".into()),
+                    thematic_break_attributes: vec![
+                        ("role".into(), "separator".into()),
+                        ("class".into(), "divider".into()),
+                        ("aria-hidden".into(), "true".into()),
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p><a href=\"https://b\" rel=\"nofollow\" target=\"_blank\" title=\"c\">a</a></p>\n<hr aria-hidden=\"true\" class=\"divider\" role=\"separator\" />",
+        "should sort attributes added by several options"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a  \nb\n\n***",
+            &Options {
+                compile: CompileOptions {
+                    canonical: true,
+                    xhtml: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a<br>\nb</p>\n<hr>",
+        "should support HTML (not XHTML) void elements"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^1]\n\n[^1]: b",
+            &Options {
+                compile: CompileOptions {
+                    canonical: true,
+                    ..CompileOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p>a<sup><a aria-describedby=\"footnote-label\" data-footnote-ref=\"\" href=\"#user-content-fn-1\" id=\"user-content-fnref-1\">1</a></sup></p>
+<section class=\"footnotes\" data-footnotes=\"\">
+<h2 class=\"sr-only\" id=\"footnote-label\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a aria-label=\"Back to content\" class=\"data-footnote-backref\" data-footnote-backref=\"\" href=\"#user-content-fnref-1\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should sort attributes in footnotes, and put the label on its own line"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a\n\n* b\n* c",
+            &Options {
+                compile: CompileOptions {
+                    canonical: true,
+                    tight_output: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<h1>a</h1>\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>",
+        "should put blocks on their own line, even w/ `tight_output`"
+    );
+
+    let dangerous = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            canonical: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options(
+            "<div id=\"a\" class=\"b\" hidden>\n\n<i title=\"c\" class=\"d\">e</i>",
+            &dangerous
+        )?,
+        "<div class=\"b\" hidden id=\"a\">\n<p><i class=\"d\" title=\"c\">e</i></p>",
+        "should sort attributes in raw HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div id='a' class=b>", &dangerous)?,
+        "<div id='a' class=b>",
+        "should not sort attributes in raw HTML that are not double quoted"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- <a id=\"b\" class=\"c\"> -->", &dangerous)?,
+        "<!-- <a id=\"b\" class=\"c\"> -->",
+        "should not change comments"
+    );
+
+    assert_eq!(
+        to_html_with_options("`<a id=\"b\" class=\"c\">`", &canonical)?,
+        "<p><code>&lt;a id=&quot;b&quot; class=&quot;c&quot;&gt;</code></p>",
+        "should not change text"
+    );
+
+    Ok(())
+}
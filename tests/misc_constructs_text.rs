@@ -0,0 +1,86 @@
+use markdown::{to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn constructs_text() -> Result<(), String> {
+    let text = Options {
+        parse: ParseOptions {
+            constructs: Constructs::text(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let flow = Options {
+        parse: ParseOptions {
+            constructs: Constructs::flow(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("*a* **b** `c` [d](e) <https://f> \\* &amp;", &text)?,
+        "<p><em>a</em> <strong>b</strong> <code>c</code> <a href=\"e\">d</a> <a href=\"https://f\">https://f</a> * &amp;</p>",
+        "should support text constructs w/ `Constructs::text`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\n> b\n\n- c\n\n***\n\n    d\n\n```\ne\n```", &text)?,
+        "<p># a</p>\n<p>&gt; b</p>\n<p>- c</p>\n<p>***</p>\n<p>d</p>\n<p><code>e</code></p>",
+        "should not support blocks w/ `Constructs::text`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>\n\na <b>c</b>",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs::text(),
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..Default::default()
+                },
+            }
+        )?,
+        "<p>&lt;div&gt;</p>\n<p>a &lt;b&gt;c&lt;/b&gt;</p>",
+        "should not support HTML w/ `Constructs::text`, even if dangerous HTML is allowed"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b) [c]\n\n[c]: d", &text)?,
+        "<p>!<a href=\"b\">a</a> [c]</p>\n<p>[c]: d</p>",
+        "should not support images (only links) or definitions w/ `Constructs::text`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# *a*",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs::text(),
+                    inline: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "# <em>a</em>",
+        "should support `Constructs::text` w/ `inline`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\n> *b*\n\n- c", &flow)?,
+        "<h1>a</h1>\n<blockquote>\n<p><em>b</em></p>\n</blockquote>\n<ul>\n<li>c</li>\n</ul>",
+        "should support blocks w/ `Constructs::flow`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n\n<b>a</b> ![b](c)", &flow)?,
+        "<p>&lt;div&gt;</p>\n<p>&lt;b&gt;a&lt;/b&gt; !<a href=\"c\">b</a></p>",
+        "should not support HTML or images w/ `Constructs::flow`"
+    );
+
+    Ok(())
+}
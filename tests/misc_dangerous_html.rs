@@ -30,5 +30,50 @@ fn dangerous_html() -> Result<(), String> {
         "should be unsafe w/ `allowDangerousHtml`"
     );
 
+    let flow = &Options {
+        compile: CompileOptions {
+            allow_html_flow: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let text = &Options {
+        compile: CompileOptions {
+            allow_html_text: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("<div>\n\na <span>b</span>", flow)?,
+        "<div>\n<p>a &lt;span&gt;b&lt;/span&gt;</p>",
+        "should be unsafe for flow, but safe for text, w/ `allow_html_flow`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n\na <span>b</span>", text)?,
+        "&lt;div&gt;\n<p>a <span>b</span></p>",
+        "should be safe for flow, but unsafe for text, w/ `allow_html_text`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>\n\na <span>b</span>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    allow_html_flow: false,
+                    allow_html_text: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div>\n<p>a <span>b</span></p>",
+        "should be unsafe for both w/ `allow_dangerous_html`, regardless of the others"
+    );
+
     Ok(())
 }
@@ -27,6 +27,24 @@ fn default_line_ending() -> Result<(), String> {
         "should infer the first line ending (3)"
     );
 
+    assert_eq!(
+        to_html("# a\r\n> b\r\n\r\n* c\r\n* d"),
+        "<h1>a</h1>\r\n<blockquote>\r\n<p>b</p>\r\n</blockquote>\r\n<ul>\r\n<li>c</li>\r\n<li>d</li>\r\n</ul>",
+        "should use an inferred `\\r\\n` to join blocks in a CRLF-only document"
+    );
+
+    assert_eq!(
+        to_html("> a\r\n\n> b\n"),
+        "<blockquote>\r\n<p>a</p>\r\n</blockquote>\r\n<blockquote>\r\n<p>b</p>\r\n</blockquote>\n",
+        "should infer the first line ending, even if other line endings exist (1)"
+    );
+
+    assert_eq!(
+        to_html("> a\n\r\n> b\r\n"),
+        "<blockquote>\n<p>a</p>\n</blockquote>\n<blockquote>\n<p>b</p>\n</blockquote>\r\n",
+        "should infer the first line ending, even if other line endings exist (2)"
+    );
+
     assert_eq!(
         to_html_with_options(
             "> a",
@@ -59,5 +77,50 @@ fn default_line_ending() -> Result<(), String> {
         "should support the given line ending, even if line endings exist"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "a  \r\nb\\\r\nc\r\nd",
+            &Options {
+                compile: CompileOptions {
+                    default_line_ending: LineEnding::CarriageReturnLineFeed,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a<br />\r\nb<br />\r\nc\r\nd</p>",
+        "should use `\\r\\n` after hard breaks and soft breaks in a CRLF document"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> a  \r\n> b\r\n\r\nc\r\n=",
+            &Options {
+                compile: CompileOptions {
+                    default_line_ending: LineEnding::CarriageReturnLineFeed,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<blockquote>\r\n<p>a<br />\r\nb</p>\r\n</blockquote>\r\n<h1>c</h1>",
+        "should use `\\r\\n` consistently around hard breaks in containers and setext headings"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> a  \nb",
+            &Options {
+                compile: CompileOptions {
+                    default_line_ending: LineEnding::CarriageReturnLineFeed,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<blockquote>\n<p>a<br />\nb</p>\n</blockquote>",
+        "should copy the line ending after hard breaks, instead of using the given line ending"
+    );
+
     Ok(())
 }
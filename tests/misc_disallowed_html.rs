@@ -0,0 +1,107 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, DisallowedHtml, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn disallowed_html() -> Result<(), String> {
+    let remove = &Options {
+        compile: CompileOptions {
+            on_disallowed_html: DisallowedHtml::Remove,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let keep = &Options {
+        compile: CompileOptions {
+            on_disallowed_html: DisallowedHtml::Keep,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a <script>alert(1)</script> b"),
+        "<p>a &lt;script&gt;alert(1)&lt;/script&gt; b</p>",
+        "should escape HTML (text) by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a <script>alert(1)</script> b",
+            &Options {
+                compile: CompileOptions {
+                    on_disallowed_html: DisallowedHtml::Escape,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a &lt;script&gt;alert(1)&lt;/script&gt; b</p>",
+        "should escape HTML (text) w/ `DisallowedHtml::Escape`"
+    );
+
+    assert_eq!(
+        to_html("<div>\n*a*\n</div>\n\nb"),
+        "&lt;div&gt;\n*a*\n&lt;/div&gt;\n<p>b</p>",
+        "should escape HTML (flow) by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <script>alert(1)</script> b", remove)?,
+        "<p>a alert(1) b</p>",
+        "should remove HTML (text) w/ `DisallowedHtml::Remove`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n*a*\n</div>\n\nb", remove)?,
+        "<p>b</p>",
+        "should remove HTML (flow) w/ `DisallowedHtml::Remove`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n<div>\n\nb", remove)?,
+        "<p>a</p>\n<p>b</p>",
+        "should remove the line ending after HTML (flow) w/ `DisallowedHtml::Remove`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a <b>c</b>](d.png)", remove)?,
+        "<p><img src=\"d.png\" alt=\"a c\" /></p>",
+        "should remove HTML (text) in image text w/ `DisallowedHtml::Remove`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <script>alert(1)</script> b", keep)?,
+        "<p>a <script>alert(1)</script> b</p>",
+        "should keep HTML (text) w/ `DisallowedHtml::Keep`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n*a*\n</div>\n\nb", keep)?,
+        "<div>\n*a*\n</div>\n<p>b</p>",
+        "should keep HTML (flow) w/ `DisallowedHtml::Keep`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a <b>c</b>](d.png)", keep)?,
+        "<p><img src=\"d.png\" alt=\"a &lt;b&gt;c&lt;/b&gt;\" /></p>",
+        "should escape HTML (text) in image text w/ `DisallowedHtml::Keep`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>\n\na <b>c</b>",
+            &Options {
+                compile: CompileOptions {
+                    allow_html_text: true,
+                    on_disallowed_html: DisallowedHtml::Remove,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a <b>c</b></p>",
+        "should only affect HTML that is not allowed"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn encode_urls() -> Result<(), String> {
+    let keep = Options {
+        compile: CompileOptions {
+            encode_urls: false,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("[a](<b c>)"),
+        "<p><a href=\"b%20c\">a</a></p>",
+        "should encode spaces by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](<b c>)", &keep)?,
+        "<p><a href=\"b c\">a</a></p>",
+        "should keep spaces w/ `encode_urls: false`"
+    );
+
+    assert_eq!(
+        to_html("[a](b%20c)"),
+        "<p><a href=\"b%20c\">a</a></p>",
+        "should not encode `%20` again by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b%20c)", &keep)?,
+        "<p><a href=\"b%20c\">a</a></p>",
+        "should keep `%20` w/ `encode_urls: false`"
+    );
+
+    assert_eq!(
+        to_html("[a](<b%20c d>)"),
+        "<p><a href=\"b%20c%20d\">a</a></p>",
+        "should encode spaces but not `%20` next to each other"
+    );
+
+    assert_eq!(
+        to_html("[a](/點/看)"),
+        "<p><a href=\"/%E9%BB%9E/%E7%9C%8B\">a</a></p>",
+        "should encode non-ASCII path segments by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](/點/看)", &keep)?,
+        "<p><a href=\"/點/看\">a</a></p>",
+        "should keep non-ASCII path segments w/ `encode_urls: false`"
+    );
+
+    assert_eq!(
+        to_html("[a](/%E9%BB%9E/看)"),
+        "<p><a href=\"/%E9%BB%9E/%E7%9C%8B\">a</a></p>",
+        "should not encode already encoded non-ASCII segments again"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](<b c.png>)", &keep)?,
+        "<p><img src=\"b c.png\" alt=\"a\" /></p>",
+        "should keep image sources w/ `encode_urls: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]\n\n[a]: <b c>", &keep)?,
+        "<p><a href=\"b c\">a</a></p>\n",
+        "should keep definition destinations w/ `encode_urls: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.b/點>", &keep)?,
+        "<p><a href=\"https://a.b/點\">https://a.b/點</a></p>",
+        "should keep autolinks w/ `encode_urls: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](<b\"&c>)", &keep)?,
+        "<p><a href=\"b&quot;&amp;c\">a</a></p>",
+        "should still encode characters unsafe in HTML w/ `encode_urls: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](javascript:alert(1))", &keep)?,
+        "<p><a href=\"\">a</a></p>",
+        "should still check protocols w/ `encode_urls: false`"
+    );
+
+    Ok(())
+}
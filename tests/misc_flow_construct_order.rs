@@ -0,0 +1,151 @@
+use markdown::{
+    mdast::{Code, Node, Root},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Constructs, FlowConstruct, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn flow_construct_order() -> Result<(), String> {
+    let mut thematic_break_first = ParseOptions::default().flow_construct_order;
+    thematic_break_first.retain(|d| *d != FlowConstruct::ThematicBreak);
+    thematic_break_first.insert(0, FlowConstruct::ThematicBreak);
+
+    let thematic_break_first = Options {
+        parse: ParseOptions {
+            flow_construct_order: thematic_break_first,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a\n---"),
+        "<h2>a</h2>",
+        "should prefer heading (setext) underlines over thematic breaks by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n---", &thematic_break_first)?,
+        "<p>a</p>\n<hr />",
+        "should prefer thematic breaks if they come first"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n===", &thematic_break_first)?,
+        "<h1>a</h1>",
+        "should still support heading (setext) underlines that are not thematic breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("***\n\n# a\n\n    b", &thematic_break_first)?,
+        "<hr />\n<h1>a</h1>\n<pre><code>b\n</code></pre>",
+        "should still support other constructs"
+    );
+
+    let mut raw_flow_last = ParseOptions::default().flow_construct_order;
+    raw_flow_last.retain(|d| *d != FlowConstruct::RawFlow);
+    raw_flow_last.push(FlowConstruct::RawFlow);
+
+    assert_eq!(
+        to_html_with_options(
+            "    ```\n    a",
+            &Options {
+                parse: ParseOptions {
+                    flow_construct_order: raw_flow_last,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<pre><code>```\na\n</code></pre>",
+        "should support moving constructs to the end"
+    );
+
+    let mut no_thematic_break = ParseOptions::default().flow_construct_order;
+    no_thematic_break.retain(|d| *d != FlowConstruct::ThematicBreak);
+
+    assert_eq!(
+        to_html_with_options(
+            "***",
+            &Options {
+                parse: ParseOptions {
+                    flow_construct_order: no_thematic_break,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>***</p>",
+        "should not try constructs that are left out"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n---",
+            &Options {
+                parse: ParseOptions {
+                    flow_construct_order: vec![],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a\n---</p>",
+        "should support an empty list (only paragraphs)"
+    );
+
+    let mut table_first = ParseOptions::gfm().flow_construct_order;
+    table_first.retain(|d| *d != FlowConstruct::GfmTable);
+    table_first.insert(0, FlowConstruct::GfmTable);
+
+    assert_eq!(
+        to_mdast(
+            "    | a |\n    | - |",
+            &ParseOptions {
+                constructs: Constructs {
+                    gfm_table: true,
+                    ..Default::default()
+                },
+                flow_construct_order: table_first,
+                ..Default::default()
+            }
+        )?,
+        Node::Root(Root {
+            children: vec![Node::Code(Code {
+                value: "| a |\n| - |".into(),
+                lang: None,
+                meta: None,
+                position: Some(Position::new(1, 1, 0, 2, 10, 19))
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 10, 19))
+        }),
+        "should not change what constructs allow, such as indentation"
+    );
+
+    let mut table_first = ParseOptions::gfm().flow_construct_order;
+    table_first.retain(|d| *d != FlowConstruct::GfmTable);
+    table_first.insert(0, FlowConstruct::GfmTable);
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>\n> a | b",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs::gfm(),
+                    flow_construct_order: table_first,
+                    ..Default::default()
+                },
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..Default::default()
+                }
+            }
+        )?,
+        "<div>\n<blockquote>\n<p>a | b</p>\n</blockquote>",
+        "should end raw constructs before containers formed while looking ahead"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn force_tight_lists() -> Result<(), String> {
+    let tight = &Options {
+        compile: CompileOptions {
+            force_tight_lists: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("* a\n\n* b"),
+        "<ul>\n<li>\n<p>a</p>\n</li>\n<li>\n<p>b</p>\n</li>\n</ul>",
+        "should wrap items in loose lists in paragraphs by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n\n* b", tight)?,
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>",
+        "should compile loose lists as tight w/ `force_tight_lists`"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n* b", tight)?,
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>",
+        "should not change tight lists w/ `force_tight_lists`"
+    );
+
+    assert_eq!(
+        to_html_with_options("1. a\n\n   b\n2. c", tight)?,
+        "<ol>\n<li>a\nb</li>\n<li>c</li>\n</ol>",
+        "should keep several paragraphs in an item apart w/ `force_tight_lists`"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n\n  > b\n* c", tight)?,
+        "<ul>\n<li>a\n<blockquote>\n<p>b</p>\n</blockquote>\n</li>\n<li>c</li>\n</ul>",
+        "should not affect paragraphs in other containers in items w/ `force_tight_lists`"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n\n  * b\n\n  * c", tight)?,
+        "<ul>\n<li>a\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n</li>\n</ul>",
+        "should compile nested loose lists as tight w/ `force_tight_lists`"
+    );
+
+    Ok(())
+}
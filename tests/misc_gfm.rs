@@ -0,0 +1,51 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn gfm() -> Result<(), String> {
+    let doc = "| a | b |\n| - | - |\n| ~c~ | www.d.com |\n\n* [x] e[^f]\n\n<iframe>\n\n[^f]: g";
+    let dangerous = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            ..CompileOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html("| a |\n| - |\n\n~b~ www.c.com [^d]\n\n[^d]: e"),
+        "<p>| a |\n| - |</p>\n<p>~b~ www.c.com <a href=\"e\">^d</a></p>\n",
+        "should not support GFM by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(doc, &dangerous)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><del>c</del></td>\n<td><a href=\"http://www.d.com\">www.d.com</a></td>\n</tr>\n</tbody>\n</table>\n<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> e<sup><a href=\"#user-content-fn-f\" id=\"user-content-fnref-f\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></li>\n</ul>\n&lt;iframe>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-f\">\n<p>g <a href=\"#user-content-fnref-f\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should support all of GFM w/ `Options::gfm`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            doc,
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        gfm_table: false,
+                        gfm_task_list_item: false,
+                        ..Constructs::gfm()
+                    },
+                    ..ParseOptions::gfm()
+                },
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    gfm_tagfilter: false,
+                    ..CompileOptions::gfm()
+                },
+            }
+        )?,
+        "<p>| a | b |\n| - | - |\n| <del>c</del> | <a href=\"http://www.d.com\">www.d.com</a> |</p>\n<ul>\n<li>[x] e<sup><a href=\"#user-content-fn-f\" id=\"user-content-fnref-f\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></li>\n</ul>\n<iframe>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-f\">\n<p>g <a href=\"#user-content-fnref-f\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should prefer explicit fields over the GFM preset"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn hard_break_all() -> Result<(), String> {
+    let breaks = Options {
+        compile: CompileOptions {
+            hard_break_all: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a\nb"),
+        "<p>a\nb</p>",
+        "should keep soft line endings by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb", &breaks)?,
+        "<p>a<br />\nb</p>",
+        "should turn soft line endings into hard breaks w/ `hard_break_all: true`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\nc", &breaks)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should turn several soft line endings into hard breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\nb",
+            &Options {
+                compile: CompileOptions {
+                    hard_break_all: true,
+                    xhtml: false,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p>a<br>\nb</p>",
+        "should support `xhtml: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\\nb", &breaks)?,
+        "<p>a<br />\nb</p>",
+        "should not double hard breaks (escape)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a  \nb", &breaks)?,
+        "<p>a<br />\nb</p>",
+        "should not double hard breaks (trailing)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n===", &breaks)?,
+        "<h1>a<br />\nb</h1>",
+        "should turn soft line endings in heading (setext) text into hard breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\nb", &breaks)?,
+        "<p>a</p>\n<p>b</p>",
+        "should not affect line endings between blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\nb", &breaks)?,
+        "<h1>a</h1>\n<p>b</p>",
+        "should not affect line endings after headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\nb\n```", &breaks)?,
+        "<pre><code>a\nb\n</code></pre>",
+        "should not affect code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a\n    b", &breaks)?,
+        "<pre><code>a\nb\n</code></pre>",
+        "should not affect code (indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a\nb`", &breaks)?,
+        "<p><code>a b</code></p>",
+        "should not affect code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a\nb](c)", &breaks)?,
+        "<p><img src=\"c\" alt=\"a\nb\" /></p>",
+        "should not add hard breaks in image alt"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> b", &breaks)?,
+        "<blockquote>\n<p>a<br />\nb</p>\n</blockquote>",
+        "should support block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\nb\n* c", &breaks)?,
+        "<ul>\n<li>a<br />\nb</li>\n<li>c</li>\n</ul>",
+        "should support tight list items"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn heading_offset() -> Result<(), String> {
+    let offset = |heading_offset| Options {
+        compile: CompileOptions {
+            heading_offset,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("# a"),
+        "<h1>a</h1>",
+        "should not shift headings by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a", &offset(2))?,
+        "<h3>a</h3>",
+        "should shift headings (atx) w/ `heading_offset`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n=\n\nb\n-", &offset(2))?,
+        "<h3>a</h3>\n<h4>b</h4>",
+        "should shift headings (setext) w/ `heading_offset`"
+    );
+
+    assert_eq!(
+        to_html_with_options("##### a\n\n###### b", &offset(2))?,
+        "<h6>a</h6>\n<h6>b</h6>",
+        "should clamp shifted headings at `6`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n\n### b", &offset(-2))?,
+        "<h1>a</h1>\n<h1>b</h1>",
+        "should clamp shifted headings at `1`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "# a",
+            &Options {
+                compile: CompileOptions {
+                    heading_offset: 1,
+                    allowed_elements: Some(vec!["h1".into(), "p".into()]),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<p>a</p>",
+        "should check shifted headings against `allowed_elements`"
+    );
+
+    Ok(())
+}
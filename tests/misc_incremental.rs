@@ -0,0 +1,128 @@
+use markdown::{mdast::Node, to_mdast, to_mdast_incremental, Constructs, Edit, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Replace `start..end` in `value` with `insert`, and parse the result both
+/// incrementally and fully.
+fn reparse(
+    value: &str,
+    start: usize,
+    end: usize,
+    insert: &str,
+    options: &ParseOptions,
+) -> Result<(Node, Node), String> {
+    let previous = to_mdast(value, options)?;
+    let value = format!("{}{}{}", &value[..start], insert, &value[end..]);
+    let edit = Edit {
+        start,
+        old_end: end,
+        new_end: start + insert.len(),
+    };
+    Ok((
+        to_mdast_incremental(&previous, &value, &edit, options)?,
+        to_mdast(&value, options)?,
+    ))
+}
+
+#[test]
+fn incremental() -> Result<(), String> {
+    let gfm = ParseOptions::gfm();
+    let doc = "# a\n\nb *c* d\n\n- e\n- f\n\n> g\n\n    h\n\ni";
+
+    let (incremental, full) = reparse(doc, 7, 8, "x", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support replacing a character in a paragraph"
+    );
+
+    let (incremental, full) = reparse(doc, 17, 17, "\n- x", &gfm)?;
+    assert_eq!(incremental, full, "should support adding a list item");
+
+    let (incremental, full) = reparse(doc, 0, 2, "", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support turning the first heading into a paragraph"
+    );
+
+    let (incremental, full) = reparse(doc, doc.len(), doc.len(), "\n===", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support turning the last paragraph into a heading"
+    );
+
+    let (incremental, full) = reparse(doc, 13, 14, "", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support removing a blank line between blocks"
+    );
+
+    let (incremental, full) = reparse(doc, 5, 5, "```\n", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support code (fenced) without closing fence, running to the end"
+    );
+
+    let (incremental, full) = reparse(doc, 5, 5, "<!--\n", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support HTML (flow) without end, running to the end"
+    );
+
+    let (incremental, full) = reparse(doc, 6, 11, "[b]", &gfm)?;
+    assert_eq!(incremental, full, "should support adding references");
+
+    let (incremental, full) = reparse("[a]\n\nb\n\nc", 9, 9, "\n\n[a]: d", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support adding definitions, which affect other blocks"
+    );
+
+    let (incremental, full) = reparse("[a]\n\nb\n\n[a]: d", 5, 6, "x", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support editing documents w/ definitions"
+    );
+
+    let (incremental, full) = reparse("a\r\n\r\nb\r\n\r\n> c\r\n\r\nd", 7, 8, "x\r\ny", &gfm)?;
+    assert_eq!(
+        incremental, full,
+        "should support moving positions over CRLF line endings"
+    );
+
+    let (incremental, full) = reparse("a\n\n\tb\n\n- c\n\n\td", 4, 4, "x", &gfm)?;
+    assert_eq!(incremental, full, "should support tabs");
+
+    let frontmatter = ParseOptions {
+        constructs: Constructs {
+            frontmatter: true,
+            ..Constructs::default()
+        },
+        ..ParseOptions::default()
+    };
+
+    let (incremental, full) =
+        reparse("---\na: b\n---\n\nc\n\n---\n\nd", 19, 20, "x", &frontmatter)?;
+    assert_eq!(
+        incremental, full,
+        "should not support frontmatter after the start of the document"
+    );
+
+    let (incremental, full) = reparse(doc, 7, 8, "x", &ParseOptions::default())?;
+    assert_eq!(incremental, full, "should support `CommonMark`");
+
+    assert_eq!(
+        to_mdast_incremental(
+            &to_mdast("a", &gfm)?,
+            "b",
+            &Edit {
+                start: 0,
+                old_end: 5,
+                new_end: 1
+            },
+            &gfm
+        )?,
+        to_mdast("b", &gfm)?,
+        "should support edits that do not fit the previous tree (by parsing everything)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use markdown::{
+    mdast::{Emphasis, Node, Root, Text},
+    to_html_inline, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn inline() -> Result<(), String> {
+    assert_eq!(
+        to_html_inline("**hi**"),
+        "<strong>hi</strong>",
+        "should support strong w/o paragraph"
+    );
+
+    assert_eq!(
+        to_html_inline("a *b* c"),
+        "a <em>b</em> c",
+        "should support emphasis w/o paragraph"
+    );
+
+    assert_eq!(
+        to_html_inline("a `b` c"),
+        "a <code>b</code> c",
+        "should support code (text) w/o paragraph"
+    );
+
+    assert_eq!(
+        to_html_inline("[a](b) <https://c.d>"),
+        "<a href=\"b\">a</a> <a href=\"https://c.d\">https://c.d</a>",
+        "should support links and autolinks w/o paragraph"
+    );
+
+    assert_eq!(
+        to_html_inline("  a  "),
+        "a",
+        "should trim initial and final whitespace"
+    );
+
+    assert_eq!(
+        to_html_inline("a  \nb"),
+        "a<br />\nb",
+        "should support hard breaks"
+    );
+
+    assert_eq!(
+        to_html_inline("# a\n\n> b\n\n- c\n\n***"),
+        "# a\n\n&gt; b\n\n- c\n\n***",
+        "should not support blocks"
+    );
+
+    assert_eq!(
+        to_html_inline("[a]\n\n[a]: b"),
+        "[a]\n\n[a]: b",
+        "should not support definitions (or references)"
+    );
+
+    assert_eq!(
+        to_html_inline("<a\n\n"),
+        "&lt;a\n\n",
+        "should not support blank lines in HTML (text) (1)"
+    );
+
+    assert_eq!(
+        to_html_inline("<!--\n\n"),
+        "&lt;!--\n\n",
+        "should not support blank lines in HTML (text) (2)"
+    );
+
+    assert_eq!(
+        to_html_inline("<!--a\n \t\nb-->"),
+        "&lt;!--a\n\nb--&gt;",
+        "should not support whitespace-only lines in HTML (text)"
+    );
+
+    assert_eq!(
+        to_html_inline("`a\n\nb`"),
+        "`a\n\nb`",
+        "should not support blank lines in code (text)"
+    );
+
+    assert_eq!(
+        to_html_inline("`a\nb\nc"),
+        "`a\nb\nc",
+        "should support unclosed code (text) across lines"
+    );
+
+    assert_eq!(
+        to_html_inline("[a](b\n\n\"c\")"),
+        "[a](b\n\n&quot;c&quot;)",
+        "should not support blank lines in resources"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<a\n\n",
+            &Options {
+                parse: ParseOptions {
+                    inline: true,
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "&lt;a\n\n",
+        "should not support blank lines in HTML (text) w/ `inline: true`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a $b\n\nc$ %%d\n\ne%%",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_text: true,
+                        percent_comment: true,
+                        ..Constructs::default()
+                    },
+                    inline: true,
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "a $b\n\nc$ %%d\n\ne%%",
+        "should not support blank lines in math (text) or percent comments (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*a* <b>",
+            &Options {
+                parse: ParseOptions {
+                    inline: true,
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<em>a</em> &lt;b&gt;",
+        "should support `inline: true` in options"
+    );
+
+    assert_eq!(
+        to_mdast(
+            "a *b*",
+            &ParseOptions {
+                inline: true,
+                ..ParseOptions::default()
+            }
+        )?,
+        Node::Root(Root {
+            children: vec![
+                Node::Text(Text {
+                    value: "a ".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                }),
+                Node::Emphasis(Emphasis {
+                    children: vec![Node::Text(Text {
+                        value: "b".into(),
+                        position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                    })],
+                    position: Some(Position::new(1, 3, 2, 1, 6, 5))
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 1, 6, 5))
+        }),
+        "should support `inline: true` in mdast"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn max_url_length() -> Result<(), String> {
+    let max = Options {
+        compile: CompileOptions {
+            allow_dangerous_protocol: true,
+            max_url_length: Some(32),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let long = format!("data:image/png;base64,{}", "A".repeat(1024));
+
+    assert_eq!(
+        to_html(&format!("[a]({})", long)),
+        "<p><a href=\"\">a</a></p>",
+        "should drop dangerous protocols (such as `data:`) in links by default"
+    );
+
+    assert_eq!(
+        to_html(&format!("![a]({})", long)),
+        "<p><img src=\"\" alt=\"a\" /></p>",
+        "should drop dangerous protocols (such as `data:`) in images by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            &format!("![a]({})", long),
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_protocol: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        format!("<p><img src=\"{}\" alt=\"a\" /></p>", long),
+        "should support long destinations by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(&format!("![a *b*]({})", long), &max)?,
+        "<p>a b</p>",
+        "should drop images w/ too long destinations, keeping their alt"
+    );
+
+    assert_eq!(
+        to_html_with_options(&format!("[a *b*]({} \"c\")", long), &max)?,
+        "<p>a <em>b</em></p>",
+        "should drop links w/ too long destinations, keeping their text"
+    );
+
+    assert_eq!(
+        to_html_with_options(&format!("![a][b]\n\n[b]: {}", long), &max)?,
+        "<p>a</p>\n",
+        "should drop references w/ too long destinations in definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](data:image/png;base64,AAAA)", &max)?,
+        "<p><img src=\"data:image/png;base64,AAAA\" alt=\"a\" /></p>",
+        "should support destinations shorter than the limit"
+    );
+
+    assert_eq!(
+        to_html_with_options(&format!("[a]({})", "b".repeat(32)), &max)?,
+        format!("<p><a href=\"{}\">a</a></p>", "b".repeat(32)),
+        "should support destinations as long as the limit"
+    );
+
+    assert_eq!(
+        to_html_with_options(&format!("[a]({})", "b".repeat(33)), &max)?,
+        "<p>a</p>",
+        "should drop destinations one byte longer than the limit"
+    );
+
+    assert_eq!(
+        to_html_with_options(&format!("<{}>", long), &max)?,
+        format!("<p><a href=\"{}\">{}</a></p>", long, long),
+        "should not affect autolinks"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, DisallowedHtml, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn normalize_whitespace() -> Result<(), String> {
+    let normalize = Options {
+        compile: CompileOptions {
+            normalize_whitespace: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a   b"),
+        "<p>a   b</p>",
+        "should keep whitespace by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a   b", &normalize)?,
+        "<p>a b</p>",
+        "should collapse spaces w/ `normalize_whitespace: true`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\t\tb", &normalize)?,
+        "<p>a b</p>",
+        "should collapse tabs"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \t \tb", &normalize)?,
+        "<p>a b</p>",
+        "should collapse mixed spaces and tabs"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a   b", &normalize)?,
+        "<h1>a b</h1>",
+        "should collapse whitespace in headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a   b*  c", &normalize)?,
+        "<p><em>a b</em> c</p>",
+        "should collapse whitespace in and around attention"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \\&  b", &normalize)?,
+        "<p>a &amp; b</p>",
+        "should collapse whitespace around character escapes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a&#32;  b &#9; &#x20; c", &normalize)?,
+        "<p>a b c</p>",
+        "should collapse whitespace across character references"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a  <!-- -->  b",
+            &Options {
+                compile: CompileOptions {
+                    normalize_whitespace: true,
+                    on_disallowed_html: DisallowedHtml::Remove,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p>a b</p>",
+        "should collapse whitespace around content that is left out"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb", &normalize)?,
+        "<p>a\nb</p>",
+        "should not affect line endings"
+    );
+
+    assert_eq!(
+        to_html_with_options("a `b   \tc` d", &normalize)?,
+        "<p>a <code>b   \tc</code> d</p>",
+        "should not affect code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na   \tb\n```", &normalize)?,
+        "<pre><code>a   \tb\n</code></pre>",
+        "should not affect code (fenced)"
+    );
+
+    assert_eq!(
+        to_html_with_options("    a   b", &normalize)?,
+        "<pre><code>a   b\n</code></pre>",
+        "should not affect code (indented)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use markdown::{to_html_with_output, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn stats() -> Result<(), String> {
+    let stats = Options {
+        compile: CompileOptions {
+            collect_stats: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_output("# a", &Options::default())?.stats,
+        None,
+        "should not collect stats by default"
+    );
+
+    let result = to_html_with_output("# a", &stats)?;
+
+    assert_eq!(
+        result.value, "<h1>a</h1>",
+        "should not change the HTML w/ `collect_stats`"
+    );
+
+    let heading = result.stats.unwrap();
+
+    assert_eq!(
+        (heading.events, heading.bytes),
+        (10, 3),
+        "should count events and bytes (heading)"
+    );
+
+    assert!(
+        heading.constructs_attempted > 0,
+        "should count attempted constructs (heading)"
+    );
+
+    let document = to_html_with_output("# a\n\n* b\n* c\n\n> d", &stats)?
+        .stats
+        .unwrap();
+
+    assert_eq!(
+        (document.events, document.bytes),
+        (58, 17),
+        "should count events and bytes (document)"
+    );
+
+    assert!(
+        document.constructs_attempted > heading.constructs_attempted,
+        "should count more attempted constructs in bigger documents"
+    );
+
+    assert_eq!(
+        to_html_with_output("", &stats)?
+            .stats
+            .map(|d| (d.events, d.bytes)),
+        Some((0, 0)),
+        "should support empty documents"
+    );
+
+    assert_eq!(
+        to_html_with_output("a *b*", &stats)?.stats,
+        to_html_with_output("a *b*", &stats)?.stats,
+        "should be deterministic"
+    );
+
+    Ok(())
+}
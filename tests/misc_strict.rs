@@ -0,0 +1,115 @@
+use markdown::{to_html, to_html_with_options, to_mdast, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn strict() -> Result<(), String> {
+    let strict = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                math_flow: true,
+                ..Constructs::gfm()
+            },
+            strict: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[a] b"),
+        "<p>[a] b</p>",
+        "should support undefined references as text by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("x\n\n[a] b", &strict),
+        Err("3:1: Unexpected reference to an undefined definition, expected a definition, a resource, or escaped brackets (strict:undefined-reference)".into()),
+        "should error on undefined shortcut references w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b\n\nc [d][e]", &strict),
+        Err("3:3: Unexpected reference to an undefined definition, expected a definition, a resource, or escaped brackets (strict:undefined-reference)".into()),
+        "should error on undefined full references w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a][]", &strict),
+        Err("1:1: Unexpected reference to an undefined definition, expected a definition, a resource, or escaped brackets (strict:undefined-reference)".into()),
+        "should error on undefined image references w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[^a]", &strict),
+        Err("1:1: Unexpected reference to an undefined definition, expected a definition, a resource, or escaped brackets (strict:undefined-reference)".into()),
+        "should error on undefined footnote calls w/ `strict`"
+    );
+
+    assert_eq!(
+        to_mdast("[a] b", &strict.parse),
+        Err("1:1: Unexpected reference to an undefined definition, expected a definition, a resource, or escaped brackets (strict:undefined-reference)".into()),
+        "should error in `to_mdast` w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]: b\n\n[a] [c][a] [d](e) [f [g](h) i]", &strict)?,
+        "<p><a href=\"b\">a</a> <a href=\"b\">c</a> <a href=\"e\">d</a> [f <a href=\"h\">g</a> i]</p>",
+        "should support references, resources, and brackets around links w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\[a\\] `[b]` a ] [ c\n\n- [x] d", &strict)?,
+        "<p>[a] <code>[b]</code> a ] [ c</p>\n<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> d</li>\n</ul>",
+        "should support escaped, unbalanced, and task list brackets w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a *b", &strict),
+        Err("1:3: Unexpected unmatched `*`, expected a matching sequence or a character escape (strict:unmatched-attention)".into()),
+        "should error on unmatched attention w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("**a*", &strict),
+        Err("1:1: Unexpected unmatched `*`, expected a matching sequence or a character escape (strict:unmatched-attention)".into()),
+        "should error on partially matched attention w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a", &strict),
+        Err("1:1: Unexpected unmatched `~`, expected a matching sequence or a character escape (strict:unmatched-attention)".into()),
+        "should error on unmatched strikethrough w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a* __b__ c * d snake_case \\*e", &strict)?,
+        "<p><em>a</em> <strong>b</strong> c * d snake_case *e</p>",
+        "should support matched, whitespace-surrounded, intraword, and escaped attention w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\n```js\nb", &strict),
+        Err("3:1: Unexpected end of code without closing fence, expected a closing fence (strict:unclosed-fence)".into()),
+        "should error on code (fenced) w/o closing fence w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> ~~~\n> a\n\nb", &strict),
+        Err("1:3: Unexpected end of code without closing fence, expected a closing fence (strict:unclosed-fence)".into()),
+        "should error on code (fenced) closed by its container w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("$$\na", &strict),
+        Err("1:1: Unexpected end of math without closing fence, expected a closing fence (strict:unclosed-fence)".into()),
+        "should error on math (flow) w/o closing fence w/ `strict`"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n```\n\n$$\nb\n$$", &strict)?,
+        "<pre><code>a\n</code></pre>\n<pre><code class=\"language-math math-display\">b\n</code></pre>",
+        "should support closed code (fenced) and math (flow) w/ `strict`"
+    );
+
+    Ok(())
+}
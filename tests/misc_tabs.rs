@@ -284,9 +284,43 @@ fn tabs_virtual_spaces() {
 
     assert_eq!(
         to_html("-\ta\n\n\tb"),
-        "<ul>\n<li>\n<p>a</p>\n<p>\tb</p>\n</li>\n</ul>",
-        // To do: CM.js does not output the tab before `b`. See if that makes sense?
-        // "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
         "should support a part of a tab as a container, and the rest of a tab as flow"
     );
+
+    assert_eq!(
+        to_html("- a\n\n\tb"),
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "should not include the rest of a tab in a paragraph in a list item"
+    );
+
+    assert_eq!(
+        to_html("- a\n\n\tb\n\tc"),
+        "<ul>\n<li>\n<p>a</p>\n<p>b\nc</p>\n</li>\n</ul>",
+        "should not include the rest of a tab in a paragraph in a list item, on further lines"
+    );
+
+    assert_eq!(
+        to_html(">\ta"),
+        "<blockquote>\n<p>a</p>\n</blockquote>",
+        "should not include the rest of a tab in a paragraph in a block quote"
+    );
+
+    assert_eq!(
+        to_html("> a\n>\tb"),
+        "<blockquote>\n<p>a\nb</p>\n</blockquote>",
+        "should not include the rest of a tab in a paragraph in a block quote, on further lines"
+    );
+
+    assert_eq!(
+        to_html(">\t\ta"),
+        "<blockquote>\n<pre><code>  a\n</code></pre>\n</blockquote>",
+        "should support the rest of a tab as indented code in a block quote"
+    );
+
+    assert_eq!(
+        to_html("-\t\ta"),
+        "<ul>\n<li>\n<pre><code>  a\n</code></pre>\n</li>\n</ul>",
+        "should support the rest of a tab as indented code in a list item"
+    );
 }
@@ -0,0 +1,71 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn tight_output() -> Result<(), String> {
+    let tight = Options {
+        compile: CompileOptions {
+            tight_output: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let doc = "# a\n\nb\nc\n\n> d\n\n***\n\n- e\n- f\n\n1. g\n\n   h\n";
+
+    assert_eq!(
+        to_html(doc),
+        "<h1>a</h1>\n<p>b\nc</p>\n<blockquote>\n<p>d</p>\n</blockquote>\n<hr />\n<ul>\n<li>e</li>\n<li>f</li>\n</ul>\n<ol>\n<li>\n<p>g</p>\n<p>h</p>\n</li>\n</ol>\n",
+        "should put line endings between blocks by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(doc, &tight)?,
+        "<h1>a</h1><p>b\nc</p><blockquote><p>d</p></blockquote><hr /><ul><li>e</li><li>f</li></ul><ol><li><p>g</p><p>h</p></li></ol>",
+        "should not put line endings between blocks w/ `tight_output`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a  \nb\\\nc", &tight)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should keep line endings in text"
+    );
+
+    assert_eq!(
+        to_html_with_options("```js\na\n\nb\n```\n\n    c\n    d\n\n- ```\n  e", &tight)?,
+        "<pre><code class=\"language-js\">a\n\nb\n</code></pre><pre><code>c\nd\n</code></pre><ul><li><pre><code>e\n</code></pre></li></ul>",
+        "should keep line endings in code (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>\na\n</div>\n\nb",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    tight_output: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<div>\na\n</div><p>b</p>",
+        "should keep line endings in HTML (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a |\n| - |\n| b |\n\nc[^d]\n\n[^d]: e",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    tight_output: true,
+                    ..CompileOptions::gfm()
+                },
+            }
+        )?,
+        "<table><thead><tr><th>a</th></tr></thead><tbody><tr><td>b</td></tr></tbody></table><p>c<sup><a href=\"#user-content-fn-d\" id=\"user-content-fnref-d\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p><section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2><ol><li id=\"user-content-fn-d\"><p>e <a href=\"#user-content-fnref-d\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p></li></ol></section>",
+        "should support GFM tables and footnotes"
+    );
+
+    Ok(())
+}
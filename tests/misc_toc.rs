@@ -0,0 +1,143 @@
+use markdown::{to_html_with_output, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn toc() -> Result<(), String> {
+    let toc = Options {
+        compile: CompileOptions {
+            toc: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let result = to_html_with_output("# a\n## b", &Options::default())?;
+
+    assert_eq!(
+        (result.value.as_str(), result.toc),
+        ("<h1>a</h1>\n<h2>b</h2>", None),
+        "should not generate ids or a table of contents by default"
+    );
+
+    let result = to_html_with_output("# a\n## b\n### c\n## d\n# e", &toc)?;
+
+    assert_eq!(
+        result.value,
+        "<h1 id=\"a\">a</h1>\n<h2 id=\"b\">b</h2>\n<h3 id=\"c\">c</h3>\n<h2 id=\"d\">d</h2>\n<h1 id=\"e\">e</h1>",
+        "should add ids to headings w/ `toc`"
+    );
+
+    assert_eq!(
+        result.toc,
+        Some("<ul>\n<li><a href=\"#a\">a</a>\n<ul>\n<li><a href=\"#b\">b</a>\n<ul>\n<li><a href=\"#c\">c</a></li>\n</ul>\n</li>\n<li><a href=\"#d\">d</a></li>\n</ul>\n</li>\n<li><a href=\"#e\">e</a></li>\n</ul>".into()),
+        "should generate a nested table of contents w/ `toc`"
+    );
+
+    assert_eq!(
+        to_html_with_output("# a\n### b\n## c", &toc)?.toc,
+        Some("<ul>\n<li><a href=\"#a\">a</a>\n<ul>\n<li><a href=\"#b\">b</a></li>\n<li><a href=\"#c\">c</a></li>\n</ul>\n</li>\n</ul>".into()),
+        "should nest one level when levels are skipped"
+    );
+
+    assert_eq!(
+        to_html_with_output("## a\n# b\n### c\n## d", &toc)?.toc,
+        Some("<ul>\n<li><a href=\"#a\">a</a></li>\n<li><a href=\"#b\">b</a>\n<ul>\n<li><a href=\"#c\">c</a></li>\n<li><a href=\"#d\">d</a></li>\n</ul>\n</li>\n</ul>".into()),
+        "should nest headings between skipped levels in their parent"
+    );
+
+    assert_eq!(
+        to_html_with_output("## a\n# b", &toc)?.toc,
+        Some("<ul>\n<li><a href=\"#a\">a</a></li>\n<li><a href=\"#b\">b</a></li>\n</ul>".into()),
+        "should support a shallower heading after the first"
+    );
+
+    let result = to_html_with_output("# A\n# a\n# a-1\n# a", &toc)?;
+
+    assert_eq!(
+        result.value,
+        "<h1 id=\"a\">A</h1>\n<h1 id=\"a-1\">a</h1>\n<h1 id=\"a-1-1\">a-1</h1>\n<h1 id=\"a-2\">a</h1>",
+        "should add numbers to ids of headings w/ the same text"
+    );
+
+    assert_eq!(
+        result.toc,
+        Some("<ul>\n<li><a href=\"#a\">A</a></li>\n<li><a href=\"#a-1\">a</a></li>\n<li><a href=\"#a-1-1\">a-1</a></li>\n<li><a href=\"#a-2\">a</a></li>\n</ul>".into()),
+        "should link to numbered ids"
+    );
+
+    let result = to_html_with_output("# *a* `b` [c](d) &amp; e!\n\nf\n-", &toc)?;
+
+    assert_eq!(
+        result.value,
+        "<h1 id=\"a-b-c--e\"><em>a</em> <code>b</code> <a href=\"d\">c</a> &amp; e!</h1>\n<h2 id=\"f\">f</h2>",
+        "should use the text of headings for ids, and support setext headings"
+    );
+
+    assert_eq!(
+        result.toc,
+        Some("<ul>\n<li><a href=\"#a-b-c--e\">a b c &amp; e!</a>\n<ul>\n<li><a href=\"#f\">f</a></li>\n</ul>\n</li>\n</ul>".into()),
+        "should use the text of headings, w/o tags, in the table of contents"
+    );
+
+    let result = to_html_with_output("#\n\n# !", &toc)?;
+
+    assert_eq!(
+        (result.value.as_str(), result.toc),
+        ("<h1></h1>\n<h1>!</h1>", None),
+        "should not add ids to headings w/ empty slugs"
+    );
+
+    assert_eq!(
+        to_html_with_output("a", &toc)?.toc,
+        None,
+        "should not generate a table of contents w/o headings"
+    );
+
+    let result = to_html_with_output(
+        "# a\n## b\n### c\n#### d",
+        &Options {
+            compile: CompileOptions {
+                toc: true,
+                toc_min_depth: 2,
+                toc_max_depth: 3,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )?;
+
+    assert_eq!(
+        result.value, "<h1>a</h1>\n<h2 id=\"b\">b</h2>\n<h3 id=\"c\">c</h3>\n<h4>d</h4>",
+        "should only add ids to headings between `toc_min_depth` and `toc_max_depth`"
+    );
+
+    assert_eq!(
+        result.toc,
+        Some("<ul>\n<li><a href=\"#b\">b</a>\n<ul>\n<li><a href=\"#c\">c</a></li>\n</ul>\n</li>\n</ul>".into()),
+        "should only include headings between `toc_min_depth` and `toc_max_depth`"
+    );
+
+    assert_eq!(
+        to_html_with_output(
+            "# a\n## b\n# c",
+            &Options {
+                compile: CompileOptions {
+                    allowed_elements: Some(vec![
+                        "h1".into(),
+                        "h2".into(),
+                        "li".into(),
+                        "ul".into()
+                    ]),
+                    toc: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )?
+        .toc,
+        Some("<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n<li>c</li>\n</ul>".into()),
+        "should turn elements that are not allowed in the table of contents into their content"
+    );
+
+    Ok(())
+}
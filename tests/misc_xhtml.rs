@@ -0,0 +1,76 @@
+use markdown::{to_html, to_html_with_options, CompileOptions, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn xhtml() -> Result<(), String> {
+    let html = Options {
+        compile: CompileOptions {
+            xhtml: false,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+    let gfm_html = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            xhtml: false,
+            ..CompileOptions::gfm()
+        },
+    };
+
+    assert_eq!(
+        to_html("a\\\nb"),
+        "<p>a<br />\nb</p>",
+        "should close breaks XHTML-style by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\\nb", &html)?,
+        "<p>a<br>\nb</p>",
+        "should not close breaks XHTML-style w/ `xhtml: false`"
+    );
+
+    assert_eq!(
+        to_html("***"),
+        "<hr />",
+        "should close thematic breaks XHTML-style by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("***", &html)?,
+        "<hr>",
+        "should not close thematic breaks XHTML-style w/ `xhtml: false`"
+    );
+
+    assert_eq!(
+        to_html("![a](b \"c\")"),
+        "<p><img src=\"b\" alt=\"a\" title=\"c\" /></p>",
+        "should close images XHTML-style by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b \"c\")", &html)?,
+        "<p><img src=\"b\" alt=\"a\" title=\"c\"></p>",
+        "should not close images XHTML-style w/ `xhtml: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("* [ ] a\n* [x] b", &Options::gfm())?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" /> a</li>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> b</li>\n</ul>",
+        "should close task list item checks XHTML-style by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("* [ ] a\n* [x] b", &gfm_html)?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\"> a</li>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\"> b</li>\n</ul>",
+        "should not close task list item checks XHTML-style w/ `xhtml: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a\\\nb](c)", &html)?,
+        "<p><img src=\"c\" alt=\"a\nb\"></p>",
+        "should not add breaks in image alts w/ `xhtml: false`"
+    );
+
+    Ok(())
+}
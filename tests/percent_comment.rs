@@ -0,0 +1,203 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn percent_comment() -> Result<(), String> {
+    let percent_comment = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                percent_comment: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("a %%b%% c"),
+        "<p>a %%b%% c</p>",
+        "should not support percent comments by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("%a | b\n-|-", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>%a</th>\n<th>b</th>\n</tr>\n</thead>\n</table>",
+        "should not affect other constructs starting w/ a percent sign by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "%%\n> a | b\n%%",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        percent_comment: true,
+                        ..Constructs::gfm()
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "",
+        "should try percent comments (flow) before tables"
+    );
+
+    assert_eq!(
+        to_html_with_options("a %%b%% c", &percent_comment)?,
+        "<p>a  c</p>",
+        "should support percent comments (text) w/ `percent_comment: true`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a %%b\nc%% d", &percent_comment)?,
+        "<p>a  d</p>",
+        "should support line endings in percent comments (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a %%%% b", &percent_comment)?,
+        "<p>a  b</p>",
+        "should support empty percent comments (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a %%b % c%% d", &percent_comment)?,
+        "<p>a  d</p>",
+        "should support a single percent sign in percent comments (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a %%b %%c%% d%% e", &percent_comment)?,
+        "<p>a c e</p>",
+        "should not support nested percent comments (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a %%b *c%% d*", &percent_comment)?,
+        "<p>a  d*</p>",
+        "should prefer percent comments (text) over constructs that start later"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a %%b* c%%*", &percent_comment)?,
+        "<p><em>a </em></p>",
+        "should support percent comments (text) in other constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a %%b` c%%", &percent_comment)?,
+        "<p><code>a %%b</code> c%%</p>",
+        "should prefer constructs that start earlier over percent comments (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a %%b", &percent_comment)?,
+        "<p>a %%b</p>",
+        "should not support unterminated percent comments (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a %b% c", &percent_comment)?,
+        "<p>a %b% c</p>",
+        "should not support single percent signs as percent comments (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("%%\na\n\n# b\n%%\n\nc", &percent_comment)?,
+        "<p>c</p>",
+        "should support percent comments (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("%% a %%\nb", &percent_comment)?,
+        "<p>b</p>",
+        "should support percent comments (flow) on one line"
+    );
+
+    assert_eq!(
+        to_html_with_options("   %%\na\n%% \t\nb", &percent_comment)?,
+        "<p>b</p>",
+        "should support whitespace around percent comments (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("    %%a%%", &percent_comment)?,
+        "<pre><code>%%a%%\n</code></pre>",
+        "should not support percent comments (flow) indented 4 spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n%%\nb\n%%\nc", &percent_comment)?,
+        "<p>a</p>\n<p>c</p>",
+        "should support percent comments (flow) interrupting paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("%% a %% b", &percent_comment)?,
+        "<p> b</p>",
+        "should not support percent comments (flow) followed by something"
+    );
+
+    assert_eq!(
+        to_html_with_options("%%\na\n\nb", &percent_comment)?,
+        "<p>%%\na</p>\n<p>b</p>",
+        "should not support unterminated percent comments (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> %%\n> a\n> %%\n\nb", &percent_comment)?,
+        "<blockquote>\n</blockquote>\n<p>b</p>",
+        "should support percent comments (flow) in containers"
+    );
+
+    assert_eq!(
+        to_html_with_options("> %%\n> a\nb\n> %%", &percent_comment)?,
+        "<blockquote>\n<p>%%\na</p>\n</blockquote>\n<p>b</p>\n<blockquote>\n<p>%%</p>\n</blockquote>",
+        "should not support percent comments (flow) that exit their container"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  %%\n  b\n  %%\n* c", &percent_comment)?,
+        "<ul>\n<li>a</li>\n<li>c</li>\n</ul>",
+        "should not affect whether lists are tight"
+    );
+
+    assert_eq!(
+        to_mdast("a %%b%% c", &percent_comment.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a  c".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 10, 9))
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 10, 9))
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9))
+        }),
+        "should not add percent comments (text) to mdast"
+    );
+
+    assert_eq!(
+        to_mdast("%%\na\n%%\n\nb", &percent_comment.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "b".into(),
+                    position: Some(Position::new(5, 1, 9, 5, 2, 10))
+                }),],
+                position: Some(Position::new(5, 1, 9, 5, 2, 10))
+            })],
+            position: Some(Position::new(1, 1, 0, 5, 2, 10))
+        }),
+        "should not add percent comments (flow) to mdast"
+    );
+
+    Ok(())
+}
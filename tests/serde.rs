@@ -0,0 +1,69 @@
+#![cfg(feature = "serde")]
+
+use markdown::{mdast::Node, to_mdast, Constructs, ParseOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn serde() -> Result<(), String> {
+    let tree = to_mdast("a", &ParseOptions::default())?;
+
+    assert_eq!(
+        serde_json::to_string(&tree).unwrap(),
+        "{\"type\":\"root\",\"children\":[{\"type\":\"paragraph\",\"children\":[{\"type\":\"text\",\"value\":\"a\",\"position\":{\"start\":{\"line\":1,\"column\":1,\"offset\":0},\"end\":{\"line\":1,\"column\":2,\"offset\":1}}}],\"position\":{\"start\":{\"line\":1,\"column\":1,\"offset\":0},\"end\":{\"line\":1,\"column\":2,\"offset\":1}}}],\"position\":{\"start\":{\"line\":1,\"column\":1,\"offset\":0},\"end\":{\"line\":1,\"column\":2,\"offset\":1}}}",
+        "should serialize like `mdast` in JavaScript"
+    );
+
+    let tree = to_mdast(
+        "> # a *b* **c**\n\n- [x] d\n\n1. e\n\n```js f\ng\n```\n\n***\n\n[h]: i \"j\"\n\n[h] [k][h] ![l][h] ![m](n) `o`\\\np <q> ~~r~~[^s]\n\n[^s]: t\n\n| u |\n| :- |\n| v |",
+        &ParseOptions::gfm(),
+    )?;
+    let value = serde_json::to_string(&tree).unwrap();
+
+    assert!(
+        value.contains("{\"type\":\"blockquote\""),
+        "should use `blockquote` for block quotes"
+    );
+
+    assert!(
+        value.contains("\"referenceType\":\"full\""),
+        "should use `referenceType` for references"
+    );
+
+    assert!(
+        value.contains("{\"type\":\"footnoteDefinition\""),
+        "should use camel case for types"
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Node>(&value).unwrap(),
+        tree,
+        "should round-trip a tree through JSON"
+    );
+
+    let tree = to_mdast(
+        "<a b=\"c\" {...d} />",
+        &ParseOptions {
+            constructs: Constructs::mdx(),
+            ..ParseOptions::default()
+        },
+    )?;
+    let value = serde_json::to_string(&tree).unwrap();
+
+    assert!(
+        value.contains("{\"type\":\"mdxJsxAttribute\",\"name\":\"b\",\"value\":\"c\"}"),
+        "should serialize literal JSX attribute values as strings"
+    );
+
+    assert!(
+        value.contains("{\"type\":\"mdxJsxExpressionAttribute\",\"value\":\"...d\""),
+        "should serialize JSX expression attributes"
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Node>(&value).unwrap(),
+        tree,
+        "should round-trip MDX through JSON"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_markdown, to_mdast, Constructs, ParseOptions, SerializeOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Parse `value`, serialize it, and check that parsing and serializing that
+/// again gives the same result.
+fn round_trip(
+    value: &str,
+    parse: &ParseOptions,
+    options: &SerializeOptions,
+) -> Result<String, String> {
+    let result = to_markdown(&to_mdast(value, parse)?, options)?;
+    let again = to_markdown(&to_mdast(&result, parse)?, options)?;
+    assert_eq!(result, again, "should be stable for {:?}", value);
+    Ok(result)
+}
+
+#[test]
+fn serialize() -> Result<(), String> {
+    let cm = ParseOptions::default();
+    let gfm = ParseOptions::gfm();
+    let definition_list = ParseOptions {
+        constructs: Constructs {
+            definition_list: true,
+            ..Constructs::default()
+        },
+        ..ParseOptions::default()
+    };
+    let default = SerializeOptions::default();
+
+    assert_eq!(
+        round_trip("", &cm, &default)?,
+        "",
+        "should support an empty document"
+    );
+
+    assert_eq!(
+        round_trip("a\nb", &cm, &default)?,
+        "a\nb\n",
+        "should support paragraphs"
+    );
+
+    assert_eq!(
+        round_trip("# a\n\nb\n===\n\nc\nd\n---\n\n###### e ##", &cm, &default)?,
+        "# a\n\n# b\n\nc\nd\n---\n\n###### e\n",
+        "should support headings"
+    );
+
+    assert_eq!(
+        round_trip("> a\n>\n> > b\nc", &cm, &default)?,
+        "> a\n>\n> > b\n> > c\n",
+        "should support block quotes"
+    );
+
+    assert_eq!(
+        round_trip("- a\n- b\n\n1. c\n\n   d\n2. e", &cm, &default)?,
+        "* a\n* b\n\n1. c\n\n   d\n2. e\n",
+        "should support lists"
+    );
+
+    assert_eq!(
+        round_trip("* a\n\n\n- b\n\n\n+ c", &cm, &default)?,
+        "* a\n\n- b\n\n* c\n",
+        "should alternate bullets of adjacent lists"
+    );
+
+    assert_eq!(
+        round_trip("1. a\n\n\n1) b", &cm, &default)?,
+        "1. a\n\n1) b\n",
+        "should alternate delimiters of adjacent ordered lists"
+    );
+
+    assert_eq!(
+        round_trip("3. a\n4. b", &cm, &default)?,
+        "3. a\n4. b\n",
+        "should support list starts"
+    );
+
+    assert_eq!(
+        round_trip("- ***\n- a", &cm, &default)?,
+        "* ___\n* a\n",
+        "should not form a thematic break from a bullet and a thematic break"
+    );
+
+    assert_eq!(
+        round_trip("```js\na\n```\n\n    b\n\n~~~\n```\n~~~", &cm, &default)?,
+        "```js\na\n```\n\n```\nb\n```\n\n````\n```\n````\n",
+        "should support code (flow)"
+    );
+
+    assert_eq!(
+        round_trip(
+            "```js\na\n```\n\n    b",
+            &cm,
+            &SerializeOptions {
+                fences: false,
+                ..SerializeOptions::default()
+            }
+        )?,
+        "```js\na\n```\n\n    b\n",
+        "should support indented code w/ `fences: false`"
+    );
+
+    assert_eq!(
+        round_trip(
+            "* a\n\n```\nb\n```",
+            &cm,
+            &SerializeOptions {
+                fences: false,
+                ..SerializeOptions::default()
+            }
+        )?,
+        "* a\n\n```\nb\n```\n",
+        "should not use indented code after a list"
+    );
+
+    assert_eq!(
+        round_trip("***\n\n<div>\n\n[a]: <b c> 'd'", &cm, &default)?,
+        "***\n\n<div>\n\n[a]: <b c> \"d\"\n",
+        "should support thematic breaks, HTML, and definitions"
+    );
+
+    assert_eq!(
+        round_trip("*a* __b__ `c` ``d`e`` a\\\nb", &cm, &default)?,
+        "*a* **b** `c` ``d`e`` a\\\nb\n",
+        "should support attention, code (text), and breaks"
+    );
+
+    assert_eq!(
+        round_trip(
+            "*a* __b__",
+            &cm,
+            &SerializeOptions {
+                emphasis: '_',
+                strong: '_',
+                ..SerializeOptions::default()
+            }
+        )?,
+        "_a_ __b__\n",
+        "should support `emphasis` and `strong`"
+    );
+
+    assert_eq!(
+        round_trip(
+            "a*b*c **d**e",
+            &cm,
+            &SerializeOptions {
+                emphasis: '_',
+                strong: '_',
+                ..SerializeOptions::default()
+            }
+        )?,
+        "a*b*c **d**e\n",
+        "should not use `_` in words"
+    );
+
+    assert_eq!(
+        round_trip("*_a_*", &cm, &default)?,
+        "*_a_*\n",
+        "should not form strong from emphasis in emphasis"
+    );
+
+    assert_eq!(
+        round_trip(
+            "[a](b) [c](<d e> \"f\") ![g](h) <https://i> <j@k.l> [m][] [n]\n\n[m]: o\n[n]: p",
+            &cm,
+            &default
+        )?,
+        "[a](b) [c](<d e> \"f\") ![g](h) <https://i> <j@k.l> [m][] [n]\n\n[m]: o\n\n[n]: p\n",
+        "should support links, images, and references"
+    );
+
+    assert_eq!(
+        round_trip("![*a*][]\n\n[*a*]: b", &cm, &default)?,
+        "![a][*a*]\n\n[*a*]: b\n",
+        "should use full references if the text does not match the label"
+    );
+
+    assert_eq!(
+        round_trip(
+            "\\# \\> \\- \\+ \\= 1\\. 2\\) \\* \\_ \\` \\[ \\] \\< \\\\ \\~ &amp;b\n\\#\n\\-",
+            &cm,
+            &default
+        )?,
+        "\\# > - + = 1. 2) \\* \\_ \\` \\[ \\] \\< \\\\ \\~ \\&b\n\\#\n\\-\n",
+        "should escape characters that would otherwise be syntax"
+    );
+
+    assert_eq!(
+        round_trip("&#35; a\n&#32;b\na&#10;&#10;c", &cm, &default)?,
+        "\\# a\n&#x20;b\na\n&#xA;c\n",
+        "should escape characters at the start of lines"
+    );
+
+    assert_eq!(
+        round_trip("a!\\[b](c)", &cm, &default)?,
+        "a!\\[b\\](c)\n",
+        "should support exclamation marks before brackets"
+    );
+
+    assert_eq!(
+        round_trip("a\\![b](c)", &cm, &default)?,
+        "a\\![b](c)\n",
+        "should escape exclamation marks before links"
+    );
+
+    assert_eq!(
+        round_trip(
+            "| a | b | c | d |\n| - | :- | -: | :-: |\n| 1 | 2\\|3 | `4\\|5` |",
+            &gfm,
+            &default
+        )?,
+        "| a | b | c | d |\n| --- | :-- | --: | :-: |\n| 1 | 2\\|3 | `4\\|5` |  |\n",
+        "should support GFM tables"
+    );
+
+    assert_eq!(
+        round_trip(
+            "~~a~~ [^b]\n\n[^b]: c\n\n    d\n\n* [ ] e\n* [x] f",
+            &gfm,
+            &default
+        )?,
+        "~~a~~ [^b]\n\n[^b]: c\n\n    d\n\n* [ ] e\n* [x] f\n",
+        "should support GFM strikethrough, footnotes, and task list items"
+    );
+
+    assert_eq!(
+        round_trip(
+            "a\n: b\n  c\n: d\n\ne\nf\n\n: g\n\n: *h*",
+            &definition_list,
+            &default
+        )?,
+        "a\n: b\n  c\n: d\n\ne\nf\n\n: g\n\n: *h*\n",
+        "should support definition lists"
+    );
+
+    assert_eq!(
+        to_markdown(
+            &Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "*a*".into(),
+                    position: None
+                })],
+                position: None
+            }),
+            &default
+        )?,
+        "\\*a\\*\n",
+        "should support a node other than root"
+    );
+
+    assert_eq!(
+        to_markdown(
+            &Node::Root(Root {
+                children: vec![],
+                position: None
+            }),
+            &SerializeOptions {
+                bullet: '.',
+                ..SerializeOptions::default()
+            }
+        ),
+        Err("Cannot serialize bullets with `.`, expected `*`, `+`, or `-`".into()),
+        "should crash on invalid markers"
+    );
+
+    Ok(())
+}
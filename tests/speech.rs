@@ -0,0 +1,168 @@
+use markdown::{to_speech_segments, Constructs, ParseOptions, Segment, SegmentKind, SpeechOptions};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn speech() -> Result<(), String> {
+    let doc = "# Venus\n\nThe *second* planet\nfrom the sun.\n\n* Hot\n* Bright\n  * Very\n\n```js\nconsole.log(1)\nconsole.log(2)\n```\n\n> Quoted.\n\n***";
+
+    assert_eq!(
+        to_speech_segments(doc, &ParseOptions::default(), &SpeechOptions::default())?,
+        vec![
+            Segment {
+                kind: SegmentKind::Heading,
+                value: "Venus".into()
+            },
+            Segment {
+                kind: SegmentKind::Paragraph,
+                value: "The second planet from the sun.".into()
+            },
+            Segment {
+                kind: SegmentKind::ListItem,
+                value: "Hot".into()
+            },
+            Segment {
+                kind: SegmentKind::ListItem,
+                value: "Bright".into()
+            },
+            Segment {
+                kind: SegmentKind::ListItem,
+                value: "Very".into()
+            },
+            Segment {
+                kind: SegmentKind::Code,
+                value: "console.log(1)\nconsole.log(2)".into()
+            },
+            Segment {
+                kind: SegmentKind::Paragraph,
+                value: "Quoted.".into()
+            },
+        ],
+        "should support a mixed document"
+    );
+
+    assert_eq!(
+        to_speech_segments(
+            "```\na\nb\nc\n```\n\n    d",
+            &ParseOptions::default(),
+            &SpeechOptions {
+                summarize_code: true
+            }
+        )?,
+        vec![
+            Segment {
+                kind: SegmentKind::Code,
+                value: "code block, 3 lines".into()
+            },
+            Segment {
+                kind: SegmentKind::Code,
+                value: "code block, 1 line".into()
+            },
+        ],
+        "should support summarizing code"
+    );
+
+    assert_eq!(
+        to_speech_segments(
+            "a\\\nb  \nc",
+            &ParseOptions::default(),
+            &SpeechOptions::default()
+        )?,
+        vec![Segment {
+            kind: SegmentKind::Paragraph,
+            value: "a b c".into()
+        }],
+        "should turn breaks into spaces"
+    );
+
+    assert_eq!(
+        to_speech_segments(
+            "| a | b |\n| - | - |\n| c | d |",
+            &ParseOptions {
+                constructs: Constructs::gfm(),
+                ..ParseOptions::default()
+            },
+            &SpeechOptions::default()
+        )?,
+        vec![
+            Segment {
+                kind: SegmentKind::Paragraph,
+                value: "a, b".into()
+            },
+            Segment {
+                kind: SegmentKind::Paragraph,
+                value: "c, d".into()
+            },
+        ],
+        "should support table rows"
+    );
+
+    assert_eq!(
+        to_speech_segments(
+            "&copy; &#9731; &#x2603; &amp;amp;",
+            &ParseOptions::default(),
+            &SpeechOptions::default()
+        )?,
+        vec![Segment {
+            kind: SegmentKind::Paragraph,
+            value: "© ☃ ☃ &amp;".into()
+        }],
+        "should decode character references"
+    );
+
+    assert_eq!(
+        to_speech_segments(
+            "# a &amp; b\n\n`&copy;`",
+            &ParseOptions::default(),
+            &SpeechOptions::default()
+        )?,
+        vec![
+            Segment {
+                kind: SegmentKind::Heading,
+                value: "a & b".into()
+            },
+            Segment {
+                kind: SegmentKind::Paragraph,
+                value: "&copy;".into()
+            },
+        ],
+        "should decode character references in headings, but not in code"
+    );
+
+    assert_eq!(
+        to_speech_segments(
+            "<div>\n\n[a]: b\n\n***",
+            &ParseOptions::default(),
+            &SpeechOptions::default()
+        )?,
+        vec![],
+        "should ignore content that is not spoken"
+    );
+
+    assert_eq!(
+        to_speech_segments(
+            "a <b onclick=\"c()\">d</b> *e <!--f-->*",
+            &ParseOptions::default(),
+            &SpeechOptions::default()
+        )?,
+        vec![Segment {
+            kind: SegmentKind::Paragraph,
+            value: "a d e".into()
+        }],
+        "should not speak HTML"
+    );
+
+    assert_eq!(
+        to_speech_segments(
+            "a <b c=\"d\">e</b> {f}",
+            &ParseOptions::mdx(),
+            &SpeechOptions::default()
+        )?,
+        vec![Segment {
+            kind: SegmentKind::Paragraph,
+            value: "a e".into()
+        }],
+        "should not speak JSX tags or expressions"
+    );
+
+    Ok(())
+}
@@ -20,4 +20,22 @@ fn text() {
         "<p>Multiple     spaces</p>",
         "should preserve internal spaces verbatim"
     );
+
+    assert_eq!(
+        to_html("hy\u{AD}phen"),
+        "<p>hy\u{AD}phen</p>",
+        "should preserve soft hyphens"
+    );
+
+    assert_eq!(
+        to_html("a&shy;b"),
+        "<p>a\u{AD}b</p>",
+        "should decode soft hyphen character references"
+    );
+
+    assert_eq!(
+        to_html("zero\u{200B}width \u{200B}*a*\u{200B}"),
+        "<p>zero\u{200B}width \u{200B}<em>a</em>\u{200B}</p>",
+        "should preserve zero-width spaces"
+    );
 }
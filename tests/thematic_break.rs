@@ -2,7 +2,7 @@ use markdown::{
     mdast::{Node, Root, ThematicBreak},
     to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -188,6 +188,61 @@ fn thematic_break() -> Result<(), String> {
         "should support turning off thematic breaks"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "***",
+            &Options {
+                compile: CompileOptions {
+                    thematic_break_attributes: vec![("class".into(), "divider".into())],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<hr class=\"divider\" />",
+        "should support `thematic_break_attributes`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "***",
+            &Options {
+                compile: CompileOptions {
+                    thematic_break_attributes: vec![
+                        ("class".into(), "a\"b<c&d".into()),
+                        ("data-x".into(), "".into())
+                    ],
+                    xhtml: false,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<hr class=\"a&quot;b&lt;c&amp;d\" data-x=\"\">",
+        "should encode values in `thematic_break_attributes` (and support `xhtml: false`)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "***",
+            &Options {
+                compile: CompileOptions {
+                    thematic_break_attributes: vec![
+                        ("a onclick".into(), "x".into()),
+                        ("b\"".into(), "x".into()),
+                        ("c>".into(), "x".into()),
+                        ("".into(), "x".into()),
+                        ("data-d".into(), "x".into())
+                    ],
+                    ..Default::default()
+                },
+                ..Default::default()
+            }
+        )?,
+        "<hr data-d=\"x\" />",
+        "should skip invalid names in `thematic_break_attributes`"
+    );
+
     assert_eq!(
         to_mdast("***", &Default::default())?,
         Node::Root(Root {